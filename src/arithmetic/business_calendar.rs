@@ -0,0 +1,349 @@
+use chrono::NaiveDate;
+use chrono::Datelike;
+use chrono::Weekday;
+use chrono::Duration;
+use parser::parse_business_calendar;
+use easter::western_easter;
+/// Built-in US federal holidays (5 U.S.C. 6103) in the text format of `parse_business_calendar`.
+pub const US_FEDERAL: &str = include_str!("us_federal.txt");
+/// Built-in TARGET2 closing days in the text format of `parse_business_calendar`.
+pub const TARGET2: &str = include_str!("target2.txt");
+/// Maximum count of consecutive non-business days before searching stops.
+const MAX_CLOSED_DAYS: u32 = 3660;
+/// Rule of holiday date in a year.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum HolidayRule {
+    /// The same month and day every year, such as `(12, 25)`.
+    Fixed(u32, u32),
+    /// The nth weekday of the month, such as `(11, Weekday::Thu, 4)`. -1 is the last one, 0 has no date.
+    NthWeekday(u32, Weekday, i32),
+    /// Days after Western Easter Sunday, such as -2 for Good Friday.
+    Easter(i32),
+    /// A single date.
+    Date(NaiveDate),
+}
+/// Shift of a holiday falling on a weekend.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Observance {
+    /// The holiday is not shifted.
+    Actual,
+    /// Saturday is observed on Friday and Sunday on Monday.
+    NearestWeekday,
+    /// Saturday and Sunday are observed on Monday.
+    NextMonday,
+    /// Sunday is observed on Monday.
+    SundayToMonday,
+}
+/// Holiday of business calendar.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Holiday {
+    /// Name such as `Christmas Day`.
+    pub name: String,
+    /// Date rule.
+    pub rule: HolidayRule,
+    /// Shift of the holiday falling on a weekend.
+    pub observance: Observance,
+    /// The first year of the holiday.
+    pub first_year: Option<i32>,
+    /// The last year of the holiday.
+    pub last_year: Option<i32>,
+}
+impl Holiday {
+    /// Returns holiday with `Actual` observance for all years.
+    pub fn new(name: &str,rule: HolidayRule) -> Holiday {
+        return Holiday {
+            name: name.to_string(),
+            rule: rule,
+            observance: Observance::Actual,
+            first_year: None,
+            last_year: None,
+        };
+    }
+    /// Returns the date of the holiday in the year before observance shift.
+    pub fn date(&self,year: i32) -> Option<NaiveDate> {
+        if self.first_year.map_or(false,|first| year < first) || self.last_year.map_or(false,|last| year > last) {
+            return None;
+        }
+        return match self.rule {
+            HolidayRule::Fixed(month,day) => NaiveDate::from_ymd_opt(year,month,day),
+            HolidayRule::NthWeekday(_,_,0) => None,
+            HolidayRule::NthWeekday(month,weekday,n) if n > 0 => {
+                let first = NaiveDate::from_ymd_opt(year,month,1)?;
+                let offset = (7 + weekday.num_days_from_monday() - first.weekday().num_days_from_monday()) % 7;
+                NaiveDate::from_ymd_opt(year,month,(n as u32 - 1).checked_mul(7)?.checked_add(1 + offset)?)
+            },
+            HolidayRule::NthWeekday(month,weekday,n) => {
+                let next = if month == 12 { NaiveDate::from_ymd_opt(year + 1,1,1) } else { NaiveDate::from_ymd_opt(year,month + 1,1) };
+                let last = next?.pred();
+                let offset = (7 + last.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+                last.checked_sub_signed(Duration::days(offset as i64 + 7 * (n.unsigned_abs() as i64 - 1)))
+                    .filter(|date| date.month() == month)
+            },
+            HolidayRule::Easter(days) => western_easter(year)?.checked_add_signed(Duration::days(days as i64)),
+            HolidayRule::Date(date) => Some(date).filter(|date| date.year() == year),
+        };
+    }
+    /// Returns the observed date of the holiday in the year. It may fall into the adjacent year.
+    pub fn observed_date(&self,year: i32) -> Option<NaiveDate> {
+        let date = self.date(year)?;
+        let days = match (self.observance, date.weekday()) {
+            (Observance::NearestWeekday, Weekday::Sat) => -1,
+            (Observance::NearestWeekday, Weekday::Sun) => 1,
+            (Observance::NextMonday, Weekday::Sat) => 2,
+            (Observance::NextMonday, Weekday::Sun) => 1,
+            (Observance::SundayToMonday, Weekday::Sun) => 1,
+            _ => 0,
+        };
+        return date.checked_add_signed(Duration::days(days));
+    }
+    /// Returns true if the holiday is observed on the date.
+    pub fn is_observed_on(&self,date: NaiveDate) -> bool {
+        return (date.year() - 1..date.year() + 2).any(|year| self.observed_date(year) == Some(date));
+    }
+}
+/// Combination of business calendar with other calendars.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Combination {
+    /// The calendar has its own rules only.
+    Single,
+    /// A day is not a business day if it is not one in any of the calendars.
+    Union(Vec<BusinessCalendar>),
+    /// A day is not a business day if it is not one in all of the calendars.
+    Intersection(Vec<BusinessCalendar>),
+}
+/// Business day calendar: weekend days, holidays and combination with other calendars.
+///
+/// A day is not a business day if it is a weekend day, an observed holiday or a non-business
+/// day of the combined calendars.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BusinessCalendar {
+    /// Name such as `TARGET2`.
+    pub name: String,
+    /// Weekend days as bits of the number of days from Monday.
+    pub weekend: u8,
+    /// Holidays.
+    pub holidays: Vec<Holiday>,
+    /// Combined calendars.
+    pub combination: Combination,
+}
+impl BusinessCalendar {
+    /// Returns calendar with Saturday and Sunday weekend and without holidays.
+    pub fn new(name: &str) -> BusinessCalendar {
+        return BusinessCalendar {
+            name: name.to_string(),
+            weekend: 1 << 5 | 1 << 6,
+            holidays: Vec::new(),
+            combination: Combination::Single,
+        };
+    }
+    /// Returns built-in US federal holidays calendar.
+    pub fn us_federal() -> BusinessCalendar {
+        return parse_business_calendar(US_FEDERAL).unwrap();
+    }
+    /// Returns built-in TARGET2 (euro area payment system) calendar.
+    pub fn target2() -> BusinessCalendar {
+        return parse_business_calendar(TARGET2).unwrap();
+    }
+    /// Returns calendar closed when any of the calendars is closed, such as for settlement in two markets.
+    pub fn union(name: &str,calendars: Vec<BusinessCalendar>) -> BusinessCalendar {
+        return BusinessCalendar {
+            name: name.to_string(),
+            weekend: 0,
+            holidays: Vec::new(),
+            combination: Combination::Union(calendars),
+        };
+    }
+    /// Returns calendar closed when all of the calendars are closed.
+    pub fn intersection(name: &str,calendars: Vec<BusinessCalendar>) -> BusinessCalendar {
+        return BusinessCalendar {
+            name: name.to_string(),
+            weekend: 0,
+            holidays: Vec::new(),
+            combination: Combination::Intersection(calendars),
+        };
+    }
+    /// Returns true if the date is a business day.
+    pub fn is_business_day(&self,date: NaiveDate) -> bool {
+        if self.weekend & (1 << date.weekday().num_days_from_monday()) != 0 || self.holidays.iter().any(|holiday| holiday.is_observed_on(date)) {
+            return false;
+        }
+        return match self.combination {
+            Combination::Single => true,
+            Combination::Union(ref calendars) => calendars.iter().all(|calendar| calendar.is_business_day(date)),
+            Combination::Intersection(ref calendars) => calendars.is_empty() || calendars.iter().any(|calendar| calendar.is_business_day(date)),
+        };
+    }
+    /// Returns observed dates and names of own holidays falling in the year, sorted by date.
+    pub fn holidays(&self,year: i32) -> Vec<(NaiveDate, String)> {
+        let mut holidays: Vec<(NaiveDate, String)> = self.holidays.iter()
+            .flat_map(|holiday| (year - 1..year + 2).filter_map(move |year| holiday.observed_date(year)).map(move |date| (date, holiday.name.clone())))
+            .filter(|&(date,_)| date.year() == year)
+            .collect();
+        holidays.sort();
+        return holidays;
+    }
+    /// Returns the first business day on or after the date (or on or before it if not `is_forward`).
+    /// Returns `None` if there is no business day within ten years.
+    pub fn adjust(&self,date: NaiveDate,is_forward: bool) -> Option<NaiveDate> {
+        let step = Duration::days(if is_forward { 1 } else { -1 });
+        let mut date = date;
+        for _ in 0..MAX_CLOSED_DAYS {
+            if self.is_business_day(date) {
+                return Some(date);
+            }
+            date = date.checked_add_signed(step)?;
+        }
+        return None;
+    }
+    /// Returns date `days` business days after the date (or before it if negative), such as
+    /// settlement date `T+2`. Returns `None` on overflow or if there is no business day within ten years.
+    pub fn add_business_days(&self,date: NaiveDate,days: i64) -> Option<NaiveDate> {
+        let is_forward = days > 0;
+        let step = Duration::days(if is_forward { 1 } else { -1 });
+        let open_days = 7 - self.closed_weekdays().count_ones() as i64;
+        let mut date = date;
+        let mut remaining = days.checked_abs()?;
+        // Whole weeks have at most `open_days` business days each, so the jump never passes the result.
+        while open_days > 0 && remaining > open_days {
+            let weeks = (remaining - 1) / open_days;
+            if weeks > 1 << 30 {
+                return None;
+            }
+            let jumped = date.checked_add_signed(Duration::weeks(if is_forward { weeks } else { -weeks }))?;
+            let count = if is_forward { self.count_business_days(date.succ(),jumped) } else { self.count_business_days(jumped,date.pred()) };
+            if count == 0 {
+                date = self.adjust(jumped,is_forward)?;
+                remaining -= 1;
+            } else {
+                date = jumped;
+                remaining -= count;
+            }
+        }
+        for _ in 0..remaining {
+            date = self.adjust(date.checked_add_signed(step)?,is_forward)?;
+        }
+        return Some(date);
+    }
+    /// Returns count of business days after `start` up to and including `end`, negative if `end` is before `start`.
+    /// If `end` is a business day, `add_business_days(start, count)` returns `end`.
+    pub fn business_days_between(&self,start: NaiveDate,end: NaiveDate) -> i64 {
+        if end < start {
+            return -self.business_days_between(end,start);
+        }
+        if end == start {
+            return 0;
+        }
+        return self.count_business_days(start.succ(),end);
+    }
+    /// Returns weekdays closed in every week as bits of the number of days from Monday, including those of combined calendars.
+    fn closed_weekdays(&self) -> u8 {
+        return self.weekend & 0x7f | match self.combination {
+            Combination::Single => 0,
+            Combination::Union(ref calendars) => calendars.iter().fold(0,|bits,calendar| bits | calendar.closed_weekdays()),
+            Combination::Intersection(ref calendars) if calendars.is_empty() => 0,
+            Combination::Intersection(ref calendars) => calendars.iter().fold(0x7f,|bits,calendar| bits & calendar.closed_weekdays()),
+        };
+    }
+    /// Appends observed dates of own holidays and holidays of combined calendars around the years.
+    fn observed_dates(&self,first_year: i32,last_year: i32,dates: &mut Vec<NaiveDate>) {
+        for holiday in self.holidays.iter() {
+            dates.extend((first_year - 1..last_year + 2).filter_map(|year| holiday.observed_date(year)));
+        }
+        match self.combination {
+            Combination::Single => {},
+            Combination::Union(ref calendars) | Combination::Intersection(ref calendars) => {
+                for calendar in calendars.iter() {
+                    calendar.observed_dates(first_year,last_year,dates);
+                }
+            },
+        }
+    }
+    /// Returns count of business days from `first` through `last`: open weekdays of the whole weeks and
+    /// the remaining days, less the holidays falling on them.
+    fn count_business_days(&self,first: NaiveDate,last: NaiveDate) -> i64 {
+        let closed = self.closed_weekdays();
+        let is_open = |date: NaiveDate| closed & (1 << date.weekday().num_days_from_monday()) == 0;
+        let days = last.signed_duration_since(first).num_days() + 1;
+        let mut count = days / 7 * (7 - closed.count_ones() as i64);
+        count += (0..days % 7).filter(|&day| is_open(last - Duration::days(day))).count() as i64;
+        let mut dates = Vec::new();
+        self.observed_dates(first.year(),last.year(),&mut dates);
+        dates.sort();
+        dates.dedup();
+        count -= dates.into_iter().filter(|&date| date >= first && date <= last && is_open(date) && !self.is_business_day(date)).count() as i64;
+        return count;
+    }
+}
+#[cfg(test)]
+#[test]
+fn test_business_calendar() {
+    let date = |text: &str| NaiveDate::parse_from_str(text,"%Y-%m-%d").unwrap();
+    let dates = |holidays: Vec<(NaiveDate, String)>| -> Vec<String> {
+        return holidays.iter().map(|&(date,_)| date.to_string()).collect();
+    };
+    let us = BusinessCalendar::us_federal();
+    let target = BusinessCalendar::target2();
+    assert_eq!(us.name, "US federal");
+    assert_eq!(dates(us.holidays(2023)), ["2023-01-02", "2023-01-16", "2023-02-20", "2023-05-29", "2023-06-19", "2023-07-04",
+                                          "2023-09-04", "2023-10-09", "2023-11-10", "2023-11-23", "2023-12-25"]);
+    assert_eq!(dates(us.holidays(2021)).last().map(|date| date.as_str()), Some("2021-12-31"));
+    assert_eq!(dates(us.holidays(2020)).len(), 10);
+    assert_eq!(dates(target.holidays(2024)), ["2024-01-01", "2024-03-29", "2024-04-01", "2024-05-01", "2024-12-25", "2024-12-26"]);
+    assert_eq!(dates(target.holidays(1999)), ["1999-01-01", "1999-12-25", "1999-12-26", "1999-12-31"]);
+    let union = BusinessCalendar::union("US and TARGET2",vec![us.clone(), target.clone()]);
+    let intersection = BusinessCalendar::intersection("US or TARGET2",vec![us.clone(), target.clone()]);
+    let testdays = [
+        ("2023-07-04", false, true, false, true),
+        ("2024-03-29", true, false, false, true),
+        ("2023-12-25", false, false, false, false),
+        ("2023-12-26", true, false, false, true),
+        ("2023-11-10", false, true, false, true),
+        ("2023-11-11", false, false, false, false),
+        ("2023-11-13", true, true, true, true),
+    ];
+    for &(day, checkus, checktarget, checkunion, checkintersection) in testdays.iter() {
+        let result = (us.is_business_day(date(day)), target.is_business_day(date(day)),
+                      union.is_business_day(date(day)), intersection.is_business_day(date(day)));
+        let checkresult = (checkus, checktarget, checkunion, checkintersection);
+        if result != checkresult {
+            panic!("Business day failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   day, result, checkresult);
+        }
+    }
+    assert_eq!(target.add_business_days(date("2024-03-28"),2), Some(date("2024-04-03")));
+    assert_eq!(target.add_business_days(date("2024-04-03"),-2), Some(date("2024-03-28")));
+    assert_eq!(union.add_business_days(date("2023-07-03"),1), Some(date("2023-07-05")));
+    assert_eq!(us.add_business_days(date("2023-12-23"),0), Some(date("2023-12-23")));
+    assert_eq!(target.business_days_between(date("2024-03-28"),date("2024-04-03")), 2);
+    assert_eq!(target.business_days_between(date("2024-04-03"),date("2024-03-28")), -2);
+    assert_eq!(us.business_days_between(date("2023-01-01"),date("2023-12-31")), 249);
+    assert_eq!(us.business_days_between(date("2023-12-31"),date("2023-12-31")), 0);
+    assert_eq!(union.business_days_between(date("2023-01-01"),date("2023-12-31")), 245);
+    assert_eq!(intersection.business_days_between(date("2023-01-01"),date("2023-12-31")), 259);
+    assert_eq!(us.business_days_between(date("2000-01-01"),date("9999-12-31")), 1_999_121);
+    for &calendar in [&us, &target, &union, &intersection].iter() {
+        for &days in [1, 7, 250, 100_000, -3, -100_000].iter() {
+            let start = date("2023-11-13");
+            let end = calendar.add_business_days(start,days).unwrap();
+            assert!(calendar.is_business_day(end));
+            assert_eq!(calendar.business_days_between(start,end), days);
+        }
+    }
+    assert_eq!(us.add_business_days(date("2023-11-11"),i64::MAX), None);
+    assert_eq!(us.add_business_days(date("2023-11-11"),i64::MIN), None);
+    assert_eq!(us.adjust(date("2023-11-11"),true), Some(date("2023-11-13")));
+    assert_eq!(us.adjust(date("2023-11-11"),false), Some(date("2023-11-09")));
+    let mut closed = BusinessCalendar::new("Closed");
+    closed.weekend = 0x7f;
+    assert_eq!(closed.add_business_days(date("2023-11-11"),1), None);
+    let nth = |n: i32| Holiday::new("Nth",HolidayRule::NthWeekday(11,Weekday::Thu,n)).date(2023);
+    assert_eq!(nth(4), Some(date("2023-11-23")));
+    assert_eq!(nth(-1), Some(date("2023-11-30")));
+    assert_eq!(nth(-5), Some(date("2023-11-02")));
+    assert_eq!(nth(5), Some(date("2023-11-30")));
+    assert_eq!(nth(6), None);
+    assert_eq!(nth(-6), None);
+    assert_eq!(nth(0), None);
+    assert_eq!(nth(i32::MAX), None);
+    assert_eq!(nth(i32::MIN), None);
+}
@@ -0,0 +1,189 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Datelike;
+use chrono::Duration;
+use parser::IsoDuration;
+use super::gregorian::{days_in_month,is_weekend};
+/// Policy of adding months to a day missing in the resulting month, such as January 31 plus one month.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MonthEndPolicy {
+    /// Day is clamped to the end of month: January 31 plus one month is February 28 (or 29).
+    Clamp,
+    /// Missing days overflow into the next month: January 31 plus one month is March 3 (or 2).
+    Overflow,
+    /// The last day of month stays the last day: February 28, 2023 plus one month is March 31.
+    /// Other days are clamped.
+    PreserveEndOfMonth,
+}
+/// Calendar-aware arithmetic of dates. Time of day and offset are kept.
+pub trait CalendarArithmetic: Sized {
+    /// Returns date of the same day `months` months later (or earlier if negative)
+    /// with `policy` for days missing in the resulting month. Returns `None` on overflow.
+    fn add_months(&self,months: i32,policy: MonthEndPolicy) -> Option<Self>;
+    /// Returns date `years` years later (or earlier if negative). February 29 follows `policy`.
+    /// Returns `None` on overflow.
+    fn add_years(&self,years: i32,policy: MonthEndPolicy) -> Option<Self> {
+        return self.add_months(years.checked_mul(12)?,policy);
+    }
+    /// Returns date `days` business days (Monday through Friday) later (or earlier if negative).
+    /// A weekend date counts as the business day just before it when moving forward
+    /// (just after it when moving backward), as in spreadsheet `WORKDAY`: Saturday plus one
+    /// business day is Monday and Saturday minus one is Friday.
+    /// Returns `None` on overflow.
+    fn add_business_days(&self,days: i64) -> Option<Self>;
+    /// Returns date `duration` later (or earlier if negative). Returns `None` on overflow.
+    fn add_duration(&self,duration: Duration) -> Option<Self>;
+    /// Returns date later by ISO 8601 `duration`: years and months are added together with `policy`,
+    /// then days and time. Returns `None` on overflow.
+    fn add_iso_duration(&self,duration: &IsoDuration,policy: MonthEndPolicy) -> Option<Self> {
+        let months = duration.years.checked_mul(12)?.checked_add(duration.months)?;
+        if duration.days.checked_abs()? > i64::MAX / 86_400_000 {
+            return None;
+        }
+        return self.add_months(months,policy)?.add_duration(Duration::days(duration.days))?.add_duration(duration.time);
+    }
+}
+impl CalendarArithmetic for NaiveDate {
+    fn add_months(&self,months: i32,policy: MonthEndPolicy) -> Option<NaiveDate> {
+        let month_index = self.year() as i64 * 12 + self.month0() as i64 + months as i64;
+        let year = month_index.div_euclid(12);
+        if year < i32::MIN as i64 || year > i32::MAX as i64 {
+            return None;
+        }
+        let (year, month) = (year as i32, month_index.rem_euclid(12) as u32 + 1);
+        let last_day = days_in_month(year,month);
+        let is_end_of_month = self.day() == days_in_month(self.year(),self.month());
+        return match policy {
+            MonthEndPolicy::Clamp => NaiveDate::from_ymd_opt(year,month,self.day().min(last_day)),
+            MonthEndPolicy::Overflow => NaiveDate::from_ymd_opt(year,month,1)?
+                .checked_add_signed(Duration::days(self.day() as i64 - 1)),
+            MonthEndPolicy::PreserveEndOfMonth if is_end_of_month => NaiveDate::from_ymd_opt(year,month,last_day),
+            MonthEndPolicy::PreserveEndOfMonth => NaiveDate::from_ymd_opt(year,month,self.day().min(last_day)),
+        };
+    }
+    fn add_business_days(&self,days: i64) -> Option<NaiveDate> {
+        let step = Duration::days(days.signum());
+        let mut date = *self;
+        if days == 0 {
+            return Some(date);
+        }
+        while is_weekend(date) {
+            date = date.checked_add_signed(step)?;
+        }
+        let mut remaining = days.checked_abs()?;
+        if is_weekend(*self) {
+            remaining -= 1;
+        }
+        if remaining / 5 > 1 << 30 {
+            return None;
+        }
+        date = date.checked_add_signed(Duration::weeks(remaining / 5 * days.signum()))?;
+        for _ in 0..remaining % 5 {
+            date = date.checked_add_signed(step)?;
+            while is_weekend(date) {
+                date = date.checked_add_signed(step)?;
+            }
+        }
+        return Some(date);
+    }
+    /// Part of `duration` shorter than a day is ignored.
+    fn add_duration(&self,duration: Duration) -> Option<NaiveDate> {
+        return self.checked_add_signed(duration);
+    }
+}
+impl CalendarArithmetic for NaiveDateTime {
+    fn add_months(&self,months: i32,policy: MonthEndPolicy) -> Option<NaiveDateTime> {
+        return self.date().add_months(months,policy).map(|date| date.and_time(self.time()));
+    }
+    fn add_business_days(&self,days: i64) -> Option<NaiveDateTime> {
+        return self.date().add_business_days(days).map(|date| date.and_time(self.time()));
+    }
+    fn add_duration(&self,duration: Duration) -> Option<NaiveDateTime> {
+        return self.checked_add_signed(duration);
+    }
+}
+impl CalendarArithmetic for DateTime<FixedOffset> {
+    fn add_months(&self,months: i32,policy: MonthEndPolicy) -> Option<DateTime<FixedOffset>> {
+        return to_offset(self.naive_local().add_months(months,policy)?,*self.offset());
+    }
+    fn add_business_days(&self,days: i64) -> Option<DateTime<FixedOffset>> {
+        return to_offset(self.naive_local().add_business_days(days)?,*self.offset());
+    }
+    fn add_duration(&self,duration: Duration) -> Option<DateTime<FixedOffset>> {
+        return self.checked_add_signed(duration);
+    }
+}
+fn to_offset(local: NaiveDateTime,offset: FixedOffset) -> Option<DateTime<FixedOffset>> {
+    return local.checked_sub_signed(Duration::seconds(offset.local_minus_utc() as i64))
+        .map(|utc| DateTime::from_utc(utc,offset));
+}
+#[cfg(test)]
+#[test]
+fn test_calendar_arithmetic() {
+    use chrono::TimeZone;
+    use self::MonthEndPolicy::*;
+    let date = |text: &str| NaiveDate::parse_from_str(text,"%Y-%m-%d").unwrap();
+    let testmonths = [
+        ("2023-01-31", 1, Clamp, "2023-02-28"),
+        ("2024-01-31", 1, Clamp, "2024-02-29"),
+        ("2023-01-31", 1, Overflow, "2023-03-03"),
+        ("2024-01-31", 1, Overflow, "2024-03-02"),
+        ("2023-01-31", 1, PreserveEndOfMonth, "2023-02-28"),
+        ("2023-02-28", 1, PreserveEndOfMonth, "2023-03-31"),
+        ("2024-02-28", 1, PreserveEndOfMonth, "2024-03-28"),
+        ("2024-02-29", 1, PreserveEndOfMonth, "2024-03-31"),
+        ("2023-04-30", -2, PreserveEndOfMonth, "2023-02-28"),
+        ("2023-03-31", -1, Clamp, "2023-02-28"),
+        ("2023-03-31", -1, Overflow, "2023-03-03"),
+        ("2023-05-15", -17, Clamp, "2021-12-15"),
+        ("2024-02-29", 12, Clamp, "2025-02-28"),
+        ("2024-02-29", 12, Overflow, "2025-03-01"),
+        ("2024-02-29", 48, Clamp, "2028-02-29"),
+        ("2000-02-29", 1200, Clamp, "2100-02-28"),
+        ("1900-02-28", 1200, PreserveEndOfMonth, "2000-02-29"),
+    ];
+    for &(start, months, policy, checkdate) in testmonths.iter() {
+        let result = date(start).add_months(months,policy);
+        if result != Some(date(checkdate)) {
+            panic!("Date conversion failed for {} plus {} months with {:?}\nReceived: {:?}\nExpected: {:?}",
+                   start, months, policy, result, checkdate);
+        }
+    }
+    assert_eq!(date("2024-02-29").add_years(1,Clamp), Some(date("2025-02-28")));
+    assert_eq!(date("2024-02-29").add_years(-4,Overflow), Some(date("2020-02-29")));
+    assert_eq!(date("2023-02-28").add_years(1,PreserveEndOfMonth), Some(date("2024-02-29")));
+    assert_eq!(date("2023-02-28").add_years(i32::MAX,Clamp), None);
+    let testdays = [
+        ("2023-10-16", 1, "2023-10-17"),
+        ("2023-10-20", 1, "2023-10-23"),
+        ("2023-10-21", 1, "2023-10-23"),
+        ("2023-10-22", -1, "2023-10-20"),
+        ("2023-10-16", -1, "2023-10-13"),
+        ("2023-10-18", 10, "2023-11-01"),
+        ("2023-10-18", -7, "2023-10-09"),
+        ("2023-10-21", 0, "2023-10-21"),
+        ("2023-10-21", 5, "2023-10-27"),
+        ("2023-10-22", 1, "2023-10-23"),
+        ("2023-10-21", -1, "2023-10-20"),
+        ("2023-10-22", -5, "2023-10-16"),
+        ("2023-10-28", 6, "2023-11-06"),
+        ("2024-02-28", 2, "2024-03-01"),
+    ];
+    for &(start, days, checkdate) in testdays.iter() {
+        let result = date(start).add_business_days(days);
+        if result != Some(date(checkdate)) {
+            panic!("Date conversion failed for {} plus {} business days\nReceived: {:?}\nExpected: {:?}",
+                   start, days, result, checkdate);
+        }
+    }
+    assert_eq!(date("2023-10-16").add_business_days(i64::MIN), None);
+    let datetime = FixedOffset::east(3 * 3600).ymd(2024,1,31).and_hms(23,30,0);
+    assert_eq!(datetime.add_months(1,Clamp), Some(FixedOffset::east(3 * 3600).ymd(2024,2,29).and_hms(23,30,0)));
+    let duration = IsoDuration { years: 1, months: 1, days: 1, time: Duration::hours(1) };
+    assert_eq!(datetime.add_iso_duration(&duration,Clamp), Some(FixedOffset::east(3 * 3600).ymd(2025,3,2).and_hms(0,30,0)));
+    assert_eq!(date("2024-01-31").add_iso_duration(&duration,Overflow), Some(date("2025-03-04")));
+    assert_eq!(date("2024-01-31").add_iso_duration(&IsoDuration::months(i32::MAX,0),Clamp), None);
+    assert_eq!(datetime.add_business_days(1).map(|result| result.to_rfc3339()), Some("2024-02-01T23:30:00+03:00".to_string()));
+}
@@ -0,0 +1,50 @@
+use chrono::NaiveDate;
+use chrono::Datelike;
+use chrono::Weekday;
+/// Returns `true` if the year is a leap year of the proleptic Gregorian calendar.
+pub fn is_leap_year(year: i32) -> bool {
+    return year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0);
+}
+/// Returns number of days in the month of the proleptic Gregorian calendar.
+pub fn days_in_month(year: i32,month: u32) -> u32 {
+    return match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+}
+/// Returns number of days in the year of the proleptic Gregorian calendar.
+pub fn days_in_year(year: i32) -> u32 {
+    return if is_leap_year(year) { 366 } else { 365 };
+}
+/// Returns `true` for Saturday and Sunday.
+pub fn is_weekend(date: NaiveDate) -> bool {
+    return date.weekday() == Weekday::Sat || date.weekday() == Weekday::Sun;
+}
+#[cfg(test)]
+#[test]
+fn test_gregorian() {
+    let testmonths = [
+        (2023, 2, 28),
+        (2024, 2, 29),
+        (1900, 2, 28),
+        (2000, 2, 29),
+        (-4, 2, 29),
+        (2023, 4, 30),
+        (2023, 12, 31),
+    ];
+    for &(year, month, checkdays) in testmonths.iter() {
+        let result = days_in_month(year,month);
+        if result != checkdays {
+            panic!("Days in month failed for {}-{}\nReceived: {:?}\nExpected: {:?}",
+                   year, month, result, checkdays);
+        }
+        let next = if month == 12 { NaiveDate::from_ymd(year + 1,1,1) } else { NaiveDate::from_ymd(year,month + 1,1) };
+        assert_eq!(next.pred().day(), checkdays);
+    }
+    assert_eq!(days_in_year(2100), 365);
+    assert_eq!(days_in_year(2400), 366);
+    assert!(is_weekend(NaiveDate::from_ymd(2023,10,21)));
+    assert!(!is_weekend(NaiveDate::from_ymd(2023,10,20)));
+}
@@ -0,0 +1,6 @@
+mod gregorian;
+mod calendar_arithmetic;
+mod business_calendar;
+pub use self::gregorian::{is_leap_year,days_in_month,days_in_year,is_weekend};
+pub use self::calendar_arithmetic::{CalendarArithmetic,MonthEndPolicy};
+pub use self::business_calendar::{BusinessCalendar,Combination,Holiday,HolidayRule,Observance,US_FEDERAL,TARGET2};
\ No newline at end of file
@@ -0,0 +1,95 @@
+use chrono::{Datelike,NaiveDate,Weekday};
+/// Weekdays from Monday.
+const WEEKDAYS: [Weekday; 7] = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun];
+/// Returns `true` if days since the common era are in the supported range of `i32`.
+pub fn is_supported_days(days: i64) -> bool {
+    return days >= i32::MIN as i64 && days <= i32::MAX as i64;
+}
+/// Date of a non-Gregorian calendar.
+///
+/// Days are counted as in `NaiveDate::num_days_from_ce`: 0001-01-01 of the proleptic Gregorian calendar is day 1.
+pub trait CalendarDate: Copy {
+    /// Era abbreviation written after year, such as `AH`.
+    const ERA: &'static str;
+    /// Weekday names, from Monday.
+    const WEEKDAYS: [&'static str; 7];
+    /// Makes a new date. Returns `None` if the date does not exist or is out of supported range.
+    fn from_ymd_opt(year: i32,month: u32,day: u32) -> Option<Self>;
+    /// Makes a date from days since the common era. Returns `None` if the date is out of supported range,
+    /// which never exceeds the `i32` range of days.
+    fn from_num_days_from_ce(days: i64) -> Option<Self>;
+    /// Returns days since the common era.
+    fn num_days_from_ce(&self) -> i64;
+    /// Returns the year.
+    fn year(&self) -> i32;
+    /// Returns the month starting from 1.
+    fn month(&self) -> u32;
+    /// Returns the day of month starting from 1.
+    fn day(&self) -> u32;
+    /// Returns month names of the year, from the first month.
+    fn month_names(year: i32) -> &'static [&'static str];
+    /// Returns number of months in the year.
+    fn months_in_year(year: i32) -> u32 {
+        return Self::month_names(year).len() as u32;
+    }
+    /// Returns name of the month.
+    fn month_name(&self) -> &'static str {
+        return Self::month_names(self.year())[self.month() as usize - 1];
+    }
+    /// Returns the day of week.
+    fn weekday(&self) -> Weekday {
+        return WEEKDAYS[(self.num_days_from_ce() - 1).rem_euclid(7) as usize];
+    }
+    /// Returns name of the day of week.
+    fn weekday_name(&self) -> &'static str {
+        return Self::WEEKDAYS[(self.num_days_from_ce() - 1).rem_euclid(7) as usize];
+    }
+    /// Makes a date of the same day as the proleptic Gregorian date.
+    fn from_naive_date(date: &NaiveDate) -> Option<Self> {
+        return Self::from_num_days_from_ce(date.num_days_from_ce() as i64);
+    }
+    /// Returns the same day as the proleptic Gregorian date. Returns `None` if the date is out of chrono range.
+    fn to_naive_date(&self) -> Option<NaiveDate> {
+        let days = self.num_days_from_ce();
+        if !is_supported_days(days) {
+            return None;
+        }
+        return NaiveDate::from_num_days_from_ce_opt(days as i32);
+    }
+}
+#[cfg(test)]
+#[test]
+fn test_calendar_date_conversion() {
+    use super::{IslamicDate,UmmAlQuraDate,HebrewDate,PersianDate};
+    fn check<D: CalendarDate + PartialOrd + ::std::fmt::Debug>(from: i64,to: i64) {
+        let mut previous: Option<D> = None;
+        for days in from..to {
+            let date = D::from_num_days_from_ce(days).unwrap();
+            if D::from_ymd_opt(date.year(),date.month(),date.day()).map(|date| date.num_days_from_ce()) != Some(days) {
+                panic!("Calendar date conversion failed for {}\nReceived: {:?}", days, date);
+            }
+            assert!(previous.map(|previous| previous < date).unwrap_or(true));
+            previous = Some(date);
+        }
+    }
+    let (from, to) = (NaiveDate::from_ymd(1882,11,12), NaiveDate::from_ymd(2174,11,26));
+    let days = |date: NaiveDate| date.num_days_from_ce() as i64;
+    check::<IslamicDate>(500_000,800_000);
+    check::<HebrewDate>(500_000,800_000);
+    check::<PersianDate>(500_000,800_000);
+    check::<UmmAlQuraDate>(days(from),days(to));
+    assert_eq!(UmmAlQuraDate::from_num_days_from_ce(days(from) - 1), None);
+    assert_eq!(UmmAlQuraDate::from_num_days_from_ce(days(to)), None);
+    for &days in [i64::MIN, i32::MIN as i64 - 1, i32::MAX as i64 + 1, i64::MAX].iter() {
+        assert_eq!(IslamicDate::from_num_days_from_ce(days), None);
+        assert_eq!(HebrewDate::from_num_days_from_ce(days), None);
+        assert_eq!(PersianDate::from_num_days_from_ce(days), None);
+        assert_eq!(UmmAlQuraDate::from_num_days_from_ce(days), None);
+    }
+    for &days in [i32::MIN as i64, i32::MAX as i64].iter() {
+        assert_eq!(IslamicDate::from_num_days_from_ce(days).map(|date| date.num_days_from_ce()), Some(days));
+        assert_eq!(HebrewDate::from_num_days_from_ce(days).map(|date| date.num_days_from_ce()), Some(days));
+        assert_eq!(PersianDate::from_num_days_from_ce(days).map(|date| date.num_days_from_ce()), Some(days));
+    }
+    assert_eq!(HebrewDate::from_naive_date(&NaiveDate::from_ymd(2023,10,16)).map(|date| date.weekday()), Some(Weekday::Mon));
+}
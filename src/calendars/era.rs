@@ -0,0 +1,64 @@
+use chrono::{Datelike,NaiveDate};
+/// Month names of the Thai solar calendar.
+pub const THAI_MONTHS: [&str; 12] = ["มกราคม","กุมภาพันธ์","มีนาคม","เมษายน","พฤษภาคม","มิถุนายน",
+    "กรกฎาคม","สิงหาคม","กันยายน","ตุลาคม","พฤศจิกายน","ธันวาคม"];
+/// Written form of a full date of the era.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EraFormat {
+    /// Era name, year, month and day with `年`, `月` and `日`, such as `令和5年10月16日`; the first year is `元年`.
+    Cjk,
+    /// Day, Thai month name, era name and year, such as `16 ตุลาคม พ.ศ. 2566`.
+    Thai,
+}
+/// Era of a year numbering, such as Japanese `令和` (Reiwa) or Minguo `民國`.
+///
+/// Tables of eras are sorted by start date, each era ends when the next one starts.
+/// A table can be extended for future eras by a new array that appends them to a built-in table.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Era {
+    /// Era name such as `令和`.
+    pub name: &'static str,
+    /// Latin abbreviation such as `R`.
+    pub abbreviation: &'static str,
+    /// First day of the era in the proleptic Gregorian calendar: year, month and day.
+    pub start: (i32, u32, u32),
+    /// Written form of a full date.
+    pub format: EraFormat,
+}
+impl Era {
+    /// Returns first day of the era. Returns `None` if the start is not a valid date.
+    pub fn start_date(&self) -> Option<NaiveDate> {
+        return NaiveDate::from_ymd_opt(self.start.0,self.start.1,self.start.2);
+    }
+    /// Returns year of the era of the Gregorian year, the first year of the era is 1.
+    pub fn era_year(&self,year: i32) -> i32 {
+        return year - self.start.0 + 1;
+    }
+    /// Returns Gregorian year of the year of the era.
+    pub fn gregorian_year(&self,era_year: i32) -> i32 {
+        return era_year + self.start.0 - 1;
+    }
+}
+/// Japanese eras since the adoption of the Gregorian calendar.
+///
+/// Meiji starts on the Gregorian equivalent of its proclamation, dates before 1873 were written in the lunisolar calendar.
+pub const JAPANESE_ERAS: [Era; 5] = [
+    Era { name: "明治", abbreviation: "M", start: (1868, 10, 23), format: EraFormat::Cjk },
+    Era { name: "大正", abbreviation: "T", start: (1912, 7, 30), format: EraFormat::Cjk },
+    Era { name: "昭和", abbreviation: "S", start: (1926, 12, 25), format: EraFormat::Cjk },
+    Era { name: "平成", abbreviation: "H", start: (1989, 1, 8), format: EraFormat::Cjk },
+    Era { name: "令和", abbreviation: "R", start: (2019, 5, 1), format: EraFormat::Cjk },
+];
+/// Minguo (Republic of China) era: year 1 is 1912.
+pub const MINGUO_ERAS: [Era; 1] = [
+    Era { name: "民國", abbreviation: "ROC", start: (1912, 1, 1), format: EraFormat::Cjk },
+];
+/// Thai Buddhist era: year is 543 years ahead of the Gregorian year, with new year on 1 January.
+pub const THAI_BUDDHIST_ERAS: [Era; 1] = [
+    Era { name: "พ.ศ.", abbreviation: "BE", start: (-542, 1, 1), format: EraFormat::Thai },
+];
+/// Returns the era of the date and year of the era. Returns `None` if the date is before the first era.
+pub fn find_era<'a>(eras: &'a [Era],date: &NaiveDate) -> Option<(&'a Era, i32)> {
+    let era = eras.iter().rev().find(|era| era.start <= (date.year(), date.month(), date.day()))?;
+    return Some((era, era.era_year(date.year())));
+}
@@ -0,0 +1,118 @@
+use super::calendar_date::{CalendarDate,is_supported_days};
+/// Days since the common era of 1 Tishrei 1 AM (7 October 3761 BC of the Julian calendar).
+const HEBREW_EPOCH: i64 = -1_373_427;
+/// Month names of a common year of the Hebrew calendar, from Tishrei.
+pub const HEBREW_MONTHS: [&str; 12] = ["Tishrei","Cheshvan","Kislev","Tevet","Shevat","Adar",
+    "Nisan","Iyar","Sivan","Tammuz","Av","Elul"];
+/// Month names of a leap year of the Hebrew calendar, from Tishrei.
+pub const HEBREW_LEAP_MONTHS: [&str; 13] = ["Tishrei","Cheshvan","Kislev","Tevet","Shevat","Adar I","Adar II",
+    "Nisan","Iyar","Sivan","Tammuz","Av","Elul"];
+/// Weekday names of the Hebrew calendar, from Monday.
+pub const HEBREW_WEEKDAYS: [&str; 7] = ["Yom Sheni","Yom Shlishi","Yom Revi'i","Yom Chamishi","Yom Shishi","Shabbat","Yom Rishon"];
+/// Returns `true` if the year is a leap year of the Hebrew calendar:
+/// years 3, 6, 8, 11, 14, 17 and 19 of the 19-year Metonic cycle.
+pub fn is_hebrew_leap_year(year: i32) -> bool {
+    return (7 * year as i64 + 1).rem_euclid(19) < 7;
+}
+/// Returns days from the epoch to the molad of Tishrei with the postponement of the new year from Sunday,
+/// Wednesday and Friday.
+fn elapsed_days(year: i64) -> i64 {
+    let months_elapsed = (235 * year - 234).div_euclid(19);
+    let parts_elapsed = 12084 + 13753 * months_elapsed;
+    let days = 29 * months_elapsed + parts_elapsed.div_euclid(25920);
+    return if (3 * (days + 1)).rem_euclid(7) < 3 { days + 1 } else { days };
+}
+/// Returns days since the common era of 1 Tishrei of the year.
+fn new_year(year: i64) -> i64 {
+    let (previous, current, next) = (elapsed_days(year - 1), elapsed_days(year), elapsed_days(year + 1));
+    let correction = if next - current == 356 {
+        2
+    } else if current - previous == 382 {
+        1
+    } else {
+        0
+    };
+    return HEBREW_EPOCH + current + correction;
+}
+/// Returns lengths of months of the year, from Tishrei.
+fn month_lengths(year: i32) -> Vec<u32> {
+    let days_in_year = new_year(year as i64 + 1) - new_year(year as i64);
+    let cheshvan = if days_in_year % 10 == 5 { 30 } else { 29 };
+    let kislev = if days_in_year % 10 == 3 { 29 } else { 30 };
+    let mut lengths = vec![30, cheshvan, kislev, 29, 30];
+    if is_hebrew_leap_year(year) {
+        lengths.extend_from_slice(&[30, 29]);
+    } else {
+        lengths.push(29);
+    }
+    lengths.extend_from_slice(&[30, 29, 30, 29, 30, 29]);
+    return lengths;
+}
+/// Date of the Hebrew calendar.
+///
+/// Months are numbered from Tishrei in the civil order, so the month 6 is Adar of a common year
+/// or Adar I of a leap year, and the month 7 is Nisan of a common year or Adar II of a leap year.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct HebrewDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+impl CalendarDate for HebrewDate {
+    const ERA: &'static str = "AM";
+    const WEEKDAYS: [&'static str; 7] = HEBREW_WEEKDAYS;
+    fn from_ymd_opt(year: i32,month: u32,day: u32) -> Option<HebrewDate> {
+        let lengths = month_lengths(year);
+        if month < 1 || month as usize > lengths.len() || day < 1 || day > lengths[month as usize - 1] {
+            return None;
+        }
+        return Some(HebrewDate {
+            year: year,
+            month: month,
+            day: day,
+        });
+    }
+    fn from_num_days_from_ce(days: i64) -> Option<HebrewDate> {
+        if !is_supported_days(days) {
+            return None;
+        }
+        // Average length of the year is 35975351/98496 days.
+        let mut year = ((days - HEBREW_EPOCH) * 98496).div_euclid(35975351);
+        while new_year(year + 1) <= days {
+            year = year + 1;
+        }
+        let mut day = days - new_year(year);
+        let mut month = 1;
+        for length in month_lengths(year as i32) {
+            if day < length as i64 {
+                break;
+            }
+            day = day - length as i64;
+            month = month + 1;
+        }
+        return Some(HebrewDate {
+            year: year as i32,
+            month: month,
+            day: day as u32 + 1,
+        });
+    }
+    fn num_days_from_ce(&self) -> i64 {
+        let before: u32 = month_lengths(self.year)[..self.month as usize - 1].iter().sum();
+        return new_year(self.year as i64) + before as i64 + self.day as i64 - 1;
+    }
+    fn year(&self) -> i32 {
+        return self.year;
+    }
+    fn month(&self) -> u32 {
+        return self.month;
+    }
+    fn day(&self) -> u32 {
+        return self.day;
+    }
+    fn month_names(year: i32) -> &'static [&'static str] {
+        if is_hebrew_leap_year(year) {
+            return &HEBREW_LEAP_MONTHS;
+        }
+        return &HEBREW_MONTHS;
+    }
+}
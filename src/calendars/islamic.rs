@@ -0,0 +1,71 @@
+use super::calendar_date::{CalendarDate,is_supported_days};
+/// Days since the common era of 1 Muharram 1 AH (Friday, 16 July 622 of the Julian calendar).
+const ISLAMIC_EPOCH: i64 = 227_015;
+/// Month names of the Islamic calendar.
+pub const ISLAMIC_MONTHS: [&str; 12] = ["Muharram","Safar","Rabi' al-Awwal","Rabi' al-Thani","Jumada al-Ula","Jumada al-Akhirah",
+    "Rajab","Sha'ban","Ramadan","Shawwal","Dhu al-Qa'dah","Dhu al-Hijjah"];
+/// Weekday names of the Islamic calendar, from Monday.
+pub const ISLAMIC_WEEKDAYS: [&str; 7] = ["al-Ithnayn","ath-Thulatha","al-Arbi'a","al-Khamis","al-Jumu'a","as-Sabt","al-Ahad"];
+/// Returns `true` if the year is a leap year of the tabular Islamic calendar:
+/// years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26 and 29 of the 30-year cycle.
+pub fn is_islamic_leap_year(year: i32) -> bool {
+    return (14 + 11 * year as i64).rem_euclid(30) < 11;
+}
+fn days_in_month(year: i32,month: u32) -> u32 {
+    return if month == 12 && is_islamic_leap_year(year) { 30 } else { 30 - (month + 1) % 2 };
+}
+fn days_before(year: i32,month: u32) -> i64 {
+    let year = year as i64;
+    let month = month as i64;
+    return ISLAMIC_EPOCH - 1 + (year - 1) * 354 + (3 + 11 * year).div_euclid(30) + 29 * (month - 1) + month / 2;
+}
+/// Date of the tabular (civil) Islamic calendar with the Friday epoch.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct IslamicDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+impl CalendarDate for IslamicDate {
+    const ERA: &'static str = "AH";
+    const WEEKDAYS: [&'static str; 7] = ISLAMIC_WEEKDAYS;
+    fn from_ymd_opt(year: i32,month: u32,day: u32) -> Option<IslamicDate> {
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year,month) {
+            return None;
+        }
+        return Some(IslamicDate {
+            year: year,
+            month: month,
+            day: day,
+        });
+    }
+    fn from_num_days_from_ce(days: i64) -> Option<IslamicDate> {
+        if !is_supported_days(days) {
+            return None;
+        }
+        let year = (30 * (days - ISLAMIC_EPOCH) + 10646).div_euclid(10631) as i32;
+        let prior_days = days - days_before(year,1) - 1;
+        let month = ((11 * prior_days + 330) / 325) as u32;
+        let day = (days - days_before(year,month)) as u32;
+        return Some(IslamicDate {
+            year: year,
+            month: month,
+            day: day,
+        });
+    }
+    fn num_days_from_ce(&self) -> i64 {
+        return days_before(self.year,self.month) + self.day as i64;
+    }
+    fn year(&self) -> i32 {
+        return self.year;
+    }
+    fn month(&self) -> u32 {
+        return self.month;
+    }
+    fn day(&self) -> u32 {
+        return self.day;
+    }
+    fn month_names(_year: i32) -> &'static [&'static str] {
+        return &ISLAMIC_MONTHS;
+    }
+}
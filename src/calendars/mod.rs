@@ -0,0 +1,12 @@
+mod calendar_date;
+mod islamic;
+mod umm_al_qura;
+mod hebrew;
+mod persian;
+mod era;
+pub use self::calendar_date::CalendarDate;
+pub use self::islamic::{IslamicDate,ISLAMIC_MONTHS,ISLAMIC_WEEKDAYS,is_islamic_leap_year};
+pub use self::umm_al_qura::{UmmAlQuraDate,UMM_AL_QURA_MIN_YEAR,UMM_AL_QURA_MAX_YEAR};
+pub use self::hebrew::{HebrewDate,HEBREW_MONTHS,HEBREW_LEAP_MONTHS,HEBREW_WEEKDAYS,is_hebrew_leap_year};
+pub use self::persian::{PersianDate,PERSIAN_MONTHS,PERSIAN_WEEKDAYS,is_persian_leap_year};
+pub use self::era::{Era,EraFormat,THAI_MONTHS,JAPANESE_ERAS,MINGUO_ERAS,THAI_BUDDHIST_ERAS,find_era};
\ No newline at end of file
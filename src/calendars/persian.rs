@@ -0,0 +1,78 @@
+use super::calendar_date::{CalendarDate,is_supported_days};
+/// Days since the common era of 1 Farvardin 1 AP (19 March 622 of the Julian calendar).
+const PERSIAN_EPOCH: i64 = 226_895;
+/// Month names of the Persian (Solar Hijri) calendar.
+pub const PERSIAN_MONTHS: [&str; 12] = ["Farvardin","Ordibehesht","Khordad","Tir","Mordad","Shahrivar",
+    "Mehr","Aban","Azar","Dey","Bahman","Esfand"];
+/// Weekday names of the Persian calendar, from Monday.
+pub const PERSIAN_WEEKDAYS: [&str; 7] = ["Doshanbeh","Seshanbeh","Chaharshanbeh","Panjshanbeh","Jomeh","Shanbeh","Yekshanbeh"];
+/// Returns `true` if the year is a leap year of the arithmetic Persian calendar with the 33-year cycle.
+pub fn is_persian_leap_year(year: i32) -> bool {
+    return (25 * year as i64 + 11).rem_euclid(33) < 8;
+}
+fn days_in_month(year: i32,month: u32) -> u32 {
+    return match month {
+        1..=6 => 31,
+        7..=11 => 30,
+        _ if is_persian_leap_year(year) => 30,
+        _ => 29,
+    };
+}
+/// Returns days since the common era of the day before 1 Farvardin of the year.
+fn days_before_year(year: i64) -> i64 {
+    return PERSIAN_EPOCH - 1 + 365 * (year - 1) + (8 * year + 21).div_euclid(33);
+}
+/// Date of the Persian (Solar Hijri) calendar.
+///
+/// Leap years follow the arithmetic 33-year cycle, which agrees with the astronomical vernal equinox rule
+/// of the official Iranian calendar in the current era and first diverges from it in 1503 AP (2124).
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct PersianDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+impl CalendarDate for PersianDate {
+    const ERA: &'static str = "AP";
+    const WEEKDAYS: [&'static str; 7] = PERSIAN_WEEKDAYS;
+    fn from_ymd_opt(year: i32,month: u32,day: u32) -> Option<PersianDate> {
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year,month) {
+            return None;
+        }
+        return Some(PersianDate {
+            year: year,
+            month: month,
+            day: day,
+        });
+    }
+    fn from_num_days_from_ce(days: i64) -> Option<PersianDate> {
+        if !is_supported_days(days) {
+            return None;
+        }
+        let year = 1 + (33 * (days - PERSIAN_EPOCH) + 3).div_euclid(12053);
+        let day_of_year = days - days_before_year(year) - 1;
+        let month = if day_of_year < 186 { day_of_year / 31 } else { (day_of_year - 6) / 30 } as u32 + 1;
+        let day = (day_of_year - (month as i64 - 1) * 30 - (month as i64 - 1).min(6)) as u32 + 1;
+        return Some(PersianDate {
+            year: year as i32,
+            month: month,
+            day: day,
+        });
+    }
+    fn num_days_from_ce(&self) -> i64 {
+        let month = self.month as i64;
+        return days_before_year(self.year as i64) + (month - 1) * 30 + (month - 1).min(6) + self.day as i64;
+    }
+    fn year(&self) -> i32 {
+        return self.year;
+    }
+    fn month(&self) -> u32 {
+        return self.month;
+    }
+    fn day(&self) -> u32 {
+        return self.day;
+    }
+    fn month_names(_year: i32) -> &'static [&'static str] {
+        return &PERSIAN_MONTHS;
+    }
+}
@@ -0,0 +1,125 @@
+use super::calendar_date::CalendarDate;
+use super::islamic::{ISLAMIC_MONTHS,ISLAMIC_WEEKDAYS};
+/// First year of the Umm al-Qura table.
+pub const UMM_AL_QURA_MIN_YEAR: i32 = 1300;
+/// Last year of the Umm al-Qura table.
+pub const UMM_AL_QURA_MAX_YEAR: i32 = 1600;
+/// Days since the common era of 1 Muharram 1300 AH (1882-11-12).
+const UMM_AL_QURA_EPOCH: i64 = 687_337;
+/// Month lengths of the Umm al-Qura calendar from 1300 AH through 1600 AH:
+/// bit `n` is set if month `n + 1` has 30 days, otherwise it has 29 days.
+const UMM_AL_QURA_MONTHS: [u16; 301] = [
+    0x555, 0x2ab, 0x937, 0x2b6, 0x576, 0x36c, 0xb55, 0xaaa, 0x956, 0x49e,
+    0x95d, 0x2ba, 0x5b5, 0x3aa, 0xb4b, 0xa96, 0x52e, 0x2ad, 0x56d, 0xb5a,
+    0x752, 0xf25, 0xe8a, 0xd16, 0xa56, 0xab5, 0x6b4, 0xda9, 0xb92, 0xb25,
+    0x64b, 0xa9b, 0x35a, 0x6d9, 0x5d4, 0xda5, 0xd4a, 0xa95, 0x536, 0x975,
+    0x2f4, 0x6e9, 0x6d4, 0x6a9, 0x535, 0x25d, 0x4bd, 0x9ba, 0x3b4, 0xb69,
+    0xb2a, 0xa55, 0x4ad, 0xa5d, 0x2da, 0x6d9, 0xeaa, 0xe94, 0xd2a, 0xc56,
+    0x4ae, 0xa6d, 0x56a, 0xd55, 0xd4a, 0xa93, 0x52b, 0xa5b, 0x53a, 0x6b5,
+    0xea9, 0xd52, 0xd29, 0xa55, 0x4ad, 0x56d, 0xaea, 0x6e4, 0xed1, 0xda2,
+    0xaaa, 0x95a, 0x2da, 0x5b9, 0xbb2, 0x764, 0x6c9, 0x555, 0x2ab, 0x4db,
+    0xaba, 0x5b4, 0xda9, 0xd52, 0xaa5, 0x92d, 0x26d, 0x8ed, 0x2da, 0xad5,
+    0xaa5, 0xa4b, 0x497, 0x937, 0x2b6, 0x975, 0xd69, 0xd52, 0xc95, 0x92b,
+    0x25b, 0x4db, 0x9d5, 0x5d2, 0xda5, 0xd4a, 0xa95, 0x54d, 0xaad, 0x3aa,
+    0xbd2, 0xbc4, 0xb89, 0xa95, 0x52d, 0x5ad, 0xb6a, 0x6d4, 0xdc9, 0xd92,
+    0xaa6, 0x956, 0x2ae, 0x56d, 0x36a, 0xb55, 0xaaa, 0x94d, 0x49d, 0x95d,
+    0x2ba, 0x5b5, 0x5aa, 0xd55, 0xa9a, 0x92e, 0x26e, 0x55d, 0xada, 0x6d4,
+    0x6a5, 0xb27, 0xa4d, 0x4ad, 0x56d, 0xb5a, 0x754, 0xf49, 0xe92, 0xd26,
+    0xa56, 0x356, 0x6b5, 0xbaa, 0xb92, 0xb25, 0x68b, 0xa9b, 0x55a, 0xada,
+    0x5b4, 0xda9, 0xb52, 0xa9a, 0x536, 0x276, 0x575, 0xaf2, 0x6d4, 0x6a9,
+    0x555, 0x2ad, 0x4bd, 0x9ba, 0x574, 0xb69, 0xb52, 0xa95, 0x52d, 0xa5d,
+    0x4da, 0xad9, 0x6b2, 0xe95, 0xe2a, 0xc96, 0x92e, 0xaad, 0x56a, 0xd65,
+    0xd4a, 0xd15, 0x62b, 0xc5b, 0x53a, 0x6b5, 0xdb2, 0xd64, 0xd29, 0xa55,
+    0x4ad, 0x96d, 0xaea, 0x6e8, 0xed1, 0xda4, 0xd4a, 0xa6a, 0x2da, 0x5b9,
+    0xb72, 0xb68, 0x6d1, 0x655, 0x4ab, 0x95b, 0x2ba, 0x5b5, 0xda9, 0xd52,
+    0xca6, 0x94e, 0x46e, 0x95d, 0x4da, 0xad5, 0xaaa, 0xa4d, 0x49b, 0x937,
+    0x4b6, 0x975, 0xd6a, 0xd52, 0xaa5, 0x94b, 0x2ab, 0x55b, 0xad9, 0x5d2,
+    0xdc5, 0xd92, 0xb25, 0x555, 0xab5, 0x5b4, 0xba9, 0x7a2, 0x745, 0x593,
+    0xaab, 0x4d6, 0x9d6, 0x5d2, 0xba5, 0xb4a, 0xa95, 0x4ad, 0x15d, 0x2dd,
+    0x9da, 0x5b4, 0x5a9, 0x52d, 0x25b, 0x8b7, 0x176, 0x56d, 0xb6a, 0xaca,
+    0xa96, 0x52b, 0x15b, 0x2bb, 0x5b6, 0xdaa, 0xb94, 0xd46, 0xa8d, 0x52d,
+    0xa9d, 0x55a, 0x755, 0x749, 0xf13, 0xe4a, 0xa96, 0x556, 0x6b5, 0xbaa,
+    0xb94,
+];
+fn month_lengths(year: i32) -> Option<u16> {
+    if !(UMM_AL_QURA_MIN_YEAR..=UMM_AL_QURA_MAX_YEAR).contains(&year) {
+        return None;
+    }
+    return Some(UMM_AL_QURA_MONTHS[(year - UMM_AL_QURA_MIN_YEAR) as usize]);
+}
+fn days_in_year(lengths: u16) -> i64 {
+    return 29 * 12 + lengths.count_ones() as i64;
+}
+fn days_in_month(lengths: u16,month: u32) -> u32 {
+    return 29 + ((lengths >> (month - 1)) & 1) as u32;
+}
+/// Date of the Umm al-Qura calendar of Saudi Arabia, driven by the embedded table of month lengths.
+///
+/// Supported years are 1300 AH through 1600 AH (1882-11-12 through 2174-11-25).
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct UmmAlQuraDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+impl CalendarDate for UmmAlQuraDate {
+    const ERA: &'static str = "AH";
+    const WEEKDAYS: [&'static str; 7] = ISLAMIC_WEEKDAYS;
+    fn from_ymd_opt(year: i32,month: u32,day: u32) -> Option<UmmAlQuraDate> {
+        let lengths = month_lengths(year)?;
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(lengths,month) {
+            return None;
+        }
+        return Some(UmmAlQuraDate {
+            year: year,
+            month: month,
+            day: day,
+        });
+    }
+    fn from_num_days_from_ce(days: i64) -> Option<UmmAlQuraDate> {
+        if days < UMM_AL_QURA_EPOCH {
+            return None;
+        }
+        let mut day = days - UMM_AL_QURA_EPOCH;
+        let mut year = UMM_AL_QURA_MIN_YEAR;
+        let mut lengths = month_lengths(year)?;
+        while day >= days_in_year(lengths) {
+            day = day - days_in_year(lengths);
+            year = year + 1;
+            lengths = month_lengths(year)?;
+        }
+        let mut month = 1;
+        while day >= days_in_month(lengths,month) as i64 {
+            day = day - days_in_month(lengths,month) as i64;
+            month = month + 1;
+        }
+        return Some(UmmAlQuraDate {
+            year: year,
+            month: month,
+            day: day as u32 + 1,
+        });
+    }
+    fn num_days_from_ce(&self) -> i64 {
+        let mut days = UMM_AL_QURA_EPOCH + self.day as i64 - 1;
+        for year in UMM_AL_QURA_MIN_YEAR..self.year {
+            days = days + days_in_year(UMM_AL_QURA_MONTHS[(year - UMM_AL_QURA_MIN_YEAR) as usize]);
+        }
+        let lengths = UMM_AL_QURA_MONTHS[(self.year - UMM_AL_QURA_MIN_YEAR) as usize];
+        for month in 1..self.month {
+            days = days + days_in_month(lengths,month) as i64;
+        }
+        return days;
+    }
+    fn year(&self) -> i32 {
+        return self.year;
+    }
+    fn month(&self) -> u32 {
+        return self.month;
+    }
+    fn day(&self) -> u32 {
+        return self.day;
+    }
+    fn month_names(_year: i32) -> &'static [&'static str] {
+        return &ISLAMIC_MONTHS;
+    }
+}
@@ -0,0 +1,187 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::TimeZone;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::NaiveDateTime;
+use chrono::Datelike;
+use chrono::Timelike;
+use chrono::Duration;
+use arithmetic::days_in_month;
+use recurrence::{OffsetResolver,resolve_local};
+use super::iteration::{Schedule,Fires,find_local};
+/// Component of systemd calendar event field: value `start`, range `start..stop`,
+/// repetition `start/repeat` or `start..stop/repeat`.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct CalendarComponent {
+    /// The first value.
+    pub start: u32,
+    /// The last value of range.
+    pub stop: Option<u32>,
+    /// Repetition step, 0 if none.
+    pub repeat: u32,
+}
+impl CalendarComponent {
+    /// Returns true if the value matches.
+    pub fn matches(&self,value: u32) -> bool {
+        if value < self.start || self.stop.map_or(false,|stop| value > stop) {
+            return false;
+        }
+        return match (self.stop, self.repeat) {
+            (None, 0) => value == self.start,
+            (Some(_), 0) => true,
+            (_, repeat) => (value - self.start) % repeat == 0,
+        };
+    }
+    /// Returns true if the value counted from the end matches: `start` is the distance from the end
+    /// and repetitions approach the end (`~07/1` means the last seven days).
+    fn matches_from_end(&self,value: u32) -> bool {
+        let (low, high) = match self.stop {
+            Some(stop) => (self.start.min(stop), self.start.max(stop)),
+            None if self.repeat == 0 => (self.start, self.start),
+            None => (1, self.start),
+        };
+        if value < low || value > high {
+            return false;
+        }
+        return self.repeat == 0 || (high - value) % self.repeat == 0;
+    }
+}
+fn matches_chain(chain: &[CalendarComponent],value: u32) -> bool {
+    return chain.is_empty() || chain.iter().any(|component| component.matches(value));
+}
+/// systemd calendar event (`OnCalendar=` of timer units) such as `Mon..Fri *-*-* 09:00:00 Europe/Berlin`.
+///
+/// Empty chains mean `*`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CalendarEvent {
+    /// Weekdays as bits of the number of days from Monday, 0 if every weekday matches.
+    pub weekdays: u8,
+    /// Years, 1970 through 2199.
+    pub years: Vec<CalendarComponent>,
+    /// Months, 1 through 12.
+    pub months: Vec<CalendarComponent>,
+    /// Days of the month, 1 through 31, counted from the end of the month if `is_end_of_month`.
+    pub days: Vec<CalendarComponent>,
+    /// True if days are written after `~` and counted from the end of the month (`~01` is the last day).
+    pub is_end_of_month: bool,
+    /// Hours, 0 through 23.
+    pub hours: Vec<CalendarComponent>,
+    /// Minutes, 0 through 59.
+    pub minutes: Vec<CalendarComponent>,
+    /// Seconds, 0 through 59.
+    pub seconds: Vec<CalendarComponent>,
+    /// Time zone suffix such as `UTC` or `Europe/Berlin`, applied by `next_after_zoned` and `prev_before_zoned`.
+    pub timezone: Option<String>,
+}
+impl Schedule for CalendarEvent {
+    fn matches_year(&self,year: i32) -> bool {
+        return year >= 0 && matches_chain(&self.years,year as u32);
+    }
+    fn matches_month(&self,_: i32,month: u32) -> bool {
+        return matches_chain(&self.months,month);
+    }
+    fn matches_date(&self,date: NaiveDate) -> bool {
+        if !self.matches_year(date.year()) || !matches_chain(&self.months,date.month()) {
+            return false;
+        }
+        if self.weekdays != 0 && self.weekdays & (1 << date.weekday().num_days_from_monday()) == 0 {
+            return false;
+        }
+        if self.is_end_of_month {
+            let from_end = days_in_month(date.year(),date.month()) - date.day() + 1;
+            return self.days.is_empty() || self.days.iter().any(|component| component.matches_from_end(from_end));
+        }
+        return matches_chain(&self.days,date.day());
+    }
+    fn matches_hour(&self,hour: u32) -> bool {
+        return matches_chain(&self.hours,hour);
+    }
+    fn matches_minute(&self,minute: u32) -> bool {
+        return matches_chain(&self.minutes,minute);
+    }
+    fn matches_second(&self,second: u32) -> bool {
+        return matches_chain(&self.seconds,second);
+    }
+}
+impl CalendarEvent {
+    /// Returns true if the local date and time match the event. Fractions of a second are ignored.
+    pub fn matches(&self,datetime: &NaiveDateTime) -> bool {
+        let time: NaiveTime = datetime.time();
+        return Schedule::matches_date(self,datetime.date()) && self.matches_hour(time.hour())
+            && self.matches_minute(time.minute()) && self.matches_second(time.second());
+    }
+    /// Returns the first matching local time after `from` (or the last one before it if not `is_forward`).
+    pub fn find_local(&self,from: &NaiveDateTime,is_forward: bool) -> Option<NaiveDateTime> {
+        return find_local(self,from,is_forward);
+    }
+    /// Returns iterator over elapse times after `datetime` in its time zone.
+    ///
+    /// The `timezone` suffix is not applied: pass `datetime` in that time zone or use `next_after_zoned`.
+    /// Daylight saving transitions are handled as in `CronSchedule::next_after`.
+    pub fn next_after<'a,Tz: TimeZone>(&'a self,datetime: &DateTime<Tz>) -> Fires<'a,CalendarEvent,Tz> {
+        return Fires::new(self,datetime,true);
+    }
+    /// Returns iterator over elapse times before `datetime` in its time zone, from the latest to the earliest.
+    pub fn prev_before<'a,Tz: TimeZone>(&'a self,datetime: &DateTime<Tz>) -> Fires<'a,CalendarEvent,Tz> {
+        return Fires::new(self,datetime,false);
+    }
+    /// Returns iterator over elapse times after `datetime` in time zone of the `timezone` suffix.
+    ///
+    /// Offsets are found by `resolver`, called with the suffix (`None` for the local time zone).
+    /// Local times skipped by a transition elapse at the offset before it, as in `RecurrenceRule::occurrences`.
+    pub fn next_after_zoned<'a>(&'a self,datetime: &DateTime<FixedOffset>,resolver: OffsetResolver<'a>) -> ZonedElapses<'a> {
+        return ZonedElapses::new(self,datetime,resolver,true);
+    }
+    /// Returns iterator over elapse times before `datetime` in time zone of the `timezone` suffix,
+    /// from the latest to the earliest. See `next_after_zoned`.
+    pub fn prev_before_zoned<'a>(&'a self,datetime: &DateTime<FixedOffset>,resolver: OffsetResolver<'a>) -> ZonedElapses<'a> {
+        return ZonedElapses::new(self,datetime,resolver,false);
+    }
+}
+/// Iterator over elapse times of a calendar event in time zone of its `timezone` suffix.
+/// Created by `CalendarEvent::next_after_zoned` and `CalendarEvent::prev_before_zoned`.
+pub struct ZonedElapses<'a> {
+    event: &'a CalendarEvent,
+    resolver: OffsetResolver<'a>,
+    current: DateTime<FixedOffset>,
+    local: Option<NaiveDateTime>,
+    is_forward: bool,
+}
+impl<'a> ZonedElapses<'a> {
+    fn new(event: &'a CalendarEvent,datetime: &DateTime<FixedOffset>,resolver: OffsetResolver<'a>,is_forward: bool) -> ZonedElapses<'a> {
+        let tzid = event.timezone.as_deref();
+        // Offset of the instant is the offset of its local time, found from the UTC time as the first guess.
+        let utc = datetime.naive_utc();
+        let mut local = Some(utc);
+        for _ in 0..2 {
+            local = local.and_then(|local| resolver(tzid,&local).or_else(|| resolver(tzid,&local.checked_sub_signed(Duration::days(1))?)))
+                .and_then(|offset| utc.checked_add_signed(Duration::seconds(offset.local_minus_utc() as i64)));
+        }
+        return ZonedElapses {
+            event: event,
+            resolver: resolver,
+            current: *datetime,
+            local: local,
+            is_forward: is_forward,
+        };
+    }
+}
+impl<'a> Iterator for ZonedElapses<'a> {
+    type Item = DateTime<FixedOffset>;
+    fn next(&mut self) -> Option<DateTime<FixedOffset>> {
+        let tzid = self.event.timezone.as_deref();
+        loop {
+            let local = find_local(self.event,&self.local?,self.is_forward);
+            self.local = local;
+            let found = match resolve_local(self.resolver,tzid,&local?) {
+                Some(found) => found,
+                None => continue,
+            };
+            if (self.is_forward && found > self.current) || (!self.is_forward && found < self.current) {
+                self.current = found;
+                return Some(found);
+            }
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use chrono::DateTime;
+use chrono::TimeZone;
+use chrono::Offset;
+use chrono::LocalResult;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::NaiveDateTime;
+use chrono::Datelike;
+use chrono::Timelike;
+use chrono::Duration;
+use super::schedule::CronSchedule;
+const MIN_YEAR: i32 = 1;
+const MAX_YEAR: i32 = 9999;
+/// Returns instants of the local time. A local time skipped by a transition (such as `02:30`
+/// when clocks jump from `02:00` to `03:00`) is resolved with the offset before the transition.
+fn resolve<Tz: TimeZone>(timezone: &Tz,local: &NaiveDateTime) -> Vec<DateTime<Tz>> {
+    return match timezone.from_local_datetime(local) {
+        LocalResult::Single(datetime) => vec![datetime],
+        LocalResult::Ambiguous(earliest,latest) => vec![earliest, latest],
+        LocalResult::None => match timezone.from_local_datetime(&(*local - Duration::days(1))).earliest() {
+            Some(before) => vec![timezone.from_utc_datetime(&(*local - Duration::seconds(before.offset().fix().local_minus_utc() as i64)))],
+            None => Vec::new(),
+        },
+    };
+}
+/// Local date and time matching of a schedule. Implemented by `CronSchedule` and `CalendarEvent`.
+pub trait Schedule {
+    /// Returns true if some dates of the year may match.
+    fn matches_year(&self,year: i32) -> bool;
+    /// Returns true if some dates of the month may match.
+    fn matches_month(&self,year: i32,month: u32) -> bool;
+    /// Returns true if the date matches.
+    fn matches_date(&self,date: NaiveDate) -> bool;
+    /// Returns true if the hour matches.
+    fn matches_hour(&self,hour: u32) -> bool;
+    /// Returns true if the minute matches.
+    fn matches_minute(&self,minute: u32) -> bool;
+    /// Returns true if the second matches.
+    fn matches_second(&self,second: u32) -> bool;
+}
+/// Returns values from `from` (or from the edge if not `is_bounded`) in the direction of search.
+fn values(from: u32,is_bounded: bool,max: u32,is_forward: bool) -> Vec<u32> {
+    return match (is_forward, is_bounded) {
+        (true, true) => (from..max + 1).collect(),
+        (true, false) => (0..max + 1).collect(),
+        (false, true) => (0..from + 1).rev().collect(),
+        (false, false) => (0..max + 1).rev().collect(),
+    };
+}
+/// Returns the first matching time of a day at or after `from`, or the last one at or before it.
+fn find_time<S: Schedule + ?Sized>(schedule: &S,from: NaiveTime,is_forward: bool) -> Option<NaiveTime> {
+    for hour in values(from.hour(),true,23,is_forward) {
+        if !schedule.matches_hour(hour) {
+            continue;
+        }
+        let is_same_hour = hour == from.hour();
+        for minute in values(from.minute(),is_same_hour,59,is_forward) {
+            if !schedule.matches_minute(minute) {
+                continue;
+            }
+            let is_same_minute = is_same_hour && minute == from.minute();
+            for second in values(from.second(),is_same_minute,59,is_forward) {
+                if schedule.matches_second(second) {
+                    return NaiveTime::from_hms_opt(hour,minute,second);
+                }
+            }
+        }
+    }
+    return None;
+}
+/// Returns the first matching local time after `from` (or the last one before it if not `is_forward`).
+pub fn find_local<S: Schedule + ?Sized>(schedule: &S,from: &NaiveDateTime,is_forward: bool) -> Option<NaiveDateTime> {
+    let truncated = from.with_nanosecond(0)?;
+    let start = if is_forward {
+        truncated + Duration::seconds(1)
+    } else if truncated == *from {
+        truncated - Duration::seconds(1)
+    } else {
+        truncated
+    };
+    let mut date = start.date();
+    let mut time = Some(start.time());
+    let edge = if is_forward { NaiveTime::from_hms_opt(0, 0, 0).unwrap() } else { NaiveTime::from_hms_opt(23, 59, 59).unwrap() };
+    while date.year() >= MIN_YEAR && date.year() <= MAX_YEAR {
+        if !schedule.matches_year(date.year()) {
+            date = if is_forward { NaiveDate::from_ymd_opt(date.year() + 1,1,1)? } else { NaiveDate::from_ymd_opt(date.year() - 1,12,31)? };
+            time = None;
+            continue;
+        }
+        if !schedule.matches_month(date.year(),date.month()) {
+            let first = date.with_day(1)?;
+            date = if is_forward { (first + Duration::days(31)).with_day(1).unwrap() } else { first.pred() };
+            time = None;
+            continue;
+        }
+        if schedule.matches_date(date) {
+            if let Some(found) = find_time(schedule,time.unwrap_or(edge),is_forward) {
+                return Some(date.and_time(found));
+            }
+        }
+        date = if is_forward { date.succ() } else { date.pred() };
+        time = None;
+    }
+    return None;
+}
+impl CronSchedule {
+    /// Returns the first matching local time after `from` (or the last one before it if not `is_forward`).
+    pub fn find_local(&self,from: &NaiveDateTime,is_forward: bool) -> Option<NaiveDateTime> {
+        return find_local(self,from,is_forward);
+    }
+    /// Returns iterator over fire times after `datetime` in its time zone.
+    ///
+    /// Local times skipped by a daylight saving transition fire once after it, shifted by the length
+    /// of the gap (`02:30` fires at `03:30`). Local times repeated by a transition fire once, at
+    /// the earlier instant, unless `datetime` is already past it.
+    pub fn next_after<'a,Tz: TimeZone>(&'a self,datetime: &DateTime<Tz>) -> CronFires<'a,Tz> {
+        return Fires::new(self,datetime,true);
+    }
+    /// Returns iterator over fire times before `datetime` in its time zone, from the latest to the earliest.
+    ///
+    /// Daylight saving transitions are handled as in `next_after`.
+    pub fn prev_before<'a,Tz: TimeZone>(&'a self,datetime: &DateTime<Tz>) -> CronFires<'a,Tz> {
+        return Fires::new(self,datetime,false);
+    }
+}
+/// Iterator over fire times of a schedule. Created by `next_after` and `prev_before` of `CronSchedule` and `CalendarEvent`.
+pub struct Fires<'a,S: 'a + Schedule,Tz: TimeZone> {
+    schedule: &'a S,
+    current: DateTime<Tz>,
+    local: NaiveDateTime,
+    is_forward: bool,
+}
+/// Iterator over fire times of cron schedule.
+pub type CronFires<'a,Tz> = Fires<'a,CronSchedule,Tz>;
+impl<'a,S: Schedule,Tz: TimeZone> Fires<'a,S,Tz> {
+    /// Returns iterator over fire times after `datetime` (or before it if not `is_forward`) in its time zone.
+    pub fn new(schedule: &'a S,datetime: &DateTime<Tz>,is_forward: bool) -> Fires<'a,S,Tz> {
+        return Fires {
+            schedule: schedule,
+            current: datetime.clone(),
+            local: datetime.naive_local(),
+            is_forward: is_forward,
+        };
+    }
+}
+impl<'a,S: Schedule,Tz: TimeZone> Iterator for Fires<'a,S,Tz> {
+    type Item = DateTime<Tz>;
+    fn next(&mut self) -> Option<DateTime<Tz>> {
+        let timezone = self.current.timezone();
+        loop {
+            self.local = find_local(self.schedule,&self.local,self.is_forward)?;
+            let found = resolve(&timezone,&self.local).into_iter()
+                .find(|datetime| if self.is_forward { *datetime > self.current } else { *datetime < self.current });
+            if let Some(found) = found {
+                self.current = found.clone();
+                return Some(found);
+            }
+        }
+    }
+}
+#[cfg(test)]
+#[test]
+fn test_cron_fires() {
+    use chrono::FixedOffset;
+    use parser::parse_cron;
+    /// US Eastern time since 2007: daylight time from the second Sunday of March to the first Sunday of November.
+    #[derive(Copy, Clone, Debug)]
+    struct Eastern;
+    fn transitions(year: i32) -> (NaiveDateTime, NaiveDateTime) {
+        let sunday = |month: u32,n: u32| {
+            let first = NaiveDate::from_ymd(year,month,1);
+            return first + Duration::days(((7 - first.weekday().num_days_from_sunday()) % 7 + 7 * (n - 1)) as i64);
+        };
+        return (sunday(3,2).and_hms(2,0,0), sunday(11,1).and_hms(2,0,0));
+    }
+    impl TimeZone for Eastern {
+        type Offset = FixedOffset;
+        fn from_offset(_: &FixedOffset) -> Eastern {
+            return Eastern;
+        }
+        fn offset_from_local_date(&self,local: &NaiveDate) -> LocalResult<FixedOffset> {
+            return self.offset_from_local_datetime(&local.and_hms(12,0,0));
+        }
+        fn offset_from_local_datetime(&self,local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            let (begin, end) = transitions(local.year());
+            let (daylight, standard) = (FixedOffset::west(4 * 3600), FixedOffset::west(5 * 3600));
+            if *local >= begin && *local < begin + Duration::hours(1) {
+                return LocalResult::None;
+            }
+            if *local >= end - Duration::hours(1) && *local < end {
+                return LocalResult::Ambiguous(daylight,standard);
+            }
+            return LocalResult::Single(if *local > begin && *local < end { daylight } else { standard });
+        }
+        fn offset_from_utc_date(&self,utc: &NaiveDate) -> FixedOffset {
+            return self.offset_from_utc_datetime(&utc.and_hms(12,0,0));
+        }
+        fn offset_from_utc_datetime(&self,utc: &NaiveDateTime) -> FixedOffset {
+            let (begin, end) = transitions(utc.year());
+            let is_daylight = *utc >= begin + Duration::hours(5) && *utc < end + Duration::hours(4);
+            return FixedOffset::west(if is_daylight { 4 * 3600 } else { 5 * 3600 });
+        }
+    }
+    let testcrons = [
+        ("*/20 9-17 * * MON-FRI", (2023,10,16,12,0), true, ["2023-10-16 16:20:00", "2023-10-16 16:40:00", "2023-10-16 17:00:00"]),
+        ("0 0 L * ?", (2023,10,16,12,0), true, ["2023-10-31 04:00:00", "2023-11-30 05:00:00", "2023-12-31 05:00:00"]),
+        ("0 0 12 ? * FRI#3", (2023,10,16,12,0), true, ["2023-10-20 16:00:00", "2023-11-17 17:00:00", "2023-12-15 17:00:00"]),
+        ("@yearly", (2023,10,16,12,0), false, ["2023-01-01 05:00:00", "2022-01-01 05:00:00", "2021-01-01 05:00:00"]),
+        ("0 12 * * *", (2023,10,16,12,0), false, ["2023-10-15 16:00:00", "2023-10-14 16:00:00", "2023-10-13 16:00:00"]),
+        // 02:30 is skipped by the transition on 2023-03-12 and fires at 03:30 daylight time.
+        ("30 2 * * *", (2023,3,11,12,0), true, ["2023-03-12 07:30:00", "2023-03-13 06:30:00", "2023-03-14 06:30:00"]),
+        ("*/30 * * * *", (2023,3,12,1,15), true, ["2023-03-12 06:30:00", "2023-03-12 07:00:00", "2023-03-12 07:30:00"]),
+        ("0,30 * * * *", (2023,3,12,3,15), false, ["2023-03-12 07:00:00", "2023-03-12 06:30:00", "2023-03-12 06:00:00"]),
+        // 01:30 is repeated on 2023-11-05 and fires once.
+        ("30 1 * * *", (2023,11,4,12,0), true, ["2023-11-05 05:30:00", "2023-11-06 06:30:00", "2023-11-07 06:30:00"]),
+        ("*/30 * * * *", (2023,11,5,0,45), true, ["2023-11-05 05:00:00", "2023-11-05 05:30:00", "2023-11-05 07:00:00"]),
+        ("*/30 * * * *", (2023,11,5,2,15), false, ["2023-11-05 07:00:00", "2023-11-05 05:30:00", "2023-11-05 05:00:00"]),
+    ];
+    for &(cron, (year, month, day, hour, minute), is_forward, ref checkfires) in testcrons.iter() {
+        let schedule = parse_cron(cron).unwrap();
+        let start = Eastern.ymd(year,month,day).and_hms(hour,minute,0);
+        let fires = if is_forward { schedule.next_after(&start) } else { schedule.prev_before(&start) };
+        let result: Vec<String> = fires.take(3).map(|datetime| datetime.naive_utc().to_string()).collect();
+        if result != *checkfires {
+            panic!("Cron fires failed for {} from {:?}\nReceived: {:?}\nExpected: {:?}",
+                   cron, start, result, checkfires);
+        }
+    }
+    // Starting within the repeated hour continues with its second pass.
+    let start = Eastern.from_utc_datetime(&NaiveDate::from_ymd(2023,11,5).and_hms(6,10,0));
+    let fires: Vec<String> = parse_cron("*/30 * * * *").unwrap().next_after(&start).take(2).map(|datetime| datetime.naive_utc().to_string()).collect();
+    assert_eq!(fires, ["2023-11-05 06:30:00", "2023-11-05 07:00:00"]);
+    assert_eq!(parse_cron("0 0 30 2 *").unwrap().next_after(&start).next(), None);
+}
@@ -0,0 +1,6 @@
+mod schedule;
+mod iteration;
+mod calendar_event;
+pub use self::schedule::{CronSchedule,SpecialDay};
+pub use self::iteration::{Schedule,Fires,CronFires};
+pub use self::calendar_event::{CalendarEvent,CalendarComponent,ZonedElapses};
\ No newline at end of file
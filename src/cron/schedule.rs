@@ -0,0 +1,131 @@
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::NaiveDateTime;
+use chrono::Datelike;
+use chrono::Timelike;
+use chrono::Weekday;
+use arithmetic::{days_in_month,is_weekend};
+use super::iteration::Schedule;
+/// Quartz day specification of the day-of-month or day-of-week field.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SpecialDay {
+    /// `L` or `L-3`: the last day of the month minus the given number of days.
+    LastDay(u32),
+    /// `15W`: the weekday (Monday to Friday) nearest to the given day within the same month.
+    NearestWeekday(u32),
+    /// `LW`: the last weekday (Monday to Friday) of the month.
+    LastWeekday,
+    /// `FRI#3`: the given occurrence of the weekday in the month, 1 through 5.
+    NthWeekday(Weekday, u32),
+    /// `FRIL`: the last occurrence of the weekday in the month.
+    LastOfWeekday(Weekday),
+}
+impl SpecialDay {
+    /// Returns true if the date matches the specification.
+    pub fn matches(&self,date: NaiveDate) -> bool {
+        let last_day = days_in_month(date.year(),date.month());
+        match *self {
+            SpecialDay::LastDay(offset) => return offset < last_day && date.day() == last_day - offset,
+            SpecialDay::NearestWeekday(day) => {
+                if day > last_day || is_weekend(date) {
+                    return false;
+                }
+                let target = date.with_day(day).unwrap();
+                let nearest = match target.weekday() {
+                    Weekday::Sat if day == 1 => day + 2,
+                    Weekday::Sat => day - 1,
+                    Weekday::Sun if day == last_day => day - 2,
+                    Weekday::Sun => day + 1,
+                    _ => day,
+                };
+                return date.day() == nearest;
+            },
+            SpecialDay::LastWeekday => return !is_weekend(date) && (date.day() + 1..last_day + 1)
+                .all(|day| is_weekend(date.with_day(day).unwrap())),
+            SpecialDay::NthWeekday(weekday,n) => return date.weekday() == weekday && (date.day() - 1) / 7 + 1 == n,
+            SpecialDay::LastOfWeekday(weekday) => return date.weekday() == weekday && date.day() + 7 > last_day,
+        }
+    }
+}
+/// Cron schedule. Fields are bit sets where bit `n` is set when value `n` matches.
+///
+/// As in Vixie cron, when both day-of-month and day-of-week fields are restricted
+/// (neither starts with `*` nor is `?`) a day matches if either field matches.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CronSchedule {
+    /// Seconds, 0 through 59. Only second 0 for five-field expressions.
+    pub seconds: u64,
+    /// Minutes, 0 through 59.
+    pub minutes: u64,
+    /// Hours, 0 through 23.
+    pub hours: u32,
+    /// Days of the month, 1 through 31.
+    pub days_of_month: u32,
+    /// Quartz specifications of the day-of-month field (`L`, `W`).
+    pub special_days_of_month: Vec<SpecialDay>,
+    /// True if the day-of-month field is unrestricted.
+    pub is_day_of_month_any: bool,
+    /// Months, 1 through 12.
+    pub months: u16,
+    /// Days of the week as the number of days from Sunday, 0 through 6.
+    pub days_of_week: u8,
+    /// Quartz specifications of the day-of-week field (`L`, `#`).
+    pub special_days_of_week: Vec<SpecialDay>,
+    /// True if the day-of-week field is unrestricted.
+    pub is_day_of_week_any: bool,
+    /// Sorted years, empty if every year matches.
+    pub years: Vec<i32>,
+}
+fn has_bit(bits: u64,value: u32) -> bool {
+    return bits & (1 << value) != 0;
+}
+impl CronSchedule {
+    /// Returns true if the year matches.
+    pub fn matches_year(&self,year: i32) -> bool {
+        return self.years.is_empty() || self.years.binary_search(&year).is_ok();
+    }
+    /// Returns true if the date matches the year, month and day fields.
+    pub fn matches_date(&self,date: NaiveDate) -> bool {
+        if !self.matches_year(date.year()) || !has_bit(self.months as u64,date.month()) {
+            return false;
+        }
+        let day_of_month = has_bit(self.days_of_month as u64,date.day())
+            || self.special_days_of_month.iter().any(|day| day.matches(date));
+        let day_of_week = has_bit(self.days_of_week as u64,date.weekday().num_days_from_sunday())
+            || self.special_days_of_week.iter().any(|day| day.matches(date));
+        return match (self.is_day_of_month_any, self.is_day_of_week_any) {
+            (true, true) => true,
+            (true, false) => day_of_week,
+            (false, true) => day_of_month,
+            (false, false) => day_of_month || day_of_week,
+        };
+    }
+    /// Returns true if the time matches the hour, minute and second fields. Fractions of a second are ignored.
+    pub fn matches_time(&self,time: NaiveTime) -> bool {
+        return has_bit(self.hours as u64,time.hour()) && has_bit(self.minutes,time.minute()) && has_bit(self.seconds,time.second());
+    }
+    /// Returns true if the local date and time match the schedule.
+    pub fn matches(&self,datetime: &NaiveDateTime) -> bool {
+        return self.matches_date(datetime.date()) && self.matches_time(datetime.time());
+    }
+}
+impl Schedule for CronSchedule {
+    fn matches_year(&self,year: i32) -> bool {
+        return CronSchedule::matches_year(self,year);
+    }
+    fn matches_month(&self,_: i32,month: u32) -> bool {
+        return has_bit(self.months as u64,month);
+    }
+    fn matches_date(&self,date: NaiveDate) -> bool {
+        return CronSchedule::matches_date(self,date);
+    }
+    fn matches_hour(&self,hour: u32) -> bool {
+        return has_bit(self.hours as u64,hour);
+    }
+    fn matches_minute(&self,minute: u32) -> bool {
+        return has_bit(self.minutes,minute);
+    }
+    fn matches_second(&self,second: u32) -> bool {
+        return has_bit(self.seconds,second);
+    }
+}
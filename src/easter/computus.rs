@@ -0,0 +1,159 @@
+use chrono::NaiveDate;
+use chrono::Duration;
+use julian_calendar::JulianDate;
+/// Easter computation method.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Computus {
+    /// Western (Catholic and Protestant) Easter by the Gregorian computus.
+    Western,
+    /// Orthodox Easter by the Julian computus, returned as a Gregorian date.
+    Orthodox,
+}
+/// Movable feast relative to Easter Sunday.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MovableFeast {
+    /// Ash Wednesday, 46 days before Easter (Western churches).
+    AshWednesday,
+    /// Palm Sunday, a week before Easter.
+    PalmSunday,
+    /// Maundy Thursday, 3 days before Easter.
+    MaundyThursday,
+    /// Good Friday, 2 days before Easter.
+    GoodFriday,
+    /// Holy Saturday, the day before Easter.
+    HolySaturday,
+    /// Easter Sunday.
+    EasterSunday,
+    /// Easter Monday, the day after Easter.
+    EasterMonday,
+    /// Ascension Day, 39 days after Easter.
+    Ascension,
+    /// Pentecost (Whit Sunday), 49 days after Easter.
+    Pentecost,
+    /// Whit Monday, 50 days after Easter.
+    WhitMonday,
+    /// Corpus Christi, 60 days after Easter (Western churches).
+    CorpusChristi,
+}
+impl MovableFeast {
+    /// Returns days after Easter Sunday, negative for feasts before it.
+    pub fn days_after_easter(&self) -> i64 {
+        match *self {
+            MovableFeast::AshWednesday => -46,
+            MovableFeast::PalmSunday => -7,
+            MovableFeast::MaundyThursday => -3,
+            MovableFeast::GoodFriday => -2,
+            MovableFeast::HolySaturday => -1,
+            MovableFeast::EasterSunday => 0,
+            MovableFeast::EasterMonday => 1,
+            MovableFeast::Ascension => 39,
+            MovableFeast::Pentecost => 49,
+            MovableFeast::WhitMonday => 50,
+            MovableFeast::CorpusChristi => 60,
+        }
+    }
+    /// Returns date of the feast in the year. Returns `None` if the date is out of chrono range.
+    pub fn date(&self,year: i32,computus: Computus) -> Option<NaiveDate> {
+        return easter(year,computus)?.checked_add_signed(Duration::days(self.days_after_easter()));
+    }
+}
+/// Returns Western Easter Sunday of the proleptic Gregorian calendar by the Meeus/Jones/Butcher algorithm.
+/// Returns `None` if the date is out of chrono range.
+pub fn western_easter(year: i32) -> Option<NaiveDate> {
+    let year = year as i64;
+    let (a, b, c) = (year.rem_euclid(19), year.div_euclid(100), year.rem_euclid(100));
+    let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let l = (32 + 2 * e + 2 * (c / 4) - h - c % 4).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    return NaiveDate::from_ymd_opt(year as i32,month as u32,day as u32);
+}
+/// Returns Orthodox Easter Sunday by the Julian computus (Meeus algorithm) converted from the Julian
+/// to the proleptic Gregorian calendar. Returns `None` if the date is out of chrono range.
+pub fn orthodox_easter(year: i32) -> Option<NaiveDate> {
+    let year = year as i64;
+    let d = (19 * year.rem_euclid(19) + 15) % 30;
+    let e = (2 * year.rem_euclid(4) + 4 * year.rem_euclid(7) - d + 34).rem_euclid(7);
+    let month = (d + e + 114) / 31;
+    let day = (d + e + 114) % 31 + 1;
+    return JulianDate::from_ymd_opt(year as i32,month as u32,day as u32)?.to_naive_date();
+}
+/// Returns Easter Sunday of the year by the computus.
+pub fn easter(year: i32,computus: Computus) -> Option<NaiveDate> {
+    return match computus {
+        Computus::Western => western_easter(year),
+        Computus::Orthodox => orthodox_easter(year),
+    };
+}
+/// Returns Good Friday of the year.
+pub fn good_friday(year: i32,computus: Computus) -> Option<NaiveDate> {
+    return MovableFeast::GoodFriday.date(year,computus);
+}
+/// Returns Ascension Day of the year.
+pub fn ascension(year: i32,computus: Computus) -> Option<NaiveDate> {
+    return MovableFeast::Ascension.date(year,computus);
+}
+/// Returns Pentecost of the year.
+pub fn pentecost(year: i32,computus: Computus) -> Option<NaiveDate> {
+    return MovableFeast::Pentecost.date(year,computus);
+}
+#[cfg(test)]
+#[test]
+fn test_easter() {
+    use chrono::{Datelike,Weekday,MIN_DATE,MAX_DATE};
+    let date = |text: &str| NaiveDate::parse_from_str(text,"%Y-%m-%d").unwrap();
+    let testyears = [
+        (1583, "1583-04-10", "1583-04-10"),
+        (1818, "1818-03-22", "1818-04-26"),
+        (1900, "1900-04-15", "1900-04-22"),
+        (1943, "1943-04-25", "1943-04-25"),
+        (1961, "1961-04-02", "1961-04-09"),
+        (1990, "1990-04-15", "1990-04-15"),
+        (2000, "2000-04-23", "2000-04-30"),
+        (2010, "2010-04-04", "2010-04-04"),
+        (2021, "2021-04-04", "2021-05-02"),
+        (2023, "2023-04-09", "2023-04-16"),
+        (2024, "2024-03-31", "2024-05-05"),
+        (2025, "2025-04-20", "2025-04-20"),
+        (2038, "2038-04-25", "2038-04-25"),
+        (2100, "2100-03-28", "2100-05-02"),
+        (2285, "2285-03-22", "2285-04-26"),
+    ];
+    for &(year, checkwestern, checkorthodox) in testyears.iter() {
+        let result = (western_easter(year), orthodox_easter(year));
+        let checkresult = (Some(date(checkwestern)), Some(date(checkorthodox)));
+        if result != checkresult {
+            panic!("Easter computation failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   year, result, checkresult);
+        }
+    }
+    assert_eq!(good_friday(2024,Computus::Western), Some(date("2024-03-29")));
+    assert_eq!(ascension(2024,Computus::Western), Some(date("2024-05-09")));
+    assert_eq!(pentecost(2024,Computus::Western), Some(date("2024-05-19")));
+    assert_eq!(good_friday(2024,Computus::Orthodox), Some(date("2024-05-03")));
+    assert_eq!(ascension(2024,Computus::Orthodox), Some(date("2024-06-13")));
+    assert_eq!(pentecost(2024,Computus::Orthodox), Some(date("2024-06-23")));
+    assert_eq!(MovableFeast::AshWednesday.date(2024,Computus::Western), Some(date("2024-02-14")));
+    assert_eq!(MovableFeast::CorpusChristi.date(2024,Computus::Western), Some(date("2024-05-30")));
+    // Every year of chrono range: Easter is a Sunday from March 22 through April 25 of the calendar in use.
+    // Orthodox Easter of the first and last years is out of range because of the calendar shift.
+    for year in MIN_DATE.year()..MAX_DATE.year() + 1 {
+        let western = western_easter(year).unwrap();
+        let orthodox = match orthodox_easter(year) {
+            Some(orthodox) => orthodox,
+            None if year.abs() > 255_000 => continue,
+            None => panic!("Easter computation failed for {}\nReceived: None", year),
+        };
+        let julian_shift = year.div_euclid(100) - year.div_euclid(400) - 2;
+        let orthodox_julian = orthodox - Duration::days(julian_shift as i64);
+        let is_in_season = |easter: NaiveDate| easter >= NaiveDate::from_ymd(easter.year(),3,22) && easter <= NaiveDate::from_ymd(easter.year(),4,25);
+        if western.weekday() != Weekday::Sun || !is_in_season(western) || orthodox.weekday() != Weekday::Sun || !is_in_season(orthodox_julian) {
+            panic!("Easter computation failed for {}\nReceived: {:?} {:?}", year, western, orthodox);
+        }
+    }
+    assert_eq!(western_easter(MAX_DATE.year() + 1), None);
+}
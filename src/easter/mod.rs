@@ -0,0 +1,2 @@
+mod computus;
+pub use self::computus::{Computus,MovableFeast,easter,western_easter,orthodox_easter,good_friday,ascension,pentecost};
\ No newline at end of file
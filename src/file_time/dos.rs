@@ -0,0 +1,83 @@
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::NaiveDateTime;
+use chrono::Datelike;
+use chrono::Timelike;
+use parser::error::{ParseErrorKind,ParseError,ParseResult};
+/// MS-DOS date and time pair used by FAT file systems and ZIP archives.
+///
+/// Date and time are local, seconds are stored with 2-second resolution.
+/// Years 1980 through 2107 are supported.
+///
+/// Errors of decoding have bit positions in the 32-bit value `date << 16 | time`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DosDateTime {
+    /// Bits 9-15: year since 1980, bits 5-8: month, bits 0-4: day.
+    pub date: u16,
+    /// Bits 11-15: hour, bits 5-10: minute, bits 0-4: seconds divided by 2.
+    pub time: u16,
+}
+impl DosDateTime {
+    /// Encodes local date and time. Odd seconds and fraction of second are truncated.
+    ///
+    /// Returns `InvalidLowValue` or `InvalidHighValue` error for years out of 1980 through 2107.
+    pub fn from_naive(datetime: &NaiveDateTime) -> ParseResult<DosDateTime> {
+        let year = datetime.year();
+        if year < 1980 {
+            return Err(ParseError::invalid_low_value(25,7));
+        }
+        if year > 2107 {
+            return Err(ParseError::invalid_high_value(25,7));
+        }
+        let date = ((year - 1980) as u16) << 9 | (datetime.month() as u16) << 5 | datetime.day() as u16;
+        let second = datetime.second() + datetime.nanosecond() / 1_000_000_000;
+        let time = (datetime.hour() as u16) << 11 | (datetime.minute() as u16) << 5 | (second / 2) as u16;
+        return Ok(DosDateTime {
+            date: date,
+            time: time,
+        });
+    }
+    /// Decodes local date and time.
+    pub fn to_naive(&self) -> ParseResult<NaiveDateTime> {
+        let year = 1980 + (self.date >> 9) as i32;
+        let month = validate_field((self.date >> 5 & 0x0f) as u32,1,12,21,4)?;
+        let day = validate_field((self.date & 0x1f) as u32,1,31,16,5)?;
+        let hour = validate_field((self.time >> 11) as u32,0,23,11,5)?;
+        let minute = validate_field((self.time >> 5 & 0x3f) as u32,0,59,5,6)?;
+        let second = validate_field((self.time & 0x1f) as u32 * 2,0,58,0,5)?;
+        let date = match NaiveDate::from_ymd_opt(year,month,day) {
+            Some(date) => date,
+            None => return Err(ParseError::invalid(ParseErrorKind::InvalidDate,16,16)),
+        };
+        return match NaiveTime::from_hms_opt(hour,minute,second) {
+            Some(time) => Ok(date.and_time(time)),
+            None => Err(ParseError::invalid(ParseErrorKind::InvalidTime,0,16)),
+        };
+    }
+}
+fn validate_field(value: u32,min: u32,max: u32,position: usize,length: usize) -> ParseResult<u32> {
+    if value < min {
+        return Err(ParseError::invalid_low_value(position,length));
+    }
+    if value > max {
+        return Err(ParseError::invalid_high_value(position,length));
+    }
+    return Ok(value);
+}
+#[cfg(test)]
+#[test]
+fn test_dos_date_time() {
+    let datetime = NaiveDate::from_ymd(2015, 1, 20).and_hms(17, 35, 21);
+    let dos = DosDateTime::from_naive(&datetime).unwrap();
+    assert_eq!(dos, DosDateTime { date: 0x4634, time: 0x8c6a });
+    assert_eq!(format!("{}", dos.to_naive().unwrap()), "2015-01-20 17:35:20");
+    assert_eq!(DosDateTime { date: 0x0021, time: 0 }.to_naive().unwrap(), NaiveDate::from_ymd(1980, 1, 1).and_hms(0, 0, 0));
+    assert_eq!(DosDateTime { date: 0xffff, time: 0 }.to_naive().unwrap_err().error_kind, ParseErrorKind::InvalidHighValue);
+    assert_eq!(DosDateTime { date: 0x0020, time: 0 }.to_naive().unwrap_err(), ParseError::invalid_low_value(16,5));
+    assert_eq!(DosDateTime { date: 0x0021, time: 0xc000 }.to_naive().unwrap_err(), ParseError::invalid_high_value(11,5));
+    assert_eq!(DosDateTime { date: 0x005e, time: 0 }.to_naive().unwrap_err().error_kind, ParseErrorKind::InvalidDate);
+    let error = DosDateTime::from_naive(&NaiveDate::from_ymd(1979, 12, 31).and_hms(0, 0, 0)).unwrap_err();
+    assert_eq!(error.error_kind, ParseErrorKind::InvalidLowValue);
+    let error = DosDateTime::from_naive(&NaiveDate::from_ymd(2108, 1, 1).and_hms(0, 0, 0)).unwrap_err();
+    assert_eq!(error.error_kind, ParseErrorKind::InvalidHighValue);
+}
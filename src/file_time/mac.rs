@@ -0,0 +1,81 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Duration;
+use chrono::Timelike;
+use parser::error::{ParseError,ParseResult};
+fn hfs_epoch() -> NaiveDateTime {
+    return NaiveDate::from_ymd_opt(1904, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+}
+fn apfs_epoch() -> NaiveDateTime {
+    return NaiveDate::from_ymd_opt(1970, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+}
+fn seconds_since(datetime: &NaiveDateTime,epoch: NaiveDateTime) -> ParseResult<i64> {
+    return match datetime.with_nanosecond(0) {
+        Some(datetime) => Ok(datetime.signed_duration_since(epoch).num_seconds()),
+        None => Err(ParseError::invalid_format(0,0)),
+    };
+}
+/// Returns HFS+ timestamp: count of seconds since 1904-01-01 UTC.
+///
+/// Fraction of second is truncated.
+/// Returns `InvalidLowValue` or `InvalidHighValue` error for dates out of 1904-01-01 through 2040-02-06.
+pub fn to_hfs_plus(datetime: &DateTime<FixedOffset>) -> ParseResult<u32> {
+    let seconds = seconds_since(&datetime.naive_utc(),hfs_epoch())?;
+    if seconds < 0 {
+        return Err(ParseError::invalid_low_value(0,0));
+    }
+    if seconds > u32::MAX as i64 {
+        return Err(ParseError::invalid_high_value(0,0));
+    }
+    return Ok(seconds as u32);
+}
+/// Returns UTC `DateTime` of HFS+ timestamp.
+pub fn from_hfs_plus(timestamp: u32) -> DateTime<FixedOffset> {
+    let utc = hfs_epoch() + Duration::seconds(timestamp as i64);
+    return DateTime::from_utc(utc, FixedOffset::east_opt(0).unwrap());
+}
+/// Returns APFS timestamp: count of nanoseconds since 1970-01-01 UTC.
+///
+/// Returns `InvalidLowValue` or `InvalidHighValue` error for dates out of 1970-01-01 through 2554-07-21.
+pub fn to_apfs(datetime: &DateTime<FixedOffset>) -> ParseResult<u64> {
+    let utc = datetime.naive_utc();
+    let seconds = seconds_since(&utc,apfs_epoch())?;
+    if seconds < 0 {
+        return Err(ParseError::invalid_low_value(0,0));
+    }
+    let nanoseconds = (seconds as u64).checked_mul(1_000_000_000).and_then(|nanoseconds| {
+        nanoseconds.checked_add(utc.nanosecond() as u64)
+    });
+    return match nanoseconds {
+        Some(nanoseconds) => Ok(nanoseconds),
+        None => Err(ParseError::invalid_high_value(0,0)),
+    };
+}
+/// Returns UTC `DateTime` of APFS timestamp.
+pub fn from_apfs(timestamp: u64) -> DateTime<FixedOffset> {
+    let seconds = Duration::seconds((timestamp / 1_000_000_000) as i64);
+    let nanoseconds = Duration::nanoseconds((timestamp % 1_000_000_000) as i64);
+    return DateTime::from_utc(apfs_epoch() + seconds + nanoseconds, FixedOffset::east_opt(0).unwrap());
+}
+#[cfg(test)]
+#[test]
+fn test_mac_timestamps() {
+    use parser::parse_w3c_datetime;
+    use parser::error::ParseErrorKind;
+    use formatter::format_w3c;
+    let datetime = parse_w3c_datetime("2015-01-20T17:35:20.123456789-08:00").unwrap();
+    assert_eq!(to_hfs_plus(&datetime), Ok(3504648920));
+    assert_eq!(format_w3c(&from_hfs_plus(3504648920)), "2015-01-21T01:35:20Z");
+    assert_eq!(format_w3c(&from_hfs_plus(u32::MAX)), "2040-02-06T06:28:15Z");
+    assert_eq!(to_apfs(&datetime), Ok(1421804120123456789));
+    assert_eq!(from_apfs(1421804120123456789), datetime);
+    assert_eq!(format_w3c(&from_apfs(u64::MAX)), "2554-07-21T23:34:33.709551615Z");
+
+    let datetime = parse_w3c_datetime("1903-12-31T23:59:59Z").unwrap();
+    assert_eq!(to_hfs_plus(&datetime).unwrap_err().error_kind, ParseErrorKind::InvalidLowValue);
+    assert_eq!(to_apfs(&datetime).unwrap_err().error_kind, ParseErrorKind::InvalidLowValue);
+    let datetime = parse_w3c_datetime("2040-02-06T06:28:16Z").unwrap();
+    assert_eq!(to_hfs_plus(&datetime).unwrap_err().error_kind, ParseErrorKind::InvalidHighValue);
+}
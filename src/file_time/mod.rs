@@ -0,0 +1,6 @@
+mod windows;
+mod dos;
+mod mac;
+pub use self::windows::{to_filetime,from_filetime};
+pub use self::dos::DosDateTime;
+pub use self::mac::{to_hfs_plus,from_hfs_plus,to_apfs,from_apfs};
\ No newline at end of file
@@ -0,0 +1,64 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Duration;
+use chrono::Timelike;
+use parser::error::{ParseError,ParseResult};
+/// FILETIME ticks per second.
+const TICKS_PER_SECOND: u64 = 10_000_000;
+fn filetime_epoch() -> NaiveDateTime {
+    return NaiveDate::from_ymd_opt(1601, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+}
+/// Returns Windows FILETIME: count of 100-nanosecond intervals since 1601-01-01 UTC.
+///
+/// NTFS timestamps are stored in the same format.
+/// Returns `InvalidLowValue` error for dates before 1601.
+pub fn to_filetime(datetime: &DateTime<FixedOffset>) -> ParseResult<u64> {
+    let utc = datetime.naive_utc();
+    let seconds = match utc.with_nanosecond(0) {
+        Some(seconds) => seconds.signed_duration_since(filetime_epoch()).num_seconds(),
+        None => return Err(ParseError::invalid_format(0,0)),
+    };
+    if seconds < 0 {
+        return Err(ParseError::invalid_low_value(0,0));
+    }
+    let ticks = (seconds as u64).checked_mul(TICKS_PER_SECOND).and_then(|ticks| {
+        ticks.checked_add(utc.nanosecond() as u64 / 100)
+    });
+    return match ticks {
+        Some(ticks) => Ok(ticks),
+        None => Err(ParseError::invalid_high_value(0,0)),
+    };
+}
+/// Returns UTC `DateTime` of Windows FILETIME.
+pub fn from_filetime(filetime: u64) -> ParseResult<DateTime<FixedOffset>> {
+    let seconds = Duration::seconds((filetime / TICKS_PER_SECOND) as i64);
+    let nanoseconds = Duration::nanoseconds(((filetime % TICKS_PER_SECOND) * 100) as i64);
+    return match filetime_epoch().checked_add_signed(seconds + nanoseconds) {
+        Some(utc) => Ok(DateTime::from_utc(utc, FixedOffset::east_opt(0).unwrap())),
+        None => Err(ParseError::invalid_high_value(0,0)),
+    };
+}
+#[cfg(test)]
+#[test]
+fn test_filetime() {
+    use parser::parse_w3c_datetime;
+    use parser::error::ParseErrorKind;
+    use formatter::format_w3c;
+    let testdata = [
+        ("1601-01-01T00:00:00Z", Ok(0)),
+        ("1970-01-01T00:00:00Z", Ok(116444736000000000)),
+        ("2015-01-20T17:35:20.1234567-08:00", Ok(130662777201234567)),
+        ("1600-12-31T23:59:59Z", Err(ParseErrorKind::InvalidLowValue)),
+    ];
+    for &(date, expected) in testdata.iter() {
+        let datetime = parse_w3c_datetime(date).unwrap();
+        let result = to_filetime(&datetime).map_err(|e| e.error_kind);
+        assert_eq!(result, expected, "{}", date);
+        if let Ok(filetime) = result {
+            assert_eq!(from_filetime(filetime).unwrap(), datetime);
+        }
+    }
+    assert_eq!(format_w3c(&from_filetime(u64::MAX).unwrap()), "+60056-05-28T05:36:10.955161500Z");
+}
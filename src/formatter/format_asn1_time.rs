@@ -0,0 +1,32 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Datelike;
+use chrono::Timelike;
+use parser::error::{ParseError,ParseResult};
+/// Returns an ASN.1 UTCTime string in DER form such as `150120173520Z`.
+///
+/// Date is converted to UTC, fraction of second is truncated.
+/// Returns `InvalidLowValue` or `InvalidHighValue` error for years out of 1950 through 2049.
+pub fn format_utc_time(datetime: &DateTime<FixedOffset>) -> ParseResult<String> {
+    let utc = datetime.naive_utc();
+    if utc.year() < 1950 {
+        return Err(ParseError::invalid_low_value(0,2));
+    }
+    if utc.year() > 2049 {
+        return Err(ParseError::invalid_high_value(0,2));
+    }
+    return Ok(format!("{}",utc.format("%y%m%d%H%M%SZ")));
+}
+/// Returns an ASN.1 GeneralizedTime string in DER form such as `20150120173520.25Z`.
+///
+/// Date is converted to UTC, fraction of second has no trailing zeros.
+pub fn format_generalized_time(datetime: &DateTime<FixedOffset>) -> String {
+    let utc = datetime.naive_utc();
+    let mut result = format!("{}",utc.format("%Y%m%d%H%M%S"));
+    let nanosecond = utc.nanosecond() % 1_000_000_000;
+    if nanosecond > 0 {
+        let fraction = format!("{:09}",nanosecond);
+        result = result + "." + fraction.trim_end_matches('0');
+    }
+    return result + "Z";
+}
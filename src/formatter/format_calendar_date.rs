@@ -0,0 +1,14 @@
+use calendars::CalendarDate;
+use super::format_localized::FormatStyle;
+/// Returns a date of a non-Gregorian calendar in the given style:
+/// `Full` such as `Yom Sheni, 1 Cheshvan 5784 AM`, `Long` such as `1 Cheshvan 5784 AM`,
+/// `Medium` such as `1 Cheshvan 5784` and `Short` such as `5784-02-01`.
+pub fn format_calendar_date<D: CalendarDate>(date: &D,style: FormatStyle) -> String {
+    let medium = format!("{} {} {}",date.day(),date.month_name(),date.year());
+    return match style {
+        FormatStyle::Full => format!("{}, {} {}",date.weekday_name(),medium,D::ERA),
+        FormatStyle::Long => format!("{} {}",medium,D::ERA),
+        FormatStyle::Medium => medium,
+        FormatStyle::Short => format!("{:04}-{:02}-{:02}",date.year(),date.month(),date.day()),
+    };
+}
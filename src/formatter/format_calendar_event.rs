@@ -0,0 +1,55 @@
+use cron::{CalendarEvent,CalendarComponent};
+const WEEKDAY_NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+fn format_chain(chain: &[CalendarComponent],width: usize) -> String {
+    if chain.is_empty() {
+        return "*".to_string();
+    }
+    return chain.iter().map(|component| {
+        let mut text = format!("{:01$}",component.start,width);
+        if let Some(stop) = component.stop {
+            text.push_str(&format!("..{:01$}",stop,width));
+        }
+        if component.repeat > 0 {
+            text.push_str(&format!("/{}",component.repeat));
+        }
+        return text;
+    }).collect::<Vec<String>>().join(",");
+}
+/// Returns weekdays with runs of three or more days as ranges, such as `Mon..Wed,Fri,Sat`.
+fn format_weekdays(weekdays: u8) -> String {
+    let mut parts = Vec::new();
+    let mut weekday = 0;
+    while weekday < 7 {
+        if weekdays & (1 << weekday) == 0 {
+            weekday += 1;
+            continue;
+        }
+        let first = weekday;
+        while weekday < 7 && weekdays & (1 << weekday) != 0 {
+            weekday += 1;
+        }
+        match weekday - first {
+            1 => parts.push(WEEKDAY_NAMES[first].to_string()),
+            2 => parts.push(format!("{},{}",WEEKDAY_NAMES[first],WEEKDAY_NAMES[first + 1])),
+            _ => parts.push(format!("{}..{}",WEEKDAY_NAMES[first],WEEKDAY_NAMES[weekday - 1])),
+        }
+    }
+    return parts.join(",");
+}
+/// Returns systemd calendar event in the normalized form printed by `systemd-analyze calendar`,
+/// such as `Mon..Fri *-*-* 09:00:00 Europe/Berlin`.
+pub fn format_calendar_event(event: &CalendarEvent) -> String {
+    let mut text = String::new();
+    if event.weekdays != 0 && event.weekdays != 0x7f {
+        text.push_str(&format_weekdays(event.weekdays));
+        text.push(' ');
+    }
+    text.push_str(&format!("{}-{}{}{} {}:{}:{}",format_chain(&event.years,4),format_chain(&event.months,2),
+        if event.is_end_of_month { '~' } else { '-' },format_chain(&event.days,2),
+        format_chain(&event.hours,2),format_chain(&event.minutes,2),format_chain(&event.seconds,2)));
+    if let Some(ref timezone) = event.timezone {
+        text.push(' ');
+        text.push_str(timezone);
+    }
+    return text;
+}
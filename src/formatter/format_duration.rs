@@ -0,0 +1,95 @@
+use chrono::Duration;
+use parser::DurationSyntax;
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+fn nanoseconds(duration: &Duration) -> i128 {
+    let seconds = duration.num_seconds();
+    let nanosecond = (*duration - Duration::seconds(seconds)).num_nanoseconds().unwrap_or(0);
+    return seconds as i128 * NANOSECONDS_PER_SECOND + nanosecond as i128;
+}
+/// Returns decimal number of `value / scale` without trailing zeros of fraction.
+fn format_decimal(value: i128,scale: i128) -> String {
+    let integer = value / scale;
+    let fraction = value % scale;
+    if fraction == 0 {
+        return format!("{}",integer);
+    }
+    let width = (scale as f64).log10().round() as usize;
+    let fraction = format!("{:0width$}",fraction,width = width);
+    return format!("{}.{}",integer,fraction.trim_end_matches('0'));
+}
+/// Returns duration string such as `1h30m`, `1h30m0s` or `1h 30m` in the given syntax.
+///
+/// Zero duration is `0s`. Negative duration is prefixed by `-`.
+pub fn format_duration(duration: &Duration,syntax: DurationSyntax) -> String {
+    let total = nanoseconds(duration);
+    let sign = if total < 0 { "-" } else { "" };
+    let mut rest = total.abs();
+    if rest == 0 {
+        return "0s".to_string();
+    }
+    let mut result = String::from(sign);
+    match syntax {
+        DurationSyntax::Go => {
+            if rest < 1_000 {
+                return format!("{}{}ns",sign,rest);
+            }
+            if rest < 1_000_000 {
+                return format!("{}{}\u{b5}s",sign,format_decimal(rest,1_000));
+            }
+            if rest < NANOSECONDS_PER_SECOND {
+                return format!("{}{}ms",sign,format_decimal(rest,1_000_000));
+            }
+            let hours = rest / (3600 * NANOSECONDS_PER_SECOND);
+            rest = rest % (3600 * NANOSECONDS_PER_SECOND);
+            let minutes = rest / (60 * NANOSECONDS_PER_SECOND);
+            rest = rest % (60 * NANOSECONDS_PER_SECOND);
+            if hours > 0 {
+                result = result + &format!("{}h",hours);
+            }
+            if hours > 0 || minutes > 0 {
+                result = result + &format!("{}m",minutes);
+            }
+            return result + &format!("{}s",format_decimal(rest,NANOSECONDS_PER_SECOND));
+        },
+        DurationSyntax::Compact => {
+            let units: [(&str, i128); 7] = [
+                ("d", 86400 * NANOSECONDS_PER_SECOND),
+                ("h", 3600 * NANOSECONDS_PER_SECOND),
+                ("m", 60 * NANOSECONDS_PER_SECOND),
+                ("s", NANOSECONDS_PER_SECOND),
+                ("ms", 1_000_000),
+                ("us", 1_000),
+                ("ns", 1),
+            ];
+            for &(unit,unit_nanoseconds) in units.iter() {
+                if rest >= unit_nanoseconds {
+                    result = result + &format!("{}{}",rest / unit_nanoseconds,unit);
+                    rest = rest % unit_nanoseconds;
+                }
+            }
+            return result;
+        },
+        DurationSyntax::Humantime => {
+            let units: [(&str, &str, i128); 9] = [
+                ("year", "years", 31_557_600 * NANOSECONDS_PER_SECOND),
+                ("month", "months", 2_630_016 * NANOSECONDS_PER_SECOND),
+                ("day", "days", 86400 * NANOSECONDS_PER_SECOND),
+                ("h", "h", 3600 * NANOSECONDS_PER_SECOND),
+                ("m", "m", 60 * NANOSECONDS_PER_SECOND),
+                ("s", "s", NANOSECONDS_PER_SECOND),
+                ("ms", "ms", 1_000_000),
+                ("us", "us", 1_000),
+                ("ns", "ns", 1),
+            ];
+            let mut parts: Vec<String> = Vec::new();
+            for &(unit,units,unit_nanoseconds) in units.iter() {
+                let count = rest / unit_nanoseconds;
+                if count > 0 {
+                    parts.push(format!("{}{}",count,if count == 1 { unit } else { units }));
+                    rest = rest % unit_nanoseconds;
+                }
+            }
+            return result + &parts.join(" ");
+        },
+    }
+}
@@ -0,0 +1,22 @@
+use chrono::{Datelike,NaiveDate};
+use calendars::{Era,EraFormat,THAI_MONTHS,find_era};
+/// Style of the date with era year.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EraStyle {
+    /// Full date in the written form of the era (`Era::format`), such as `令和5年10月16日`
+    /// or `16 ตุลาคม พ.ศ. 2566`.
+    Full,
+    /// Era abbreviation with year, month and day separated by dots, such as `R5.10.16`.
+    Abbreviated,
+}
+/// Returns a date with era year such as `令和5年10月16日`, `16 ตุลาคม พ.ศ. 2566` or `R5.10.16`.
+/// Returns `None` if the date is before the first era of the table.
+pub fn format_era_date(date: &NaiveDate,eras: &[Era],style: EraStyle) -> Option<String> {
+    let (era, year) = find_era(eras,date)?;
+    return Some(match (style, era.format) {
+        (EraStyle::Full, EraFormat::Cjk) if year == 1 => format!("{}元年{}月{}日",era.name,date.month(),date.day()),
+        (EraStyle::Full, EraFormat::Cjk) => format!("{}{}年{}月{}日",era.name,year,date.month(),date.day()),
+        (EraStyle::Full, EraFormat::Thai) => format!("{} {} {} {}",date.day(),THAI_MONTHS[date.month0() as usize],era.name,year),
+        (EraStyle::Abbreviated, _) => format!("{}{}.{:02}.{:02}",era.abbreviation,year,date.month(),date.day()),
+    });
+}
@@ -0,0 +1,59 @@
+use chrono::FixedOffset;
+use chrono::Duration;
+use parser::{ICalDateTime,ICalPeriod};
+/// Returns an iCalendar `DATE` or `DATE-TIME` string such as `19980119T070000Z`.
+///
+/// Zoned value is prefixed by its `TZID` parameter: `TZID=America/New_York:19980119T020000`.
+pub fn format_ical_datetime(datetime: &ICalDateTime) -> String {
+    match *datetime {
+        ICalDateTime::Date(date) => format!("{}",date.format("%Y%m%d")),
+        ICalDateTime::Floating(datetime) => format!("{}",datetime.format("%Y%m%dT%H%M%S")),
+        ICalDateTime::Utc(datetime) => format!("{}",datetime.format("%Y%m%dT%H%M%SZ")),
+        ICalDateTime::Zoned(ref tzid,datetime) => format!("TZID={}:{}",tzid,datetime.format("%Y%m%dT%H%M%S")),
+    }
+}
+/// Returns an iCalendar `UTC-OFFSET` string such as `-0500`. Seconds are added if not zero.
+pub fn format_ical_utc_offset(offset: &FixedOffset) -> String {
+    let offset = offset.local_minus_utc();
+    let sign = if offset < 0 { "-" } else { "+" };
+    let seconds = offset.abs();
+    let result = format!("{}{:02}{:02}",sign,seconds / 3600,seconds / 60 % 60);
+    if seconds % 60 != 0 {
+        return format!("{}{:02}",result,seconds % 60);
+    }
+    return result;
+}
+/// Returns an iCalendar `DURATION` string such as `P15DT5H0M20S` or `P2W`.
+///
+/// Fraction of second is truncated.
+pub fn format_ical_duration(duration: &Duration) -> String {
+    let total = duration.num_seconds();
+    let sign = if total < 0 { "-" } else { "" };
+    let total = total.abs();
+    if total == 0 {
+        return "PT0S".to_string();
+    }
+    if total % (7 * 86400) == 0 {
+        return format!("{}P{}W",sign,total / (7 * 86400));
+    }
+    let mut result = format!("{}P",sign);
+    if total >= 86400 {
+        result = result + &format!("{}D",total / 86400);
+    }
+    let time = [(total % 86400 / 3600, "H"), (total % 3600 / 60, "M"), (total % 60, "S")];
+    if let Some(first) = time.iter().position(|&(value,_)| value > 0) {
+        let last = time.iter().rposition(|&(value,_)| value > 0).unwrap_or(first);
+        result = result + "T";
+        for &(value,unit) in &time[first..last + 1] {
+            result = result + &format!("{}{}",value,unit);
+        }
+    }
+    return result;
+}
+/// Returns an iCalendar `PERIOD` string such as `19970101T180000Z/PT5H30M`.
+pub fn format_ical_period(period: &ICalPeriod) -> String {
+    match *period {
+        ICalPeriod::Explicit(ref start,ref end) => format!("{}/{}",format_ical_datetime(start),format_ical_datetime(end)),
+        ICalPeriod::Start(ref start,duration) => format!("{}/{}",format_ical_datetime(start),format_ical_duration(&duration)),
+    }
+}
@@ -0,0 +1,50 @@
+use chrono::Duration;
+use parser::{IsoDuration,IsoInterval};
+use super::format_w3c::format_w3c;
+/// Returns an ISO 8601 duration string such as `P1Y2M10DT2H30M` or `PT0.5S`.
+///
+/// Zero duration is `PT0S`. Duration without positive components is prefixed by `-`,
+/// otherwise negative components keep their own sign: `P1M-2D`.
+pub fn format_iso_duration(duration: &IsoDuration) -> String {
+    let seconds = duration.time.num_seconds();
+    let nanosecond = (duration.time - Duration::seconds(seconds)).num_nanoseconds().unwrap_or(0);
+    let nanoseconds = seconds as i128 * 1_000_000_000 + nanosecond as i128;
+    let date = [(duration.years as i128, "Y"), (duration.months as i128, "M"), (duration.days as i128, "D")];
+    let time = [(nanoseconds / 3_600_000_000_000, "H"), (nanoseconds / 60_000_000_000 % 60, "M")];
+    let seconds = nanoseconds % 60_000_000_000;
+    let values = date.iter().chain(time.iter()).map(|&(value,_)| value).chain(Some(seconds));
+    let (is_negative, sign) = if values.clone().all(|value| value <= 0) { (true, -1) } else { (false, 1) };
+    if values.clone().all(|value| value == 0) {
+        return "PT0S".to_string();
+    }
+    let mut result = if is_negative { "-P".to_string() } else { "P".to_string() };
+    for &(value,unit) in date.iter().filter(|&&(value,_)| value != 0) {
+        result = result + &format!("{}{}",value * sign,unit);
+    }
+    if time.iter().any(|&(value,_)| value != 0) || seconds != 0 {
+        result = result + "T";
+    }
+    for &(value,unit) in time.iter().filter(|&&(value,_)| value != 0) {
+        result = result + &format!("{}{}",value * sign,unit);
+    }
+    if seconds != 0 {
+        let seconds = seconds * sign;
+        let fraction = format!("{:09}",(seconds % 1_000_000_000).abs());
+        let fraction = fraction.trim_end_matches('0');
+        let integer = if seconds < 0 && seconds > -1_000_000_000 { "-0".to_string() } else { format!("{}",seconds / 1_000_000_000) };
+        result = result + &integer;
+        if !fraction.is_empty() {
+            result = result + "." + fraction;
+        }
+        result = result + "S";
+    }
+    return result;
+}
+/// Returns an ISO 8601 time interval string such as `2007-03-01T13:00:00Z/P1Y2M10DT2H30M`.
+pub fn format_iso_interval(interval: &IsoInterval) -> String {
+    match *interval {
+        IsoInterval::StartEnd(ref start,ref end) => format!("{}/{}",format_w3c(start),format_w3c(end)),
+        IsoInterval::StartDuration(ref start,ref duration) => format!("{}/{}",format_w3c(start),format_iso_duration(duration)),
+        IsoInterval::DurationEnd(ref duration,ref end) => format!("{}/{}",format_iso_duration(duration),format_w3c(end)),
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{Datelike,NaiveDate};
+use julian_calendar::{JulianDate,Cutover};
+fn format_ymd(year: i32,month: u32,day: u32) -> String {
+    return format!("{:04}-{:02}-{:02}",year,month,day);
+}
+/// Returns a Julian calendar date such as `1700-03-11`.
+pub fn format_julian_date(date: &JulianDate) -> String {
+    return format_ymd(date.year(),date.month(),date.day());
+}
+/// Returns a Julian calendar date with the Gregorian dual dating such as `1700-03-11/22`,
+/// `1700-02-19/03-01` or `1700-12-25/1701-01-05`. Returns `None` if the date is out of chrono range.
+pub fn format_dual_date(date: &JulianDate) -> Option<String> {
+    let gregorian = date.to_naive_date()?;
+    let julian = format_julian_date(date);
+    if gregorian.year() != date.year() {
+        return Some(format!("{}/{}",julian,format_ymd(gregorian.year(),gregorian.month(),gregorian.day())));
+    }
+    if gregorian.month() != date.month() {
+        return Some(format!("{}/{:02}-{:02}",julian,gregorian.month(),gregorian.day()));
+    }
+    return Some(format!("{}/{:02}",julian,gregorian.day()));
+}
+/// Returns a `YYYY-MM-DD` date as written in the civil calendar that switched to the Gregorian calendar at the cutover.
+pub fn format_historical_date(date: &NaiveDate,cutover: &Cutover) -> String {
+    let (year, month, day) = cutover.to_historical(date).ymd();
+    return format_ymd(year,month,day);
+}
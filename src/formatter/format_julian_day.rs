@@ -0,0 +1,9 @@
+use julian_day::{DayEpoch,DayNumber};
+use super::helper::format_day_number;
+/// Returns day number with day counting abbreviation such as `JD 2457043.23000`.
+///
+/// `precision` is count of fraction digits (0 through 20), fraction is rounded.
+/// Returns `None` if the rounded day number is out of range.
+pub fn format_julian_day(value: &DayNumber,epoch: DayEpoch,precision: usize) -> Option<String> {
+    return format_day_number(value,precision).map(|number| format!("{} {}",epoch.name(),number));
+}
@@ -0,0 +1,352 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Datelike;
+use chrono::Timelike;
+use locale::{Locale,EN};
+/// Length of CLDR date and time style.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FormatStyle {
+    /// Such as `Sunday, December 11, 2011`.
+    Full,
+    /// Such as `December 11, 2011`.
+    Long,
+    /// Such as `Dec 11, 2011`.
+    Medium,
+    /// Such as `12/11/11`.
+    Short,
+}
+impl FormatStyle {
+    fn index(&self) -> usize {
+        match *self {
+            FormatStyle::Full => 0,
+            FormatStyle::Long => 1,
+            FormatStyle::Medium => 2,
+            FormatStyle::Short => 3,
+        }
+    }
+}
+/// What part of date and time is formatted and how.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum LocalizedStyle<'a> {
+    /// Date in the given style.
+    Date(FormatStyle),
+    /// Time in the given style.
+    Time(FormatStyle),
+    /// Date and time in the given styles.
+    DateTime(FormatStyle, FormatStyle),
+    /// CLDR skeleton such as `yMMMd`, matched to the closest skeleton of the locale table with field
+    /// widths adjusted, so `yMMMMEEEEd` uses the pattern of `yMMMEd` with full month and weekday.
+    /// Date and time fields without a common match are matched separately and combined.
+    Skeleton(&'a str),
+    /// CLDR pattern such as `d MMMM y`.
+    Pattern(&'a str),
+}
+/// Returns a localized date and time string such as `11 декабря 2011 г., 01:43` for the given style.
+///
+/// Supported pattern fields: `y`, `yy`, `M` through `MMMM`, `L` through `LLLL` (stand-alone month),
+/// `d`, `dd`, `E` through `EEEE`, `c` through `cccc`, `a`, `h`, `hh`, `H`, `HH`, `k`, `K`, `m`, `mm`,
+/// `s`, `ss`, `S` (fraction of second), `z` through `zzzz`, `Z`, `ZZZZZ`, `x`/`X` through `xxx`/`XXX`.
+/// Text in apostrophes is copied as is, `''` is an apostrophe.
+pub fn format_localized(datetime: &DateTime<FixedOffset>,locale: &Locale,style: LocalizedStyle) -> String {
+    return match style {
+        LocalizedStyle::Date(date) => format_pattern(datetime,locale,locale.date_patterns[date.index()]),
+        LocalizedStyle::Time(time) => format_pattern(datetime,locale,locale.time_patterns[time.index()]),
+        LocalizedStyle::DateTime(date,time) => {
+            let date_text = format_pattern(datetime,locale,locale.date_patterns[date.index()]);
+            let time_text = format_pattern(datetime,locale,locale.time_patterns[time.index()]);
+            let combination = format_pattern(datetime,locale,locale.datetime_patterns[date.index()]);
+            combination.replace("{1}",&date_text).replace("{0}",&time_text)
+        },
+        LocalizedStyle::Skeleton(skeleton) => {
+            let requested: Vec<(char, usize)> = split_pattern(skeleton).into_iter().filter_map(|(field,_)| field).collect();
+            format_pattern(datetime,locale,&skeleton_pattern(locale,&requested))
+        },
+        LocalizedStyle::Pattern(pattern) => format_pattern(datetime,locale,pattern),
+    };
+}
+/// Splits pattern into fields (letter and count) and other text as it is written, with apostrophes.
+fn split_pattern(pattern: &str) -> Vec<(Option<(char, usize)>, String)> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut result = Vec::new();
+    let mut position = 0;
+    while position < chars.len() {
+        let begin = position;
+        let c = chars[position];
+        if c == '\'' {
+            position = position + 1;
+            while position < chars.len() {
+                if chars[position] == '\'' {
+                    if position + 1 < chars.len() && chars[position + 1] == '\'' {
+                        position = position + 2;
+                        continue;
+                    }
+                    break;
+                }
+                position = position + 1;
+            }
+            position = (position + 1).min(chars.len());
+            result.push((None, chars[begin..position].iter().collect()));
+            continue;
+        }
+        while position < chars.len() && chars[position] == c {
+            position = position + 1;
+        }
+        let field = if c.is_ascii_alphabetic() { Some((c, position - begin)) } else { None };
+        result.push((field, chars[begin..position].iter().collect()));
+    }
+    return result;
+}
+/// Returns the field letter which stands for the same calendar field, such as `M` for stand-alone month `L`.
+fn field_type(field: char) -> char {
+    return match field {
+        'L' => 'M',
+        'c' | 'e' => 'E',
+        'H' | 'k' | 'K' => 'h',
+        'Z' | 'x' | 'X' | 'v' | 'O' => 'z',
+        _ => field,
+    };
+}
+fn is_text_field(field: char,count: usize) -> bool {
+    return match field_type(field) {
+        'M' => count >= 3,
+        'E' | 'a' | 'z' | 'G' => true,
+        _ => false,
+    };
+}
+fn is_date_field(field: char) -> bool {
+    return "GyYuUQqMLwWdDFgEec".contains(field);
+}
+/// Penalty of a requested field missing in the available skeleton.
+const MISSING_FIELD: usize = 0x1000;
+/// Returns distance of available skeleton fields to the requested ones as in CLDR `availableFormats` matching,
+/// `None` if the skeleton has fields which are not requested.
+fn skeleton_distance(requested: &[(char, usize)],available: &[(char, usize)]) -> Option<usize> {
+    if available.iter().any(|&(field,_)| requested.iter().all(|&(other,_)| field_type(other) != field_type(field))) {
+        return None;
+    }
+    let mut distance = 0;
+    for &(field,count) in requested.iter() {
+        distance = distance + match available.iter().find(|&&(other,_)| field_type(other) == field_type(field)) {
+            None => MISSING_FIELD,
+            Some(&(other,other_count)) if is_text_field(other,other_count) != is_text_field(field,count) => 0x100,
+            Some(&(other,_)) if other != field => 0x10,
+            Some(&(_,other_count)) => (other_count as isize - count as isize).unsigned_abs(),
+        };
+    }
+    return Some(distance);
+}
+/// Returns pattern of the best match of skeleton fields in the locale table.
+///
+/// Widths of fields except hours, minutes and seconds are adjusted to the requested ones. Requested fields
+/// missing in the best match are appended. If date and time fields have no common match, they are matched
+/// separately and combined with the date and time pattern of the matching date style.
+fn skeleton_pattern(locale: &Locale,requested: &[(char, usize)]) -> String {
+    let best = locale.skeletons.iter().filter_map(|&(skeleton,pattern)| {
+        let available: Vec<(char, usize)> = split_pattern(skeleton).into_iter().filter_map(|(field,_)| field).collect();
+        return skeleton_distance(requested,&available).map(|distance| (distance, available, pattern));
+    }).min_by_key(|&(distance,_,_)| distance);
+    if best.as_ref().map(|&(distance,_,_)| distance >= MISSING_FIELD).unwrap_or(true) {
+        let (date, time): (Vec<_>, Vec<_>) = requested.iter().partition(|&&(field,_)| is_date_field(field));
+        if !date.is_empty() && !time.is_empty() {
+            let month = date.iter().find(|&&(field,_)| field_type(field) == 'M').map(|&(_,count)| count).unwrap_or(0);
+            let has_weekday = date.iter().any(|&(field,_)| field_type(field) == 'E');
+            let style = match month {
+                4..=5 if has_weekday => FormatStyle::Full,
+                4..=5 => FormatStyle::Long,
+                3 => FormatStyle::Medium,
+                _ => FormatStyle::Short,
+            };
+            return locale.datetime_patterns[style.index()]
+                .replace("{1}",&skeleton_pattern(locale,&date)).replace("{0}",&skeleton_pattern(locale,&time));
+        }
+    }
+    let (available, pattern) = match best {
+        Some((_,available,pattern)) => (available, pattern),
+        None => (Vec::new(), ""),
+    };
+    let mut result = String::new();
+    for (field,text) in split_pattern(pattern) {
+        let adjusted = field.and_then(|(field,count)| {
+            if "hms".contains(field_type(field)) {
+                return None;
+            }
+            let &(_,requested_count) = requested.iter().find(|&&(other,_)| field_type(other) == field_type(field))?;
+            if requested_count == count || is_text_field(field,requested_count) != is_text_field(field,count) {
+                return None;
+            }
+            return Some((0..requested_count).map(|_| field).collect::<String>());
+        });
+        result = result + &adjusted.unwrap_or(text);
+    }
+    for &(field,count) in requested.iter().filter(|&&(field,_)| available.iter().all(|&(other,_)| field_type(other) != field_type(field))) {
+        if !result.is_empty() {
+            result.push(' ');
+        }
+        result.extend((0..count).map(|_| field));
+    }
+    return result;
+}
+fn format_pattern(datetime: &DateTime<FixedOffset>,locale: &Locale,pattern: &str) -> String {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut result = String::new();
+    let mut position = 0;
+    while position < chars.len() {
+        let c = chars[position];
+        if c == '\'' {
+            if position + 1 < chars.len() && chars[position + 1] == '\'' {
+                result.push('\'');
+                position = position + 2;
+                continue;
+            }
+            position = position + 1;
+            while position < chars.len() {
+                if chars[position] == '\'' {
+                    if position + 1 < chars.len() && chars[position + 1] == '\'' {
+                        result.push('\'');
+                        position = position + 2;
+                        continue;
+                    }
+                    break;
+                }
+                result.push(chars[position]);
+                position = position + 1;
+            }
+            position = position + 1;
+            continue;
+        }
+        let mut count = 1;
+        while position + count < chars.len() && chars[position + count] == c {
+            count = count + 1;
+        }
+        position = position + count;
+        if c.is_ascii_alphabetic() {
+            result.push_str(&format_field(datetime,locale,c,count));
+        } else {
+            for _ in 0..count {
+                result.push(c);
+            }
+        }
+    }
+    return result;
+}
+fn format_field(datetime: &DateTime<FixedOffset>,locale: &Locale,field: char,count: usize) -> String {
+    let month = datetime.month0() as usize;
+    let weekday = datetime.weekday().num_days_from_monday() as usize;
+    let hour = datetime.hour();
+    let am_pm = locale.am_pm.or(EN.am_pm).unwrap_or(["AM","PM"]);
+    return match field {
+        'y' if count == 2 => format!("{:02}",datetime.year().rem_euclid(100)),
+        'y' => format!("{:0width$}",datetime.year(),width = count),
+        'M' if count == 3 => locale.months_abbreviated[month].to_string(),
+        'M' if count >= 4 => locale.months_genitive[month].to_string(),
+        'L' if count == 3 => locale.months_abbreviated[month].to_string(),
+        'L' if count >= 4 => locale.months[month].to_string(),
+        'M' | 'L' => format!("{:0width$}",month + 1,width = count),
+        'd' => format!("{:0width$}",datetime.day(),width = count),
+        'E' | 'c' if count >= 4 => locale.weekdays[weekday].to_string(),
+        'E' | 'c' => locale.weekdays_abbreviated[weekday].to_string(),
+        'a' => am_pm[(hour / 12) as usize].to_string(),
+        'h' => format!("{:0width$}",(hour + 11) % 12 + 1,width = count),
+        'H' => format!("{:0width$}",hour,width = count),
+        'k' => format!("{:0width$}",(hour + 23) % 24 + 1,width = count),
+        'K' => format!("{:0width$}",hour % 12,width = count),
+        'm' => format!("{:0width$}",datetime.minute(),width = count),
+        's' => format!("{:0width$}",datetime.second() + datetime.nanosecond() / 1_000_000_000,width = count),
+        'S' => {
+            let fraction = format!("{:09}",datetime.nanosecond() % 1_000_000_000);
+            format!("{:0<width$}",&fraction[..count.min(9)],width = count)
+        },
+        'z' => format_offset(datetime.offset().local_minus_utc(),count >= 4,true),
+        'Z' if count >= 5 => format_offset(datetime.offset().local_minus_utc(),true,false),
+        'Z' => format_offset(datetime.offset().local_minus_utc(),false,false).replace(":",""),
+        'X' if datetime.offset().local_minus_utc() == 0 => "Z".to_string(),
+        'x' | 'X' if count == 1 => {
+            let offset = format_offset(datetime.offset().local_minus_utc(),true,false);
+            if offset.ends_with(":00") { offset[..3].to_string() } else { offset.replace(":","") }
+        },
+        'x' | 'X' if count == 2 || count == 4 => format_offset(datetime.offset().local_minus_utc(),true,false).replace(":",""),
+        'x' | 'X' => format_offset(datetime.offset().local_minus_utc(),true,false),
+        _ => (0..count).map(|_| field).collect(),
+    };
+}
+/// Returns offset such as `+03:00`, or `GMT+3` and `GMT+03:00` in GMT format.
+fn format_offset(offset: i32,is_long: bool,is_gmt: bool) -> String {
+    let sign = if offset < 0 { "-" } else { "+" };
+    let hour = offset.abs() / 3600;
+    let minute = offset.abs() / 60 % 60;
+    if is_gmt {
+        if offset == 0 {
+            return "GMT".to_string();
+        }
+        if !is_long {
+            if minute == 0 {
+                return format!("GMT{}{}",sign,hour);
+            }
+            return format!("GMT{}{}:{:02}",sign,hour,minute);
+        }
+        return format!("GMT{}{:02}:{:02}",sign,hour,minute);
+    }
+    return format!("{}{:02}:{:02}",sign,hour,minute);
+}
+#[cfg(test)]
+#[test]
+fn test_format_localized() {
+    use parser::{parse_w3c_datetime,parse_localized};
+    use locale::{RU,DE,FR,ES,PL,CS};
+    let datetime = parse_w3c_datetime("2011-12-11T01:43:05.25+03:00").unwrap();
+    let testdates = [
+        (&EN, LocalizedStyle::Date(FormatStyle::Full), "Sunday, December 11, 2011"),
+        (&EN, LocalizedStyle::Date(FormatStyle::Short), "12/11/11"),
+        (&EN, LocalizedStyle::Time(FormatStyle::Full), "1:43:05 AM GMT+03:00"),
+        (&EN, LocalizedStyle::DateTime(FormatStyle::Long,FormatStyle::Short), "December 11, 2011 at 1:43 AM"),
+        (&EN, LocalizedStyle::Skeleton("yMMMEd"), "Sun, Dec 11, 2011"),
+        (&EN, LocalizedStyle::Pattern("y-MM-dd'T'HH:mm:ss.SSSXXX 'o''clock'"), "2011-12-11T01:43:05.250+03:00 o'clock"),
+        (&RU, LocalizedStyle::Date(FormatStyle::Long), "11 декабря 2011 г."),
+        (&RU, LocalizedStyle::DateTime(FormatStyle::Medium,FormatStyle::Short), "11 дек 2011 г., 01:43"),
+        (&RU, LocalizedStyle::Time(FormatStyle::Long), "01:43:05 GMT+3"),
+        (&RU, LocalizedStyle::Skeleton("yMMMM"), "декабрь 2011 г."),
+        (&DE, LocalizedStyle::DateTime(FormatStyle::Full,FormatStyle::Short), "Sonntag, 11. Dezember 2011 um 01:43"),
+        (&FR, LocalizedStyle::DateTime(FormatStyle::Long,FormatStyle::Short), "11 décembre 2011 à 01:43"),
+        (&ES, LocalizedStyle::Date(FormatStyle::Full), "domingo, 11 de diciembre de 2011"),
+        (&ES, LocalizedStyle::Skeleton("hm"), "1:43 AM"),
+        (&PL, LocalizedStyle::DateTime(FormatStyle::Long,FormatStyle::Short), "11 grudnia 2011 o 01:43"),
+        (&PL, LocalizedStyle::Skeleton("yMMMM"), "grudzień 2011"),
+        (&EN, LocalizedStyle::Skeleton("yMMMMEEEEd"), "Sunday, December 11, 2011"),
+        (&EN, LocalizedStyle::Skeleton("yMMdd"), "12/11/2011"),
+        (&EN, LocalizedStyle::Skeleton("MMMMEd"), "Sun, December 11"),
+        (&EN, LocalizedStyle::Skeleton("yMMMdHm"), "Dec 11, 2011, 01:43"),
+        (&EN, LocalizedStyle::Skeleton("yMMMMdhm"), "December 11, 2011 at 1:43 AM"),
+        (&EN, LocalizedStyle::Skeleton("Hmsz"), "01:43:05 GMT+3"),
+        (&EN, LocalizedStyle::Skeleton("Hm"), "01:43"),
+        (&RU, LocalizedStyle::Skeleton("MMMMEEEEd"), "воскресенье, 11 декабря"),
+        (&RU, LocalizedStyle::Skeleton("yMMMMd"), "11 декабря 2011 г."),
+        (&DE, LocalizedStyle::Skeleton("yMMMMEEEEdHm"), "Sonntag, 11. Dezember 2011 um 01:43"),
+        (&CS, LocalizedStyle::DateTime(FormatStyle::Full,FormatStyle::Short), "neděle 11. prosince 2011 v 1:43"),
+        (&CS, LocalizedStyle::Date(FormatStyle::Medium), "11. 12. 2011"),
+    ];
+    for &(locale, style, checkdate) in testdates.iter() {
+        let dt = format_localized(&datetime,locale,style);
+        if dt != checkdate {
+            panic!("Date conversion failed for {:?}\nReceived: {:?}\nExpected: {:?}",
+                   style, dt, checkdate);
+        }
+    }
+    static NL: Locale = Locale {
+        code: "nl",
+        months: ["januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december"],
+        months_genitive: ["januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december"],
+        months_abbreviated: ["jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec"],
+        weekdays: ["maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag", "zondag"],
+        weekdays_abbreviated: ["ma", "di", "wo", "do", "vr", "za", "zo"],
+        date_patterns: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd-MM-y"],
+        ..Locale::ROOT
+    };
+    assert_eq!(format_localized(&datetime,&NL,LocalizedStyle::Date(FormatStyle::Full)), "zondag 11 december 2011");
+    assert_eq!(format_localized(&datetime,&NL,LocalizedStyle::DateTime(FormatStyle::Short,FormatStyle::Medium)), "11-12-2011 01:43:05");
+    assert_eq!(format_localized(&datetime,&NL,LocalizedStyle::Skeleton("yMMMMEEEEd")), "2011 december 11, zondag");
+    for locale in &[&EN, &RU, &DE, &FR, &ES, &PL, &CS] {
+        let text = format_localized(&datetime,locale,LocalizedStyle::DateTime(FormatStyle::Full,FormatStyle::Short));
+        let (date, time) = parse_localized(&text,locale).unwrap();
+        assert_eq!(date.and_time(time.unwrap()), datetime.naive_local().with_second(0).unwrap().with_nanosecond(0).unwrap(), "{}", text);
+    }
+}
@@ -0,0 +1,32 @@
+use ntp::{NtpTimestamp,NtpDate,NtpShort};
+use parser::error::ParseResult;
+use super::format_w3c::format_w3c;
+/// Returns NTP timestamp as hexadecimal seconds and fraction such as `d85a1ad8.80000000`.
+pub fn format_ntp(timestamp: &NtpTimestamp) -> String {
+    return format!("{:08x}.{:08x}",timestamp.seconds,timestamp.fraction);
+}
+/// Returns NTP short format as hexadecimal seconds and fraction such as `0001.8000`.
+pub fn format_ntp_short(short: &NtpShort) -> String {
+    return format!("{:04x}.{:04x}",short.seconds,short.fraction);
+}
+/// Returns an W3C date and time string of NTP date such as `2015-01-21T01:35:20.500Z`.
+///
+/// With `precision` of `None` fraction of second is formatted as by `format_w3c`.
+/// Otherwise exactly `precision` digits (up to 19) are taken from the 64-bit fraction, truncated.
+pub fn format_ntp_date(date: &NtpDate,precision: Option<usize>) -> ParseResult<String> {
+    let precision = match precision {
+        Some(precision) => precision.min(19),
+        None => return Ok(format_w3c(&date.to_datetime()?)),
+    };
+    let seconds = NtpDate {
+        era: date.era,
+        offset: date.offset,
+        fraction: 0,
+    };
+    let datetime = seconds.to_datetime()?;
+    if precision == 0 {
+        return Ok(format_w3c(&datetime));
+    }
+    let fraction = (date.fraction as u128 * 10u128.pow(precision as u32)) >> 64;
+    return Ok(format!("{}.{:0width$}Z",datetime.format("%FT%T"),fraction,width = precision));
+}
@@ -0,0 +1,204 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Duration;
+use locale::{Locale,EN};
+use super::format_localized::{format_localized,LocalizedStyle};
+/// Length of relative time names.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RelativeStyle {
+    /// Such as `3 minutes ago`.
+    Long,
+    /// Such as `3 min ago`.
+    Short,
+}
+/// Rounding of count of units.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Rounding {
+    /// To the nearest: 90 seconds is `2 minutes`.
+    Round,
+    /// Toward zero: 90 seconds is `1 minute`.
+    Floor,
+    /// Away from zero: 61 seconds is `2 minutes`.
+    Ceil,
+}
+/// Limits of relative time units. Count of unit less than limit is formatted with this unit,
+/// otherwise the next unit is used.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct RelativeThresholds {
+    /// Seconds less than this are formatted as present (`now`).
+    pub now: i64,
+    /// Seconds less than this are formatted as seconds.
+    pub seconds: i64,
+    /// Minutes less than this are formatted as minutes.
+    pub minutes: i64,
+    /// Hours less than this are formatted as hours.
+    pub hours: i64,
+    /// Days less than this are formatted as days.
+    pub days: i64,
+    /// Weeks less than this are formatted as weeks. `0` disables weeks.
+    pub weeks: i64,
+    /// Months less than this are formatted as months, otherwise years are used.
+    pub months: i64,
+}
+impl Default for RelativeThresholds {
+    fn default() -> RelativeThresholds {
+        return RelativeThresholds {
+            now: 10,
+            seconds: 45,
+            minutes: 45,
+            hours: 22,
+            days: 26,
+            weeks: 0,
+            months: 11,
+        };
+    }
+}
+/// Options of relative time formatting.
+#[derive(Copy, Clone, Debug)]
+pub struct RelativeOptions {
+    /// Locale of names. Default is `EN`.
+    pub locale: &'static Locale,
+    /// Length of names. Default is `Long`.
+    pub style: RelativeStyle,
+    /// Rounding of count of units. Default is `Round`.
+    pub rounding: Rounding,
+    /// Limits of units.
+    pub thresholds: RelativeThresholds,
+}
+impl Default for RelativeOptions {
+    fn default() -> RelativeOptions {
+        return RelativeOptions {
+            locale: &EN,
+            style: RelativeStyle::Long,
+            rounding: Rounding::Round,
+            thresholds: RelativeThresholds::default(),
+        };
+    }
+}
+/// Average length of month and year in seconds, by Gregorian calendar.
+const SECONDS_PER_MONTH: i64 = 2_629_746;
+const SECONDS_PER_YEAR: i64 = 31_556_952;
+fn divide(value: i64,divisor: i64,rounding: Rounding) -> i64 {
+    return match rounding {
+        Rounding::Round => (value + divisor / 2) / divisor,
+        Rounding::Floor => value / divisor,
+        Rounding::Ceil => (value + divisor - 1) / divisor,
+    };
+}
+/// Returns relative time string of `datetime` against `now` such as `3 minutes ago` or `in 2 days`.
+pub fn format_relative(datetime: &DateTime<FixedOffset>,now: &DateTime<FixedOffset>) -> String {
+    return format_relative_with(datetime,now,&RelativeOptions::default());
+}
+/// Returns relative time string of `datetime` against `now` with the given options.
+///
+/// Months and years have average length of Gregorian month and year.
+pub fn format_relative_with(datetime: &DateTime<FixedOffset>,now: &DateTime<FixedOffset>,options: &RelativeOptions) -> String {
+    let locale = options.locale;
+    let thresholds = &options.thresholds;
+    let difference = datetime.signed_duration_since(*now).num_seconds();
+    let seconds = difference.abs();
+    if seconds < thresholds.now {
+        return locale.relative_patterns[2].to_string();
+    }
+    let units = [
+        (1, thresholds.seconds),
+        (60, thresholds.minutes),
+        (3600, thresholds.hours),
+        (86400, thresholds.days),
+        (7 * 86400, thresholds.weeks),
+        (SECONDS_PER_MONTH, thresholds.months),
+        (SECONDS_PER_YEAR, i64::MAX),
+    ];
+    let mut unit = 0;
+    let mut count = seconds;
+    for (index,&(unit_seconds,limit)) in units.iter().enumerate() {
+        if index == 4 && limit == 0 {
+            continue;
+        }
+        unit = index;
+        count = divide(seconds,unit_seconds,options.rounding).max(1);
+        if count < limit {
+            break;
+        }
+    }
+    let name = match options.style {
+        RelativeStyle::Long => {
+            let units = match locale.relative_units_past {
+                Some(ref units) if difference < 0 => units,
+                _ => &locale.relative_units,
+            };
+            units[unit][(locale.plural)(count as u64)]
+        },
+        RelativeStyle::Short => locale.relative_units_short[unit],
+    };
+    let pattern = if difference < 0 { locale.relative_patterns[0] } else { locale.relative_patterns[1] };
+    return pattern.replace("{0}",&format!("{} {}",count,name));
+}
+/// Returns relative time string if `datetime` is nearer than `limit` to `now`,
+/// otherwise returns absolute date and time in the given style, such as `Dec 11, 2011`.
+pub fn format_relative_smart(datetime: &DateTime<FixedOffset>,now: &DateTime<FixedOffset>,options: &RelativeOptions,limit: Duration,absolute: LocalizedStyle) -> String {
+    let distance = datetime.signed_duration_since(*now);
+    if distance < limit && -distance < limit {
+        return format_relative_with(datetime,now,options);
+    }
+    return format_localized(datetime,options.locale,absolute);
+}
+#[cfg(test)]
+#[test]
+fn test_format_relative() {
+    use parser::parse_w3c_datetime;
+    use locale::{RU,DE,FR,ES,PL,CS};
+    use super::format_localized::FormatStyle;
+    let now = parse_w3c_datetime("2011-12-11T10:43:00+03:00").unwrap();
+    let long = RelativeOptions::default();
+    let short = RelativeOptions { style: RelativeStyle::Short, ..long };
+    let floor = RelativeOptions { rounding: Rounding::Floor, ..long };
+    let weeks = RelativeOptions { thresholds: RelativeThresholds { weeks: 4, days: 7, ..long.thresholds }, ..long };
+    let russian = RelativeOptions { locale: &RU, ..long };
+    let polish = RelativeOptions { locale: &PL, ..long };
+    let czech = RelativeOptions { locale: &CS, ..long };
+    let short_czech = RelativeOptions { style: RelativeStyle::Short, ..czech };
+    let testdates = [
+        ("2011-12-11T10:43:05+03:00", &long, "now"),
+        ("2011-12-11T10:42:30+03:00", &long, "30 seconds ago"),
+        ("2011-12-11T10:42:00+03:00", &long, "1 minute ago"),
+        ("2011-12-11T10:44:31+03:00", &long, "in 2 minutes"),
+        ("2011-12-11T10:44:31+03:00", &floor, "in 1 minute"),
+        ("2011-12-11T10:40:00+03:00", &short, "3 min ago"),
+        ("2011-12-11T10:43:00Z", &long, "in 3 hours"),
+        ("2011-12-10T10:43:00+03:00", &long, "1 day ago"),
+        ("2011-12-25T10:43:00+03:00", &long, "in 14 days"),
+        ("2011-12-25T10:43:00+03:00", &weeks, "in 2 weeks"),
+        ("2012-03-11T10:43:00+03:00", &long, "in 3 months"),
+        ("2009-12-11T10:43:00+03:00", &long, "2 years ago"),
+        ("2011-12-11T10:42:39+03:00", &russian, "21 секунду назад"),
+        ("2011-12-11T10:40:00+03:00", &russian, "3 минуты назад"),
+        ("2011-12-16T10:43:00+03:00", &russian, "через 5 дней"),
+        ("2001-12-11T10:43:00+03:00", &russian, "10 лет назад"),
+        ("2011-12-11T10:42:39+03:00", &polish, "21 sekund temu"),
+        ("2011-12-11T10:42:00+03:00", &polish, "1 minutę temu"),
+        ("2011-12-11T13:43:00+03:00", &polish, "za 3 godziny"),
+        ("2011-12-11T10:42:00+03:00", &czech, "před 1 minutou"),
+        ("2011-12-14T10:43:00+03:00", &czech, "za 3 dny"),
+        ("2006-12-11T10:43:00+03:00", &czech, "před 5 lety"),
+        ("2011-12-08T10:43:00+03:00", &czech, "před 3 dny"),
+        ("2011-12-10T10:43:00+03:00", &czech, "před 1 dnem"),
+        ("2006-12-11T10:43:00+03:00", &short_czech, "před 5 r."),
+        ("2016-12-11T10:43:00+03:00", &czech, "za 5 let"),
+    ];
+    for &(date, options, checkdate) in testdates.iter() {
+        let dt = format_relative_with(&parse_w3c_datetime(date).unwrap(),&now,options);
+        if dt != checkdate {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, dt, checkdate);
+        }
+    }
+    let datetime = parse_w3c_datetime("2011-12-08T10:43:00+03:00").unwrap();
+    assert_eq!(format_relative(&datetime,&now), "3 days ago");
+    assert_eq!(format_relative_with(&datetime,&now,&RelativeOptions { locale: &DE, ..long }), "vor 3 Tagen");
+    assert_eq!(format_relative_with(&datetime,&now,&RelativeOptions { locale: &FR, ..long }), "il y a 3 jours");
+    assert_eq!(format_relative_with(&datetime,&now,&RelativeOptions { locale: &ES, ..long }), "hace 3 días");
+    let absolute = LocalizedStyle::Date(FormatStyle::Medium);
+    assert_eq!(format_relative_smart(&datetime,&now,&long,Duration::days(7),absolute), "3 days ago");
+    assert_eq!(format_relative_smart(&datetime,&now,&long,Duration::days(1),absolute), "Dec 8, 2011");
+}
@@ -0,0 +1,48 @@
+use recurrence::{RecurrenceRule,weekday_name};
+use parser::ICalDateTime;
+use chrono::Weekday;
+use super::format_icalendar::format_ical_datetime;
+fn format_numbers<T: ToString>(values: &[T]) -> String {
+    return values.iter().map(|value| value.to_string()).collect::<Vec<String>>().join(",");
+}
+/// Returns an iCalendar recurrence rule string such as `FREQ=MONTHLY;COUNT=10;BYDAY=-1FR` without `RRULE:` prefix.
+///
+/// Parts are written in the order of RFC 5545 grammar. Default `INTERVAL=1` and `WKST=MO` are omitted.
+pub fn format_rrule(rule: &RecurrenceRule) -> String {
+    let mut parts = vec![format!("FREQ={}",rule.frequency.name())];
+    if rule.interval != 1 {
+        parts.push(format!("INTERVAL={}",rule.interval));
+    }
+    if let Some(count) = rule.count {
+        parts.push(format!("COUNT={}",count));
+    }
+    if let Some(ref until) = rule.until {
+        let until = match *until {
+            ICalDateTime::Zoned(_,datetime) => ICalDateTime::Floating(datetime),
+            ref until => until.clone(),
+        };
+        parts.push(format!("UNTIL={}",format_ical_datetime(&until)));
+    }
+    let lists = [
+        ("BYSECOND", format_numbers(&rule.by_second)),
+        ("BYMINUTE", format_numbers(&rule.by_minute)),
+        ("BYHOUR", format_numbers(&rule.by_hour)),
+        ("BYDAY", rule.by_day.iter().map(|&(index,weekday)| {
+            if index == 0 { weekday_name(weekday).to_string() } else { format!("{}{}",index,weekday_name(weekday)) }
+        }).collect::<Vec<String>>().join(",")),
+        ("BYMONTHDAY", format_numbers(&rule.by_month_day)),
+        ("BYYEARDAY", format_numbers(&rule.by_year_day)),
+        ("BYWEEKNO", format_numbers(&rule.by_week_no)),
+        ("BYMONTH", format_numbers(&rule.by_month)),
+        ("BYSETPOS", format_numbers(&rule.by_set_pos)),
+    ];
+    for &(name,ref values) in lists.iter() {
+        if !values.is_empty() {
+            parts.push(format!("{}={}",name,values));
+        }
+    }
+    if rule.week_start != Weekday::Mon {
+        parts.push(format!("WKST={}",weekday_name(rule.week_start)));
+    }
+    return parts.join(";");
+}
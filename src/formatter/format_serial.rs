@@ -0,0 +1,35 @@
+use chrono::Timelike;
+use julian_day::DayNumber;
+use spreadsheet::{SerialEpoch,from_serial};
+use super::helper::format_day_number;
+/// Returns spreadsheet serial number such as `42024.73287`.
+///
+/// `precision` is count of fraction digits (0 through 20), fraction is rounded.
+/// Returns `None` if the rounded serial number is out of range.
+pub fn format_serial(serial: &DayNumber,precision: usize) -> Option<String> {
+    return format_day_number(serial,precision);
+}
+/// Returns date and time of spreadsheet serial number as spreadsheet shows it, such as `2015-01-20T17:35:20`.
+///
+/// Dates of the 1900 date system which are not exists are formatted too:
+/// serial 0 is `1900-01-00` and serial 60 is `1900-02-29`.
+pub fn format_serial_datetime(serial: &DayNumber,epoch: SerialEpoch) -> Option<String> {
+    let phantom_date = match (epoch, serial.day) {
+        (SerialEpoch::Excel1900, 0) | (SerialEpoch::Lotus, 0) => Some("1900-01-00"),
+        (SerialEpoch::Excel1900, 60) | (SerialEpoch::Lotus, 60) => Some("1900-02-29"),
+        _ => None,
+    };
+    if let Some(phantom_date) = phantom_date {
+        let time = DayNumber { day: 0, nanosecond: serial.nanosecond };
+        let time = from_serial(&time, SerialEpoch::Excel1904)?;
+        return Some(format!("{}T{}",phantom_date,time.format(time_format(time.nanosecond()))));
+    }
+    let datetime = from_serial(serial, epoch)?;
+    return Some(format!("{}",datetime.format(&format!("%FT{}",time_format(datetime.nanosecond())))));
+}
+fn time_format(nanosecond: u32) -> &'static str {
+    if nanosecond > 0 {
+        return "%T%.f";
+    }
+    return "%T";
+}
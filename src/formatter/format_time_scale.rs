@@ -0,0 +1,11 @@
+use chrono::NaiveDateTime;
+use chrono::Timelike;
+use time_scale::TimeScale;
+/// Returns date and time with time scale abbreviation such as `2015-01-20T17:35:20 TAI`.
+pub fn format_time_scale(datetime: &NaiveDateTime,scale: TimeScale) -> String {
+    let mut format = String::from("%FT%T");
+    if datetime.nanosecond() > 0 {
+        format = format + "%.f";
+    }
+    return format!("{} {}",datetime.format(&format),scale.name());
+}
@@ -2,6 +2,8 @@ use chrono::DateTime;
 use chrono::FixedOffset;
 use chrono::Timelike;
 /// Returns an W3C date and time string such as `1996-12-19T16:39:57Z`.
+///
+/// Leap second (nanosecond value of 1 000 000 000 or greater) is formatted as `23:59:60`.
 pub fn format_w3c(datetime: &DateTime<FixedOffset>) -> String {
     let mut format = String::from("%FT%T");
     let offset = datetime.timezone().local_minus_utc();
@@ -0,0 +1,29 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Timelike;
+/// Returns an Apache Common Log Format timestamp such as `[10/Oct/2000:13:55:36 -0700]`.
+pub fn format_clf(datetime: &DateTime<FixedOffset>) -> String {
+    return format!("{}",datetime.format("[%d/%b/%Y:%H:%M:%S %z]"));
+}
+/// Returns an nginx `$time_iso8601` timestamp such as `2000-10-10T13:55:36-07:00`.
+///
+/// Unlike `format_w3c`, UTC is formatted as `+00:00` and fraction of second is omitted.
+pub fn format_nginx_iso8601(datetime: &DateTime<FixedOffset>) -> String {
+    return format!("{}",datetime.format("%FT%T%:z"));
+}
+/// Returns an nginx `$msec` timestamp such as `971211336.123`.
+///
+/// Milliseconds are truncated. Time before 1970 is negative such as `-0.500` for `1969-12-31T23:59:59.5Z`.
+pub fn format_nginx_msec(datetime: &DateTime<FixedOffset>) -> String {
+    let milliseconds = datetime.timestamp() * 1000 + (datetime.nanosecond() % 1_000_000_000 / 1_000_000) as i64;
+    let sign = if milliseconds < 0 { "-" } else { "" };
+    let magnitude = milliseconds.abs();
+    return format!("{}{}.{:03}",sign,magnitude.div_euclid(1000),magnitude.rem_euclid(1000));
+}
+/// Returns IIS W3C Extended Log `date` and `time` fields such as `2000-10-10` and `20:55:36`.
+///
+/// Date and time are converted to UTC.
+pub fn format_iis(datetime: &DateTime<FixedOffset>) -> (String, String) {
+    let utc = datetime.naive_utc();
+    return (format!("{}",utc.format("%F")),format!("{}",utc.format("%T")));
+}
@@ -0,0 +1,25 @@
+use julian_day::{DayNumber,NANOSECONDS_PER_DAY};
+/// Returns `None` if rounding up the fraction overflows the day.
+pub fn format_day_number(value: &DayNumber,precision: usize) -> Option<String> {
+    let precision = precision.min(20);
+    let scale = 10u128.pow(precision as u32);
+    let mut day = value.day;
+    let mut fraction = (value.nanosecond as u128 * scale + NANOSECONDS_PER_DAY as u128 / 2) / NANOSECONDS_PER_DAY as u128;
+    if fraction == scale {
+        day = day.checked_add(1)?;
+        fraction = 0;
+    }
+    let mut sign = "";
+    if day < 0 {
+        sign = "-";
+        if fraction > 0 {
+            day = day + 1;
+            fraction = scale - fraction;
+        }
+    }
+    let day = day.unsigned_abs();
+    if precision == 0 {
+        return Some(format!("{}{}",sign,day));
+    }
+    return Some(format!("{}{}.{:0width$}",sign,day,fraction,width = precision));
+}
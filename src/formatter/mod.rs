@@ -1,2 +1,35 @@
 mod format_w3c;
-pub use self::format_w3c::format_w3c;
\ No newline at end of file
+mod format_time_scale;
+mod format_julian_day;
+mod format_serial;
+mod format_ntp;
+mod format_asn1_time;
+mod format_web_log;
+mod format_localized;
+mod format_relative;
+mod format_duration;
+mod format_icalendar;
+mod format_rrule;
+mod format_calendar_event;
+mod format_julian_date;
+mod format_calendar_date;
+mod format_era_date;
+mod format_iso_duration;
+mod helper;
+pub use self::format_w3c::format_w3c;
+pub use self::format_time_scale::format_time_scale;
+pub use self::format_julian_day::format_julian_day;
+pub use self::format_serial::{format_serial,format_serial_datetime};
+pub use self::format_ntp::{format_ntp,format_ntp_date,format_ntp_short};
+pub use self::format_asn1_time::{format_utc_time,format_generalized_time};
+pub use self::format_web_log::{format_clf,format_nginx_iso8601,format_nginx_msec,format_iis};
+pub use self::format_localized::{format_localized,FormatStyle,LocalizedStyle};
+pub use self::format_relative::{format_relative,format_relative_with,format_relative_smart,RelativeOptions,RelativeStyle,RelativeThresholds,Rounding};
+pub use self::format_duration::format_duration;
+pub use self::format_icalendar::{format_ical_datetime,format_ical_utc_offset,format_ical_duration,format_ical_period};
+pub use self::format_rrule::format_rrule;
+pub use self::format_calendar_event::format_calendar_event;
+pub use self::format_julian_date::{format_julian_date,format_dual_date,format_historical_date};
+pub use self::format_calendar_date::format_calendar_date;
+pub use self::format_era_date::{format_era_date,EraStyle};
+pub use self::format_iso_duration::{format_iso_duration,format_iso_interval};
\ No newline at end of file
@@ -0,0 +1,94 @@
+use chrono::{Datelike,NaiveDate};
+use super::julian_date::JulianDate;
+/// Date of a civil calendar that switched from the Julian to the Gregorian calendar.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum HistoricalDate {
+    /// Date before the cutover, written in the Julian calendar.
+    Julian(JulianDate),
+    /// Date on or after the cutover, written in the Gregorian calendar.
+    Gregorian(NaiveDate),
+}
+impl HistoricalDate {
+    /// Returns the same day as the proleptic Gregorian date. Returns `None` if the date is out of chrono range.
+    pub fn to_naive_date(&self) -> Option<NaiveDate> {
+        return match *self {
+            HistoricalDate::Julian(date) => date.to_naive_date(),
+            HistoricalDate::Gregorian(date) => Some(date),
+        };
+    }
+    /// Returns year, month and day as written.
+    pub fn ymd(&self) -> (i32, u32, u32) {
+        return match *self {
+            HistoricalDate::Julian(date) => (date.year(), date.month(), date.day()),
+            HistoricalDate::Gregorian(date) => (date.year(), date.month(), date.day()),
+        };
+    }
+}
+/// Adoption of the Gregorian calendar: the first day written in the Gregorian calendar.
+/// Previous day is written in the Julian calendar.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Cutover {
+    first_gregorian: NaiveDate,
+}
+impl Cutover {
+    /// Makes a cutover with the first Gregorian date.
+    pub fn new(first_gregorian: NaiveDate) -> Cutover {
+        return Cutover {
+            first_gregorian: first_gregorian,
+        };
+    }
+    /// Papal bull cutover: 1582-10-04 (Julian) is followed by 1582-10-15 (Gregorian).
+    /// Adopted by Italy, Spain, Portugal and Poland.
+    pub fn papal() -> Cutover {
+        return Cutover::new(NaiveDate::from_ymd_opt(1582, 10, 15).unwrap());
+    }
+    /// Great Britain and its colonies: 1752-09-02 is followed by 1752-09-14.
+    pub fn great_britain() -> Cutover {
+        return Cutover::new(NaiveDate::from_ymd_opt(1752, 9, 14).unwrap());
+    }
+    /// Russia: 1918-01-31 is followed by 1918-02-14.
+    pub fn russia() -> Cutover {
+        return Cutover::new(NaiveDate::from_ymd_opt(1918, 2, 14).unwrap());
+    }
+    /// Greece: 1923-02-15 is followed by 1923-03-01.
+    pub fn greece() -> Cutover {
+        return Cutover::new(NaiveDate::from_ymd_opt(1923, 3, 1).unwrap());
+    }
+    /// Returns the first Gregorian date.
+    pub fn first_gregorian(&self) -> NaiveDate {
+        return self.first_gregorian;
+    }
+    /// Returns the last Julian date.
+    pub fn last_julian(&self) -> JulianDate {
+        return JulianDate::from_naive_date(&self.first_gregorian).pred_opt().unwrap();
+    }
+    /// Returns `true` if the day is written in the Gregorian calendar.
+    pub fn is_gregorian(&self,date: &NaiveDate) -> bool {
+        return *date >= self.first_gregorian;
+    }
+    /// Returns the day as written in the civil calendar with this cutover.
+    pub fn to_historical(&self,date: &NaiveDate) -> HistoricalDate {
+        if self.is_gregorian(date) {
+            return HistoricalDate::Gregorian(*date);
+        }
+        return HistoricalDate::Julian(JulianDate::from_naive_date(date));
+    }
+    /// Interprets the civil calendar date written with this cutover.
+    /// Returns `None` if the date does not exist, including days skipped by the cutover.
+    pub fn from_historical_ymd(&self,year: i32,month: u32,day: u32) -> Option<HistoricalDate> {
+        let first_gregorian = &self.first_gregorian;
+        if (year, month, day) >= (first_gregorian.year(), first_gregorian.month(), first_gregorian.day()) {
+            return NaiveDate::from_ymd_opt(year,month,day).map(HistoricalDate::Gregorian);
+        }
+        let date = JulianDate::from_ymd_opt(year,month,day)?;
+        if date > self.last_julian() {
+            return None;
+        }
+        return Some(HistoricalDate::Julian(date));
+    }
+}
+impl Default for Cutover {
+    fn default() -> Cutover {
+        return Cutover::papal();
+    }
+}
@@ -0,0 +1,133 @@
+use chrono::{Datelike,NaiveDate};
+/// Days from 0001-01-01 of the proleptic Gregorian calendar to Julian Day Number 0.
+const JULIAN_DAY_NUMBER_OFFSET: i64 = 1_721_425;
+/// Returns `true` if the year is a leap year of the Julian calendar (astronomical year numbering).
+pub fn is_leap_year(year: i32) -> bool {
+    return year.rem_euclid(4) == 0;
+}
+/// Returns number of days in the month of the Julian calendar.
+pub fn days_in_month(year: i32,month: u32) -> u32 {
+    return match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+}
+/// Date of the proleptic Julian calendar.
+///
+/// Years use astronomical numbering: year 0 is 1 BC, year -1 is 2 BC.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct JulianDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+impl JulianDate {
+    /// Makes a new Julian date. Returns `None` if the month or the day is invalid.
+    pub fn from_ymd_opt(year: i32,month: u32,day: u32) -> Option<JulianDate> {
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year,month) {
+            return None;
+        }
+        return Some(JulianDate {
+            year: year,
+            month: month,
+            day: day,
+        });
+    }
+    /// Makes a Julian date from Julian Day Number. Returns `None` if the year is out of `i32` range.
+    pub fn from_julian_day_number(julian_day_number: i64) -> Option<JulianDate> {
+        let c = julian_day_number.checked_add(32082)?;
+        let d = c.checked_mul(4)?.checked_add(3)?.div_euclid(1461);
+        let e = c - (1461 * d).div_euclid(4);
+        let m = (5 * e + 2) / 153;
+        let day = e - (153 * m + 2) / 5 + 1;
+        let month = m + 3 - 12 * (m / 10);
+        let year = d - 4800 + m / 10;
+        if year < i32::MIN as i64 || year > i32::MAX as i64 {
+            return None;
+        }
+        return Some(JulianDate {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        });
+    }
+    /// Makes a Julian date of the same day as the proleptic Gregorian date.
+    pub fn from_naive_date(date: &NaiveDate) -> JulianDate {
+        let julian_day_number = date.num_days_from_ce() as i64 + JULIAN_DAY_NUMBER_OFFSET;
+        return JulianDate::from_julian_day_number(julian_day_number).unwrap();
+    }
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        return self.year;
+    }
+    /// Returns the month starting from 1.
+    pub fn month(&self) -> u32 {
+        return self.month;
+    }
+    /// Returns the day of month starting from 1.
+    pub fn day(&self) -> u32 {
+        return self.day;
+    }
+    /// Returns Julian Day Number of the date.
+    pub fn julian_day_number(&self) -> i64 {
+        let a = (14 - self.month as i64) / 12;
+        let y = self.year as i64 + 4800 - a;
+        let m = self.month as i64 + 12 * a - 3;
+        return self.day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32083;
+    }
+    /// Returns the same day as the proleptic Gregorian date. Returns `None` if the date is out of chrono range.
+    pub fn to_naive_date(&self) -> Option<NaiveDate> {
+        let days = self.julian_day_number() - JULIAN_DAY_NUMBER_OFFSET;
+        if days < i32::MIN as i64 || days > i32::MAX as i64 {
+            return None;
+        }
+        return NaiveDate::from_num_days_from_ce_opt(days as i32);
+    }
+    /// Returns the next day. Returns `None` on overflow.
+    pub fn succ_opt(&self) -> Option<JulianDate> {
+        return JulianDate::from_julian_day_number(self.julian_day_number() + 1);
+    }
+    /// Returns the previous day. Returns `None` on overflow.
+    pub fn pred_opt(&self) -> Option<JulianDate> {
+        return JulianDate::from_julian_day_number(self.julian_day_number() - 1);
+    }
+}
+#[cfg(test)]
+#[test]
+fn test_julian_date() {
+    use chrono::{MIN_DATE,MAX_DATE};
+    let testdates = [
+        ((1582, 10, 4), "1582-10-14"),
+        ((1582, 10, 5), "1582-10-15"),
+        ((1700, 2, 18), "1700-02-28"),
+        ((1700, 2, 19), "1700-03-01"),
+        ((1700, 2, 29), "1700-03-11"),
+        ((1752, 9, 2), "1752-09-13"),
+        ((1918, 1, 31), "1918-02-13"),
+        ((2000, 1, 1), "2000-01-14"),
+        ((1, 1, 3), "0001-01-01"),
+        ((0, 12, 30), "0000-12-28"),
+        ((-4712, 1, 1), "-4713-11-24"),
+    ];
+    for &((year, month, day), checkdate) in testdates.iter() {
+        let julian = JulianDate::from_ymd_opt(year,month,day).unwrap();
+        let date = julian.to_naive_date();
+        let checkdate = NaiveDate::parse_from_str(checkdate,"%Y-%m-%d").ok();
+        if date != checkdate {
+            panic!("Julian date conversion failed for {:?}\nReceived: {:?}\nExpected: {:?}",
+                   julian, date, checkdate);
+        }
+        assert_eq!(JulianDate::from_naive_date(&date.unwrap()), julian);
+    }
+    assert_eq!(JulianDate::from_ymd_opt(-4712,1,1).unwrap().julian_day_number(), 0);
+    assert_eq!(JulianDate::from_ymd_opt(1700,2,29).map(|date| date.day()), Some(29));
+    assert_eq!(JulianDate::from_ymd_opt(1701,2,29), None);
+    assert_eq!(JulianDate::from_ymd_opt(1700,13,1), None);
+    assert_eq!(JulianDate::from_ymd_opt(1700,2,28).and_then(|date| date.succ_opt()), JulianDate::from_ymd_opt(1700,2,29));
+    assert_eq!(JulianDate::from_ymd_opt(1701,1,1).and_then(|date| date.pred_opt()), JulianDate::from_ymd_opt(1700,12,31));
+    for date in [MIN_DATE.naive_utc(), MAX_DATE.naive_utc()].iter() {
+        assert_eq!(JulianDate::from_naive_date(date).to_naive_date(), Some(*date));
+    }
+}
@@ -0,0 +1,4 @@
+mod julian_date;
+mod cutover;
+pub use self::julian_date::{JulianDate,is_leap_year,days_in_month};
+pub use self::cutover::{Cutover,HistoricalDate};
\ No newline at end of file
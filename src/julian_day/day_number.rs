@@ -0,0 +1,197 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Duration;
+use chrono::Timelike;
+/// Nanoseconds per day.
+pub const NANOSECONDS_PER_DAY: u64 = 86_400_000_000_000;
+/// Days beyond chrono range, used to avoid `Duration` overflow.
+const MAX_DAYS: i64 = 1 << 32;
+/// Epoch of day counting.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DayEpoch {
+    /// Julian Day (JD). Day 0 starts at noon of -4713-11-24 (proleptic Gregorian calendar).
+    JulianDay,
+    /// Modified Julian Date (MJD). `MJD = JD - 2400000.5`.
+    ModifiedJulianDay,
+    /// Reduced Julian Day (RJD). `RJD = JD - 2400000`.
+    ReducedJulianDay,
+    /// Truncated Julian Day (TJD). `TJD = JD - 2440000.5`.
+    TruncatedJulianDay,
+}
+impl DayEpoch {
+    /// Returns start of day 0 in UTC.
+    pub fn epoch(&self) -> NaiveDateTime {
+        match *self {
+            DayEpoch::JulianDay => NaiveDate::from_ymd_opt(-4713, 11, 24).unwrap().and_hms_opt(12, 0, 0).unwrap(),
+            DayEpoch::ModifiedJulianDay => NaiveDate::from_ymd_opt(1858, 11, 17).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+            DayEpoch::ReducedJulianDay => NaiveDate::from_ymd_opt(1858, 11, 16).unwrap().and_hms_opt(12, 0, 0).unwrap(),
+            DayEpoch::TruncatedJulianDay => NaiveDate::from_ymd_opt(1968, 5, 24).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+        }
+    }
+    /// Returns abbreviation of day counting such as `JD`.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            DayEpoch::JulianDay => "JD",
+            DayEpoch::ModifiedJulianDay => "MJD",
+            DayEpoch::ReducedJulianDay => "RJD",
+            DayEpoch::TruncatedJulianDay => "TJD",
+        }
+    }
+    /// Returns day counting by its abbreviation.
+    pub fn from_name(name: &str) -> Option<DayEpoch> {
+        match name {
+            "JD" => Some(DayEpoch::JulianDay),
+            "MJD" => Some(DayEpoch::ModifiedJulianDay),
+            "RJD" => Some(DayEpoch::ReducedJulianDay),
+            "TJD" => Some(DayEpoch::TruncatedJulianDay),
+            _ => None,
+        }
+    }
+}
+/// Day number split to the whole day and the fraction of day with nanosecond precision.
+///
+/// Value is `day + nanosecond / 86 400 000 000 000`. Negative values have negative `day`
+/// and positive `nanosecond`, so `-0.25` is `day: -1, nanosecond: 64 800 000 000 000`.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct DayNumber {
+    /// Whole days since epoch.
+    pub day: i64,
+    /// Nanoseconds since start of day (0 through 86 399 999 999 999).
+    pub nanosecond: u64,
+}
+impl DayNumber {
+    /// Returns day number of UTC date and time.
+    ///
+    /// Leap second is counted as the last nanosecond of day.
+    pub fn from_naive(datetime: &NaiveDateTime,epoch: DayEpoch) -> DayNumber {
+        return DayNumber::from_naive_since(datetime, &epoch.epoch());
+    }
+    /// Returns count of days between `epoch` and `datetime`.
+    ///
+    /// Leap second is counted as the last nanosecond of day.
+    pub fn from_naive_since(datetime: &NaiveDateTime,epoch: &NaiveDateTime) -> DayNumber {
+        let mut day = datetime.date().signed_duration_since(epoch.date()).num_days();
+        let mut nanosecond = time_nanosecond(datetime) as i64 - time_nanosecond(epoch) as i64;
+        if nanosecond < 0 {
+            day = day - 1;
+            nanosecond = nanosecond + NANOSECONDS_PER_DAY as i64;
+        }
+        return DayNumber {
+            day: day,
+            nanosecond: nanosecond as u64,
+        };
+    }
+    /// Returns day number of date and time.
+    pub fn from_datetime(datetime: &DateTime<FixedOffset>,epoch: DayEpoch) -> DayNumber {
+        return DayNumber::from_naive(&datetime.naive_utc(), epoch);
+    }
+    /// Returns UTC date and time of day number.
+    ///
+    /// Returns `None` if value is out of range.
+    pub fn to_naive(&self,epoch: DayEpoch) -> Option<NaiveDateTime> {
+        return self.to_naive_since(&epoch.epoch());
+    }
+    /// Returns date and time which is day number of days after `epoch`.
+    ///
+    /// Returns `None` if value is out of range.
+    pub fn to_naive_since(&self,epoch: &NaiveDateTime) -> Option<NaiveDateTime> {
+        if self.nanosecond >= NANOSECONDS_PER_DAY || self.day.unsigned_abs() > MAX_DAYS as u64 {
+            return None;
+        }
+        let days = epoch.checked_add_signed(Duration::days(self.day))?;
+        return days.checked_add_signed(Duration::nanoseconds(self.nanosecond as i64));
+    }
+    /// Returns UTC `DateTime` of day number.
+    ///
+    /// Returns `None` if value is out of range.
+    pub fn to_datetime(&self,epoch: DayEpoch) -> Option<DateTime<FixedOffset>> {
+        return self.to_naive(epoch).map(|datetime| DateTime::from_utc(datetime, FixedOffset::east_opt(0).unwrap()));
+    }
+    /// Returns day number in another day counting.
+    ///
+    /// Returns `None` if value is out of range.
+    pub fn convert(&self,from: DayEpoch,to: DayEpoch) -> Option<DayNumber> {
+        if self.nanosecond >= NANOSECONDS_PER_DAY {
+            return None;
+        }
+        let from = DayNumber::from_naive(&from.epoch(), to);
+        let mut day = self.day.checked_add(from.day)?;
+        let mut nanosecond = self.nanosecond + from.nanosecond;
+        if nanosecond >= NANOSECONDS_PER_DAY {
+            day = day.checked_add(1)?;
+            nanosecond = nanosecond - NANOSECONDS_PER_DAY;
+        }
+        return Some(DayNumber {
+            day: day,
+            nanosecond: nanosecond,
+        });
+    }
+    /// Returns day number from floating point value.
+    ///
+    /// Double precision keeps about 20 microseconds for current Julian Day values.
+    pub fn from_f64(value: f64) -> Option<DayNumber> {
+        if !value.is_finite() || value.abs() >= i64::MAX as f64 {
+            return None;
+        }
+        let day = value.floor();
+        let mut result = DayNumber {
+            day: day as i64,
+            nanosecond: ((value - day) * NANOSECONDS_PER_DAY as f64).round() as u64,
+        };
+        if result.nanosecond >= NANOSECONDS_PER_DAY {
+            result.day = result.day + 1;
+            result.nanosecond = result.nanosecond - NANOSECONDS_PER_DAY;
+        }
+        return Some(result);
+    }
+    /// Returns day number as floating point value.
+    pub fn to_f64(&self) -> f64 {
+        return self.day as f64 + self.nanosecond as f64 / NANOSECONDS_PER_DAY as f64;
+    }
+}
+fn time_nanosecond(datetime: &NaiveDateTime) -> u64 {
+    let nanosecond = datetime.num_seconds_from_midnight() as u64 * 1_000_000_000 + datetime.nanosecond() as u64;
+    if nanosecond >= NANOSECONDS_PER_DAY {
+        return NANOSECONDS_PER_DAY - 1;
+    }
+    return nanosecond;
+}
+#[cfg(test)]
+#[test]
+fn test_day_number() {
+    let j2000 = NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0);
+    let unix = NaiveDate::from_ymd(1970, 1, 1).and_hms(0, 0, 0);
+    let half = NANOSECONDS_PER_DAY / 2;
+    let testdata = [
+        (j2000, DayEpoch::JulianDay, 2451545, 0),
+        (j2000, DayEpoch::ModifiedJulianDay, 51544, half),
+        (j2000, DayEpoch::ReducedJulianDay, 51545, 0),
+        (j2000, DayEpoch::TruncatedJulianDay, 11544, half),
+        (unix, DayEpoch::JulianDay, 2440587, half),
+        (unix, DayEpoch::ModifiedJulianDay, 40587, 0),
+        (unix, DayEpoch::TruncatedJulianDay, 587, 0),
+        (NaiveDate::from_ymd(1858, 11, 16).and_hms(18, 0, 0), DayEpoch::ModifiedJulianDay, -1, half + half / 2),
+    ];
+    for &(datetime, epoch, day, nanosecond) in testdata.iter() {
+        let value = DayNumber::from_naive(&datetime, epoch);
+        assert_eq!(value, DayNumber { day: day, nanosecond: nanosecond }, "{} {:?}", datetime, epoch);
+        assert_eq!(value.to_naive(epoch), Some(datetime));
+    }
+    let mjd = DayNumber::from_naive(&j2000, DayEpoch::ModifiedJulianDay);
+    assert_eq!(mjd.convert(DayEpoch::ModifiedJulianDay, DayEpoch::JulianDay), Some(DayNumber { day: 2451545, nanosecond: 0 }));
+    let min = DayNumber { day: i64::MIN, nanosecond: 0 };
+    let max = DayNumber { day: i64::MAX, nanosecond: half };
+    assert_eq!(min.to_naive(DayEpoch::JulianDay), None);
+    assert_eq!(max.to_naive(DayEpoch::JulianDay), None);
+    assert_eq!(min.convert(DayEpoch::JulianDay, DayEpoch::ModifiedJulianDay), None);
+    assert_eq!(max.convert(DayEpoch::ModifiedJulianDay, DayEpoch::JulianDay), None);
+    assert_eq!(max.convert(DayEpoch::ModifiedJulianDay, DayEpoch::TruncatedJulianDay).map(|value| value.day), Some(i64::MAX - 40_000));
+    assert_eq!(mjd.to_f64(), 51544.5);
+    assert_eq!(DayNumber::from_f64(-0.25), Some(DayNumber { day: -1, nanosecond: 3 * NANOSECONDS_PER_DAY / 4 }));
+    assert_eq!(DayNumber::from_f64(f64::NAN), None);
+    let precise = NaiveDate::from_ymd(2015, 1, 20).and_hms_nano(17, 35, 20, 123_456_789);
+    let jd = DayNumber::from_naive(&precise, DayEpoch::JulianDay);
+    assert_eq!(jd.to_naive(DayEpoch::JulianDay), Some(precise));
+}
@@ -0,0 +1,2 @@
+mod day_number;
+pub use self::day_number::{DayEpoch,DayNumber,NANOSECONDS_PER_DAY};
\ No newline at end of file
@@ -0,0 +1,199 @@
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Duration;
+use parser::error::{ParseErrorKind,ParseError,ParseResult};
+/// IERS leap seconds: date from which `TAI - UTC` is in effect and its value.
+const BUILTIN_LEAP_SECONDS: [(i32, u32, i32); 28] = [
+    (1972, 1, 10),
+    (1972, 7, 11),
+    (1973, 1, 12),
+    (1974, 1, 13),
+    (1975, 1, 14),
+    (1976, 1, 15),
+    (1977, 1, 16),
+    (1978, 1, 17),
+    (1979, 1, 18),
+    (1980, 1, 19),
+    (1981, 7, 20),
+    (1982, 7, 21),
+    (1983, 7, 22),
+    (1985, 7, 23),
+    (1988, 1, 24),
+    (1990, 1, 25),
+    (1991, 1, 26),
+    (1992, 7, 27),
+    (1993, 7, 28),
+    (1994, 7, 29),
+    (1996, 1, 30),
+    (1997, 7, 31),
+    (1999, 1, 32),
+    (2006, 1, 33),
+    (2009, 1, 34),
+    (2012, 7, 35),
+    (2015, 7, 36),
+    (2017, 1, 37),
+];
+/// Expiration date of the bundled table (IERS Bulletin C 70).
+const BUILTIN_EXPIRES: (i32, u32, u32) = (2026, 6, 28);
+/// Table of UTC leap seconds.
+///
+/// Each entry holds a date from which `TAI - UTC` offset (in seconds) is in effect.
+/// A positive leap second `23:59:60` is inserted at the end of the day before an entry
+/// when offset is increased by one second.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LeapSecondTable {
+    entries: Vec<(NaiveDate, i32)>,
+    expires: Option<NaiveDate>,
+}
+impl LeapSecondTable {
+    /// Returns table bundled with the library.
+    pub fn builtin() -> LeapSecondTable {
+        let entries = BUILTIN_LEAP_SECONDS.iter().filter_map(|&(year, month, offset)| {
+            NaiveDate::from_ymd_opt(year, month, 1).map(|date| (date, offset))
+        }).collect();
+        let (year, month, day) = BUILTIN_EXPIRES;
+        return LeapSecondTable {
+            entries: entries,
+            expires: NaiveDate::from_ymd_opt(year, month, day),
+        };
+    }
+    /// Parses IERS `leap-seconds.list` file content.
+    ///
+    /// Data lines have format `<NTP seconds> <TAI - UTC> [# comment]`.
+    /// Line `#@ <NTP seconds>` holds the expiration date, other lines beginning with `#` are ignored.
+    pub fn parse_leap_seconds_list(text: &str) -> ParseResult<LeapSecondTable> {
+        let mut entries: Vec<(NaiveDate, i32)> = Vec::new();
+        let mut expires = None;
+        let mut line_position = 0;
+        for line in text.lines() {
+            let position = line_position;
+            line_position = line_position + line.chars().count() + 1;
+            let line = line.trim_end_matches('\r');
+            if let Some(expires_field) = line.strip_prefix("#@") {
+                let ntp = parse_list_number(expires_field, position + 2)?;
+                let datetime = ntp_to_datetime(ntp, position + 2, line.chars().count() - 2)?;
+                expires = Some(datetime.date());
+                continue;
+            }
+            let data = match line.find('#') {
+                Some(index) => &line[..index],
+                None => line,
+            };
+            if data.trim().is_empty() {
+                continue;
+            }
+            let mut fields = data.split_whitespace();
+            let ntp_field = fields.next().unwrap_or("");
+            let offset_field = match fields.next() {
+                Some(field) => field,
+                None => return Err(ParseError::invalid_format(position, data.chars().count())),
+            };
+            if fields.next().is_some() {
+                return Err(ParseError::invalid(ParseErrorKind::StringNotEnded, position, data.chars().count()));
+            }
+            let ntp = parse_list_number(ntp_field, position)?;
+            let offset = parse_list_number(offset_field, position)?;
+            let datetime = ntp_to_datetime(ntp, position, ntp_field.len())?;
+            if let Some(&(previous, _)) = entries.last() {
+                if datetime.date() <= previous {
+                    return Err(ParseError::invalid_low_value(position, ntp_field.len()));
+                }
+            }
+            entries.push((datetime.date(), offset as i32));
+        }
+        if entries.is_empty() {
+            return Err(ParseError::invalid_format(0, line_position));
+        }
+        return Ok(LeapSecondTable {
+            entries: entries,
+            expires: expires,
+        });
+    }
+    /// Returns entries of the table: date from which `TAI - UTC` offset is in effect and its value.
+    pub fn entries(&self) -> &[(NaiveDate, i32)] {
+        return &self.entries;
+    }
+    /// Returns date after which the table is not valid.
+    pub fn expires(&self) -> Option<NaiveDate> {
+        return self.expires;
+    }
+    /// Returns `true` if positive leap second `23:59:60` is inserted at the end of `date` (UTC).
+    pub fn is_leap_second_day(&self, date: NaiveDate) -> bool {
+        let next = match date.succ_opt() {
+            Some(next) => next,
+            None => return false,
+        };
+        for index in 1..self.entries.len() {
+            if self.entries[index].0 == next {
+                return self.entries[index].1 - self.entries[index - 1].1 == 1;
+            }
+        }
+        return false;
+    }
+    /// Returns `TAI - UTC` offset in seconds at the given UTC date and time.
+    ///
+    /// Returns `None` for dates before the first entry of the table.
+    pub fn tai_minus_utc(&self, datetime: &NaiveDateTime) -> Option<i32> {
+        let date = datetime.date();
+        let mut result = None;
+        for &(since, offset) in self.entries.iter() {
+            if since > date {
+                break;
+            }
+            result = Some(offset);
+        }
+        return result;
+    }
+}
+fn parse_list_number(text: &str, position: usize) -> ParseResult<i64> {
+    let text = text.trim();
+    if let Ok(value) = text.parse::<i64>() {
+        return Ok(value);
+    }
+    return Err(ParseError::invalid_format(position, text.chars().count()));
+}
+fn ntp_to_datetime(ntp: i64, position: usize, length: usize) -> ParseResult<NaiveDateTime> {
+    if ntp < 0 {
+        return Err(ParseError::invalid_low_value(position, length));
+    }
+    let epoch = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    if let Some(datetime) = epoch.checked_add_signed(Duration::seconds(ntp)) {
+        return Ok(datetime);
+    }
+    return Err(ParseError::invalid_high_value(position, length));
+}
+#[cfg(test)]
+#[test]
+fn test_leap_second_table() {
+    let list = "\
+#   leap-seconds.list
+#$   3676924800
+#@   3960057600
+#
+2272060800      10      # 1 Jan 1972
+2287785600      11      # 1 Jul 1972
+2303683200      12      # 1 Jan 1973
+3692217600      37      # 1 Jan 2017
+";
+    let table = LeapSecondTable::parse_leap_seconds_list(list).unwrap();
+    assert_eq!(table.expires(), Some(NaiveDate::from_ymd(2025, 6, 28)));
+    assert_eq!(table.entries().len(), 4);
+    assert_eq!(table.entries()[3], (NaiveDate::from_ymd(2017, 1, 1), 37));
+    assert!(table.is_leap_second_day(NaiveDate::from_ymd(1972, 6, 30)));
+    assert!(!table.is_leap_second_day(NaiveDate::from_ymd(1971, 12, 31)));
+    assert!(!table.is_leap_second_day(NaiveDate::from_ymd(2016, 12, 31)));
+
+    let builtin = LeapSecondTable::builtin();
+    assert!(builtin.is_leap_second_day(NaiveDate::from_ymd(2016, 12, 31)));
+    assert!(builtin.is_leap_second_day(NaiveDate::from_ymd(2015, 6, 30)));
+    assert!(!builtin.is_leap_second_day(NaiveDate::from_ymd(2015, 12, 31)));
+    assert_eq!(builtin.tai_minus_utc(&NaiveDate::from_ymd(1971, 1, 1).and_hms(0, 0, 0)), None);
+    assert_eq!(builtin.tai_minus_utc(&NaiveDate::from_ymd(2016, 12, 31).and_hms(23, 59, 59)), Some(36));
+    assert_eq!(builtin.tai_minus_utc(&NaiveDate::from_ymd(2017, 1, 1).and_hms(0, 0, 0)), Some(37));
+
+    let error = LeapSecondTable::parse_leap_seconds_list("# header\n2272060800 x\n").unwrap_err();
+    assert_eq!(error.error_kind, ParseErrorKind::InvalidFormat);
+    assert_eq!(error.position_begin, 9);
+    let error = LeapSecondTable::parse_leap_seconds_list("2287785600 11\n2272060800 10\n").unwrap_err();
+    assert_eq!(error.error_kind, ParseErrorKind::InvalidLowValue);
+}
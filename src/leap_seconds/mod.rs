@@ -0,0 +1,2 @@
+mod leap_second_table;
+pub use self::leap_second_table::LeapSecondTable;
\ No newline at end of file
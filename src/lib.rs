@@ -4,9 +4,63 @@
 //! ## Supported features
 //!
 //! * W3C parser and formatter
+//! * Leap second table
+//! * TAI, GPS, Galileo and BeiDou time scales
+//! * Julian Day and Modified Julian Date
+//! * Spreadsheet serial dates
+//! * Windows FILETIME, MS-DOS, HFS+ and APFS timestamps
+//! * NTP timestamps and short format
+//! * ASN.1 UTCTime and GeneralizedTime parser and formatter
+//! * Syslog (RFC 3164 and RFC 5424) timestamp parser
+//! * Web server log (Apache, nginx, IIS) timestamp parser and formatter
+//! * Localized human date parser and CLDR-style formatter (en, ru, de, fr, es, pl, cs)
+//! * Natural language relative date parser
+//! * Relative time formatter such as `3 minutes ago`
+//! * Duration parser and formatter (compact, Go and humantime syntaxes)
+//! * iCalendar (RFC 5545) DATE, DATE-TIME, UTC-OFFSET, PERIOD and DURATION values
+//! * iCalendar recurrence rules (RRULE, RDATE, EXDATE) expansion
+//! * Cron expressions (classic and Quartz) with next and previous fire times
+//! * systemd calendar events (`OnCalendar=`) with next and previous elapse times
+//! * Calendar-aware arithmetic: months, years and business days with end-of-month policies
+//! * ISO 8601 durations and intervals such as `P1Y2M10DT2H30M` applied with calendar-aware arithmetic
+//! * Business day calendars with holiday rules (built-in US federal and TARGET2)
+//! * Western and Orthodox Easter and movable feasts
+//! * Julian calendar dates with national Gregorian cutovers and dual dating
+//! * Islamic (tabular and Umm al-Qura), Hebrew and Persian calendars
+//! * Japanese era, Minguo and Thai Buddhist year dates
 //#
+// Style of the original parser code: explicit `return`, `&Vec<char>` helpers, `field: field` initializers.
+#![allow(clippy::assign_op_pattern, clippy::clone_on_copy, clippy::is_digit_ascii_radix, clippy::let_unit_value,
+         clippy::match_ref_pats, clippy::needless_borrow, clippy::needless_late_init, clippy::needless_return,
+         clippy::ptr_arg, clippy::redundant_field_names)]
 extern crate chrono;
 /// Using for date and time parsing.
 pub mod parser;
 /// Using for date and time formatting.
-pub mod formatter;
\ No newline at end of file
+pub mod formatter;
+/// Using for leap seconds handling.
+pub mod leap_seconds;
+/// Using for conversion between UTC and atomic time scales.
+pub mod time_scale;
+/// Using for Julian Day and Modified Julian Date conversions.
+pub mod julian_day;
+/// Using for spreadsheet serial date conversions.
+pub mod spreadsheet;
+/// Using for file system timestamp conversions.
+pub mod file_time;
+/// Using for NTP timestamp conversions.
+pub mod ntp;
+/// Using for month and weekday names of languages.
+pub mod locale;
+/// Using for iCalendar recurrence rules expansion.
+pub mod recurrence;
+/// Using for cron schedules and systemd calendar events.
+pub mod cron;
+/// Using for calendar-aware date arithmetic and business day calendars.
+pub mod arithmetic;
+/// Using for Easter and movable feasts.
+pub mod easter;
+/// Using for Julian calendar dates and Gregorian cutovers.
+pub mod julian_calendar;
+/// Using for Islamic, Hebrew and Persian calendars and era years.
+pub mod calendars;
\ No newline at end of file
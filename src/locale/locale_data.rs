@@ -0,0 +1,463 @@
+/// Names and words of a language used for parsing and formatting of human dates.
+///
+/// Built-in locales are `EN`, `RU`, `DE`, `FR`, `ES`, `PL` and `CS`.
+/// Other locales can be defined as `static` values of this struct, taking untranslated fields
+/// from `Locale::ROOT`: `static UK: Locale = Locale { code: "uk", months: [...], ..Locale::ROOT };`.
+/// Names are matched case-insensitively.
+#[derive(Debug)]
+pub struct Locale {
+    /// Language code such as `ru`.
+    pub code: &'static str,
+    /// Full month names in nominative case, from January.
+    pub months: [&'static str; 12],
+    /// Full month names in the case used after day number, e.g. genitive `декабря` in Russian.
+    pub months_genitive: [&'static str; 12],
+    /// Abbreviated month names without trailing dot.
+    pub months_abbreviated: [&'static str; 12],
+    /// Full weekday names, from Monday.
+    pub weekdays: [&'static str; 7],
+    /// Abbreviated weekday names without trailing dot, from Monday.
+    pub weekdays_abbreviated: [&'static str; 7],
+    /// Words which are skipped between parts of date and time, such as `в`, `à`, `um`.
+    pub connectors: &'static [&'static str],
+    /// Suffixes of day number, such as `th` or `er`.
+    pub day_suffixes: &'static [&'static str],
+    /// Separators of hours and minutes.
+    pub time_separators: &'static [&'static str],
+    /// Markers of 12-hour clock: before noon and after noon.
+    pub am_pm: Option<[&'static str; 2]>,
+    /// CLDR date patterns: full, long, medium and short.
+    pub date_patterns: [&'static str; 4],
+    /// CLDR time patterns: full, long, medium and short.
+    pub time_patterns: [&'static str; 4],
+    /// CLDR patterns of date and time combination, where `{1}` is date and `{0}` is time: full, long, medium and short.
+    pub datetime_patterns: [&'static str; 4],
+    /// CLDR skeletons and their patterns, such as `("yMMMd", "MMM d, y")`.
+    pub skeletons: &'static [(&'static str, &'static str)],
+    /// Plural rule: returns index of plural form (one, few, many) of number.
+    pub plural: fn(u64) -> usize,
+    /// Names of seconds, minutes, hours, days, weeks, months and years in plural forms (one, few, many)
+    /// used in relative time.
+    pub relative_units: [[&'static str; 3]; 7],
+    /// Abbreviated names of seconds, minutes, hours, days, weeks, months and years.
+    pub relative_units_short: [&'static str; 7],
+    /// Names of units in the past pattern if they differ from `relative_units`, such as Czech instrumental case.
+    pub relative_units_past: Option<[[&'static str; 3]; 7]>,
+    /// Relative time patterns, where `{0}` is a count with unit: past, future and present.
+    pub relative_patterns: [&'static str; 3],
+}
+fn plural_one_other(number: u64) -> usize {
+    return if number == 1 { 0 } else { 2 };
+}
+fn plural_french(number: u64) -> usize {
+    return if number <= 1 { 0 } else { 2 };
+}
+fn plural_slavic(number: u64) -> usize {
+    if number % 10 == 1 && number % 100 != 11 {
+        return 0;
+    }
+    if (2..=4).contains(&(number % 10)) && !(12..=14).contains(&(number % 100)) {
+        return 1;
+    }
+    return 2;
+}
+fn plural_polish(number: u64) -> usize {
+    if number == 1 {
+        return 0;
+    }
+    if (2..=4).contains(&(number % 10)) && !(12..=14).contains(&(number % 100)) {
+        return 1;
+    }
+    return 2;
+}
+fn plural_czech(number: u64) -> usize {
+    return match number {
+        1 => 0,
+        2..=4 => 1,
+        _ => 2,
+    };
+}
+impl Locale {
+    /// Defaults for user-defined locales: patterns and skeletons of the CLDR root locale,
+    /// English names and words of relative time.
+    pub const ROOT: Locale = Locale {
+        code: "und",
+        months: ["January","February","March","April","May","June","July","August","September","October","November","December"],
+        months_genitive: ["January","February","March","April","May","June","July","August","September","October","November","December"],
+        months_abbreviated: ["Jan","Feb","Mar","Apr","May","Jun","Jul","Aug","Sep","Oct","Nov","Dec"],
+        weekdays: ["Monday","Tuesday","Wednesday","Thursday","Friday","Saturday","Sunday"],
+        weekdays_abbreviated: ["Mon","Tue","Wed","Thu","Fri","Sat","Sun"],
+        connectors: &[],
+        day_suffixes: &[],
+        time_separators: &[":"],
+        am_pm: Some(["AM","PM"]),
+        date_patterns: ["y MMMM d, EEEE","y MMMM d","y MMM d","y-MM-dd"],
+        time_patterns: ["HH:mm:ss zzzz","HH:mm:ss z","HH:mm:ss","HH:mm"],
+        datetime_patterns: ["{1} {0}","{1} {0}","{1} {0}","{1} {0}"],
+        skeletons: &[
+            ("yMMMd","y MMM d"),
+            ("yMMMMd","y MMMM d"),
+            ("MMMd","MMM d"),
+            ("yMd","y-MM-dd"),
+            ("yM","y-MM"),
+            ("yMMM","y MMM"),
+            ("MMMEd","MMM d, E"),
+            ("yMMMEd","y MMM d, E"),
+            ("Ed","d, E"),
+            ("Hm","HH:mm"),
+            ("Hms","HH:mm:ss"),
+            ("hm","h:mm a"),
+            ("hms","h:mm:ss a"),
+        ],
+        plural: plural_one_other,
+        relative_units: [
+            ["second","seconds","seconds"],
+            ["minute","minutes","minutes"],
+            ["hour","hours","hours"],
+            ["day","days","days"],
+            ["week","weeks","weeks"],
+            ["month","months","months"],
+            ["year","years","years"],
+        ],
+        relative_units_short: ["sec","min","h","d","wk","mo","yr"],
+        relative_units_past: None,
+        relative_patterns: ["{0} ago","in {0}","now"],
+    };
+    /// Returns built-in locale by language code such as `ru` or `ru-RU`.
+    pub fn builtin(code: &str) -> Option<&'static Locale> {
+        let language = code.split(&['-','_'][..]).next().unwrap_or(code);
+        return BUILTIN_LOCALES.iter().find(|locale| locale.code.eq_ignore_ascii_case(language)).cloned();
+    }
+}
+/// English.
+pub static EN: Locale = Locale {
+    code: "en",
+    months: ["January","February","March","April","May","June","July","August","September","October","November","December"],
+    months_genitive: ["January","February","March","April","May","June","July","August","September","October","November","December"],
+    months_abbreviated: ["Jan","Feb","Mar","Apr","May","Jun","Jul","Aug","Sep","Oct","Nov","Dec"],
+    weekdays: ["Monday","Tuesday","Wednesday","Thursday","Friday","Saturday","Sunday"],
+    weekdays_abbreviated: ["Mon","Tue","Wed","Thu","Fri","Sat","Sun"],
+    connectors: &["at","on","of","the"],
+    day_suffixes: &["st","nd","rd","th"],
+    time_separators: &[":"],
+    am_pm: Some(["AM","PM"]),
+    date_patterns: ["EEEE, MMMM d, y","MMMM d, y","MMM d, y","M/d/yy"],
+    time_patterns: ["h:mm:ss a zzzz","h:mm:ss a z","h:mm:ss a","h:mm a"],
+    datetime_patterns: ["{1} 'at' {0}","{1} 'at' {0}","{1}, {0}","{1}, {0}"],
+    skeletons: &[
+        ("yMMMd","MMM d, y"),
+        ("yMMMMd","MMMM d, y"),
+        ("MMMd","MMM d"),
+        ("MMMMd","MMMM d"),
+        ("yMd","M/d/y"),
+        ("yM","M/y"),
+        ("yMMM","MMM y"),
+        ("yMMMM","MMMM y"),
+        ("MMMEd","EEE, MMM d"),
+        ("yMMMEd","EEE, MMM d, y"),
+        ("Ed","d EEE"),
+        ("Hm","HH:mm"),
+        ("Hms","HH:mm:ss"),
+        ("hm","h:mm a"),
+        ("hms","h:mm:ss a"),
+    ],
+    plural: plural_one_other,
+    relative_units: [
+        ["second","seconds","seconds"],
+        ["minute","minutes","minutes"],
+        ["hour","hours","hours"],
+        ["day","days","days"],
+        ["week","weeks","weeks"],
+        ["month","months","months"],
+        ["year","years","years"],
+    ],
+    relative_units_short: ["sec","min","h","d","wk","mo","yr"],
+    relative_units_past: None,
+    relative_patterns: ["{0} ago","in {0}","now"],
+};
+/// Russian.
+pub static RU: Locale = Locale {
+    code: "ru",
+    months: ["январь","февраль","март","апрель","май","июнь","июль","август","сентябрь","октябрь","ноябрь","декабрь"],
+    months_genitive: ["января","февраля","марта","апреля","мая","июня","июля","августа","сентября","октября","ноября","декабря"],
+    months_abbreviated: ["янв","фев","мар","апр","май","июн","июл","авг","сен","окт","ноя","дек"],
+    weekdays: ["понедельник","вторник","среда","четверг","пятница","суббота","воскресенье"],
+    weekdays_abbreviated: ["пн","вт","ср","чт","пт","сб","вс"],
+    connectors: &["в","г.","г","года"],
+    day_suffixes: &[],
+    time_separators: &[":"],
+    am_pm: None,
+    date_patterns: ["EEEE, d MMMM y 'г'.","d MMMM y 'г'.","d MMM y 'г'.","dd.MM.y"],
+    time_patterns: ["HH:mm:ss zzzz","HH:mm:ss z","HH:mm:ss","HH:mm"],
+    datetime_patterns: ["{1}, {0}","{1}, {0}","{1}, {0}","{1}, {0}"],
+    skeletons: &[
+        ("yMMMd","d MMM y 'г'."),
+        ("yMMMMd","d MMMM y 'г'."),
+        ("MMMd","d MMM"),
+        ("MMMMd","d MMMM"),
+        ("yMd","dd.MM.y"),
+        ("yM","MM.y"),
+        ("yMMM","LLL y 'г'."),
+        ("yMMMM","LLLL y 'г'."),
+        ("MMMEd","ccc, d MMM"),
+        ("yMMMEd","EEE, d MMM y 'г'."),
+        ("Ed","ccc, d"),
+        ("Hm","HH:mm"),
+        ("Hms","HH:mm:ss"),
+        ("hm","h:mm a"),
+        ("hms","h:mm:ss a"),
+    ],
+    plural: plural_slavic,
+    relative_units: [
+        ["секунду","секунды","секунд"],
+        ["минуту","минуты","минут"],
+        ["час","часа","часов"],
+        ["день","дня","дней"],
+        ["неделю","недели","недель"],
+        ["месяц","месяца","месяцев"],
+        ["год","года","лет"],
+    ],
+    relative_units_short: ["сек.","мин.","ч","дн.","нед.","мес.","г."],
+    relative_units_past: None,
+    relative_patterns: ["{0} назад","через {0}","сейчас"],
+};
+/// German.
+pub static DE: Locale = Locale {
+    code: "de",
+    months: ["Januar","Februar","März","April","Mai","Juni","Juli","August","September","Oktober","November","Dezember"],
+    months_genitive: ["Januar","Februar","März","April","Mai","Juni","Juli","August","September","Oktober","November","Dezember"],
+    months_abbreviated: ["Jan","Feb","Mär","Apr","Mai","Jun","Jul","Aug","Sep","Okt","Nov","Dez"],
+    weekdays: ["Montag","Dienstag","Mittwoch","Donnerstag","Freitag","Samstag","Sonntag"],
+    weekdays_abbreviated: ["Mo","Di","Mi","Do","Fr","Sa","So"],
+    connectors: &["um","den","Uhr"],
+    day_suffixes: &["."],
+    time_separators: &[":","."],
+    am_pm: None,
+    date_patterns: ["EEEE, d. MMMM y","d. MMMM y","dd.MM.y","dd.MM.yy"],
+    time_patterns: ["HH:mm:ss zzzz","HH:mm:ss z","HH:mm:ss","HH:mm"],
+    datetime_patterns: ["{1} 'um' {0}","{1} 'um' {0}","{1}, {0}","{1}, {0}"],
+    skeletons: &[
+        ("yMMMd","d. MMM y"),
+        ("yMMMMd","d. MMMM y"),
+        ("MMMd","d. MMM"),
+        ("MMMMd","d. MMMM"),
+        ("yMd","d.M.y"),
+        ("yM","M/y"),
+        ("yMMM","MMM y"),
+        ("yMMMM","MMMM y"),
+        ("MMMEd","E, d. MMM"),
+        ("yMMMEd","E, d. MMM y"),
+        ("Ed","E, d."),
+        ("Hm","HH:mm"),
+        ("Hms","HH:mm:ss"),
+        ("hm","h:mm a"),
+        ("hms","h:mm:ss a"),
+    ],
+    plural: plural_one_other,
+    relative_units: [
+        ["Sekunde","Sekunden","Sekunden"],
+        ["Minute","Minuten","Minuten"],
+        ["Stunde","Stunden","Stunden"],
+        ["Tag","Tagen","Tagen"],
+        ["Woche","Wochen","Wochen"],
+        ["Monat","Monaten","Monaten"],
+        ["Jahr","Jahren","Jahren"],
+    ],
+    relative_units_short: ["Sek.","Min.","Std.","Tg.","Wo.","Mon.","J."],
+    relative_units_past: None,
+    relative_patterns: ["vor {0}","in {0}","jetzt"],
+};
+/// French.
+pub static FR: Locale = Locale {
+    code: "fr",
+    months: ["janvier","février","mars","avril","mai","juin","juillet","août","septembre","octobre","novembre","décembre"],
+    months_genitive: ["janvier","février","mars","avril","mai","juin","juillet","août","septembre","octobre","novembre","décembre"],
+    months_abbreviated: ["janv","févr","mars","avr","mai","juin","juil","août","sept","oct","nov","déc"],
+    weekdays: ["lundi","mardi","mercredi","jeudi","vendredi","samedi","dimanche"],
+    weekdays_abbreviated: ["lun","mar","mer","jeu","ven","sam","dim"],
+    connectors: &["à","le"],
+    day_suffixes: &["er"],
+    time_separators: &[":","h"],
+    am_pm: None,
+    date_patterns: ["EEEE d MMMM y","d MMMM y","d MMM y","dd/MM/y"],
+    time_patterns: ["HH:mm:ss zzzz","HH:mm:ss z","HH:mm:ss","HH:mm"],
+    datetime_patterns: ["{1} 'à' {0}","{1} 'à' {0}","{1}, {0}","{1} {0}"],
+    skeletons: &[
+        ("yMMMd","d MMM y"),
+        ("yMMMMd","d MMMM y"),
+        ("MMMd","d MMM"),
+        ("MMMMd","d MMMM"),
+        ("yMd","dd/MM/y"),
+        ("yM","MM/y"),
+        ("yMMM","MMM y"),
+        ("yMMMM","MMMM y"),
+        ("MMMEd","E d MMM"),
+        ("yMMMEd","E d MMM y"),
+        ("Ed","E d"),
+        ("Hm","HH:mm"),
+        ("Hms","HH:mm:ss"),
+        ("hm","h:mm a"),
+        ("hms","h:mm:ss a"),
+    ],
+    plural: plural_french,
+    relative_units: [
+        ["seconde","secondes","secondes"],
+        ["minute","minutes","minutes"],
+        ["heure","heures","heures"],
+        ["jour","jours","jours"],
+        ["semaine","semaines","semaines"],
+        ["mois","mois","mois"],
+        ["an","ans","ans"],
+    ],
+    relative_units_short: ["s","min","h","j","sem.","m.","a"],
+    relative_units_past: None,
+    relative_patterns: ["il y a {0}","dans {0}","maintenant"],
+};
+/// Spanish.
+pub static ES: Locale = Locale {
+    code: "es",
+    months: ["enero","febrero","marzo","abril","mayo","junio","julio","agosto","septiembre","octubre","noviembre","diciembre"],
+    months_genitive: ["enero","febrero","marzo","abril","mayo","junio","julio","agosto","septiembre","octubre","noviembre","diciembre"],
+    months_abbreviated: ["ene","feb","mar","abr","may","jun","jul","ago","sep","oct","nov","dic"],
+    weekdays: ["lunes","martes","miércoles","jueves","viernes","sábado","domingo"],
+    weekdays_abbreviated: ["lun","mar","mié","jue","vie","sáb","dom"],
+    connectors: &["de","del","a las","a la"],
+    day_suffixes: &["º"],
+    time_separators: &[":"],
+    am_pm: None,
+    date_patterns: ["EEEE, d 'de' MMMM 'de' y","d 'de' MMMM 'de' y","d MMM y","d/M/yy"],
+    time_patterns: ["H:mm:ss (zzzz)","H:mm:ss z","H:mm:ss","H:mm"],
+    datetime_patterns: ["{1}, {0}","{1}, {0}","{1}, {0}","{1}, {0}"],
+    skeletons: &[
+        ("yMMMd","d MMM y"),
+        ("yMMMMd","d 'de' MMMM 'de' y"),
+        ("MMMd","d MMM"),
+        ("MMMMd","d 'de' MMMM"),
+        ("yMd","d/M/y"),
+        ("yM","M/y"),
+        ("yMMM","MMM y"),
+        ("yMMMM","MMMM 'de' y"),
+        ("MMMEd","E, d MMM"),
+        ("yMMMEd","EEE, d MMM y"),
+        ("Ed","E d"),
+        ("Hm","H:mm"),
+        ("Hms","H:mm:ss"),
+        ("hm","h:mm a"),
+        ("hms","h:mm:ss a"),
+    ],
+    plural: plural_one_other,
+    relative_units: [
+        ["segundo","segundos","segundos"],
+        ["minuto","minutos","minutos"],
+        ["hora","horas","horas"],
+        ["día","días","días"],
+        ["semana","semanas","semanas"],
+        ["mes","meses","meses"],
+        ["año","años","años"],
+    ],
+    relative_units_short: ["s","min","h","d","sem.","m.","a"],
+    relative_units_past: None,
+    relative_patterns: ["hace {0}","dentro de {0}","ahora"],
+};
+/// Polish.
+pub static PL: Locale = Locale {
+    code: "pl",
+    months: ["styczeń","luty","marzec","kwiecień","maj","czerwiec","lipiec","sierpień","wrzesień","październik","listopad","grudzień"],
+    months_genitive: ["stycznia","lutego","marca","kwietnia","maja","czerwca","lipca","sierpnia","września","października","listopada","grudnia"],
+    months_abbreviated: ["sty","lut","mar","kwi","maj","cze","lip","sie","wrz","paź","lis","gru"],
+    weekdays: ["poniedziałek","wtorek","środa","czwartek","piątek","sobota","niedziela"],
+    weekdays_abbreviated: ["pon","wt","śr","czw","pt","sob","niedz"],
+    connectors: &["o","r.","r","roku"],
+    day_suffixes: &[],
+    time_separators: &[":"],
+    am_pm: None,
+    date_patterns: ["EEEE, d MMMM y","d MMMM y","d MMM y","d.MM.y"],
+    time_patterns: ["HH:mm:ss zzzz","HH:mm:ss z","HH:mm:ss","HH:mm"],
+    datetime_patterns: ["{1} 'o' {0}","{1} 'o' {0}","{1}, {0}","{1}, {0}"],
+    skeletons: &[
+        ("yMMMd","d MMM y"),
+        ("yMMMMd","d MMMM y"),
+        ("MMMd","d MMM"),
+        ("MMMMd","d MMMM"),
+        ("yMd","d.MM.y"),
+        ("yM","MM.y"),
+        ("yMMM","LLL y"),
+        ("yMMMM","LLLL y"),
+        ("MMMEd","EEE, d MMM"),
+        ("yMMMEd","EEE, d MMM y"),
+        ("Ed","EEE, d"),
+        ("Hm","HH:mm"),
+        ("Hms","HH:mm:ss"),
+        ("hm","h:mm a"),
+        ("hms","h:mm:ss a"),
+    ],
+    plural: plural_polish,
+    relative_units: [
+        ["sekundę","sekundy","sekund"],
+        ["minutę","minuty","minut"],
+        ["godzinę","godziny","godzin"],
+        ["dzień","dni","dni"],
+        ["tydzień","tygodnie","tygodni"],
+        ["miesiąc","miesiące","miesięcy"],
+        ["rok","lata","lat"],
+    ],
+    relative_units_short: ["sek.","min","godz.","dn.","tydz.","mies.","r."],
+    relative_units_past: None,
+    relative_patterns: ["{0} temu","za {0}","teraz"],
+};
+/// Czech.
+pub static CS: Locale = Locale {
+    code: "cs",
+    months: ["leden","únor","březen","duben","květen","červen","červenec","srpen","září","říjen","listopad","prosinec"],
+    months_genitive: ["ledna","února","března","dubna","května","června","července","srpna","září","října","listopadu","prosince"],
+    months_abbreviated: ["led","úno","bře","dub","kvě","čvn","čvc","srp","zář","říj","lis","pro"],
+    weekdays: ["pondělí","úterý","středa","čtvrtek","pátek","sobota","neděle"],
+    weekdays_abbreviated: ["po","út","st","čt","pá","so","ne"],
+    connectors: &["v","ve"],
+    day_suffixes: &["."],
+    time_separators: &[":"],
+    am_pm: None,
+    date_patterns: ["EEEE d. MMMM y","d. MMMM y","d. M. y","dd.MM.yy"],
+    time_patterns: ["H:mm:ss zzzz","H:mm:ss z","H:mm:ss","H:mm"],
+    datetime_patterns: ["{1} 'v' {0}","{1} 'v' {0}","{1} {0}","{1} {0}"],
+    skeletons: &[
+        ("yMMMd","d. M. y"),
+        ("yMMMMd","d. MMMM y"),
+        ("MMMd","d. M."),
+        ("MMMMd","d. MMMM"),
+        ("yMd","d. M. y"),
+        ("yM","M/y"),
+        ("yMMM","LLLL y"),
+        ("yMMMM","LLLL y"),
+        ("MMMEd","EEE d. M."),
+        ("yMMMEd","EEE d. M. y"),
+        ("Ed","EEE d."),
+        ("Hm","H:mm"),
+        ("Hms","H:mm:ss"),
+        ("hm","h:mm a"),
+        ("hms","h:mm:ss a"),
+    ],
+    plural: plural_czech,
+    relative_units: [
+        ["sekundu","sekundy","sekund"],
+        ["minutu","minuty","minut"],
+        ["hodinu","hodiny","hodin"],
+        ["den","dny","dní"],
+        ["týden","týdny","týdnů"],
+        ["měsíc","měsíce","měsíců"],
+        ["rok","roky","let"],
+    ],
+    relative_units_short: ["s","min","h","d","týd.","měs.","r."],
+    relative_units_past: Some([
+        ["sekundou","sekundami","sekundami"],
+        ["minutou","minutami","minutami"],
+        ["hodinou","hodinami","hodinami"],
+        ["dnem","dny","dny"],
+        ["týdnem","týdny","týdny"],
+        ["měsícem","měsíci","měsíci"],
+        ["rokem","lety","lety"],
+    ]),
+    relative_patterns: ["před {0}","za {0}","nyní"],
+};
+/// All built-in locales.
+pub static BUILTIN_LOCALES: [&Locale; 7] = [&EN, &RU, &DE, &FR, &ES, &PL, &CS];
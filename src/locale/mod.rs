@@ -0,0 +1,2 @@
+mod locale_data;
+pub use self::locale_data::{Locale,EN,RU,DE,FR,ES,PL,CS,BUILTIN_LOCALES};
\ No newline at end of file
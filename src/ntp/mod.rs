@@ -0,0 +1,2 @@
+mod ntp_timestamp;
+pub use self::ntp_timestamp::{NtpTimestamp,NtpDate,NtpShort};
\ No newline at end of file
@@ -0,0 +1,249 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Duration;
+use chrono::Timelike;
+use parser::error::{ParseError,ParseResult};
+/// Seconds per NTP era.
+const SECONDS_PER_ERA: i64 = 1 << 32;
+fn ntp_epoch() -> NaiveDateTime {
+    return NaiveDate::from_ymd_opt(1900, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+}
+/// Returns seconds since NTP epoch and nanoseconds of date and time.
+/// Leap second repeats the last second of day as NTP does.
+fn seconds_since_epoch(datetime: &DateTime<FixedOffset>) -> (i64, u32) {
+    let utc = datetime.naive_utc();
+    let nanosecond = utc.nanosecond() % 1_000_000_000;
+    let seconds = utc.with_nanosecond(0).map(|utc| utc.signed_duration_since(ntp_epoch()).num_seconds()).unwrap_or(0);
+    return (seconds, nanosecond);
+}
+fn datetime_since_epoch(seconds: i64,nanosecond: u32) -> ParseResult<DateTime<FixedOffset>> {
+    // Duration::seconds panics far beyond the chrono range.
+    if seconds.abs() > 1 << 50 {
+        if seconds < 0 {
+            return Err(ParseError::invalid_low_value(0,0));
+        }
+        return Err(ParseError::invalid_high_value(0,0));
+    }
+    let duration = Duration::seconds(seconds) + Duration::nanoseconds(nanosecond as i64);
+    return match ntp_epoch().checked_add_signed(duration) {
+        Some(utc) => Ok(DateTime::from_utc(utc, FixedOffset::east_opt(0).unwrap())),
+        None if seconds < 0 => Err(ParseError::invalid_low_value(0,0)),
+        None => Err(ParseError::invalid_high_value(0,0)),
+    };
+}
+/// Returns nanoseconds of 64-bit binary fraction of second, rounded.
+fn fraction_to_nanosecond(fraction: u64) -> u64 {
+    return ((fraction as u128 * 1_000_000_000 + (1u128 << 63)) >> 64) as u64;
+}
+/// Returns 64-bit binary fraction of second of nanoseconds, rounded.
+fn nanosecond_to_fraction(nanosecond: u32) -> u128 {
+    return (((nanosecond as u128) << 64) + 500_000_000) / 1_000_000_000;
+}
+/// NTP timestamp format: 32-bit seconds and 32-bit fraction of second.
+///
+/// Seconds are counted since the start of NTP era. Era 0 starts at 1900-01-01 UTC,
+/// era 1 starts at 2036-02-07T06:28:16Z.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct NtpTimestamp {
+    /// Seconds since the start of era.
+    pub seconds: u32,
+    /// Fraction of second in units of 2^-32 seconds.
+    pub fraction: u32,
+}
+impl NtpTimestamp {
+    /// Returns timestamp of 64-bit value as it is transmitted in NTP packets.
+    pub fn from_u64(value: u64) -> NtpTimestamp {
+        return NtpTimestamp {
+            seconds: (value >> 32) as u32,
+            fraction: value as u32,
+        };
+    }
+    /// Returns 64-bit value as it is transmitted in NTP packets.
+    pub fn to_u64(&self) -> u64 {
+        return (self.seconds as u64) << 32 | self.fraction as u64;
+    }
+    /// Returns timestamp of date and time. Era number is dropped.
+    pub fn from_datetime(datetime: &DateTime<FixedOffset>) -> NtpTimestamp {
+        return NtpDate::from_datetime(datetime).timestamp();
+    }
+    /// Returns UTC `DateTime` of timestamp in the given era.
+    pub fn to_datetime(&self,era: i32) -> ParseResult<DateTime<FixedOffset>> {
+        return self.with_era(era).to_datetime();
+    }
+    /// Returns UTC `DateTime` of timestamp in the era which gives date nearest to `pivot`.
+    ///
+    /// This is the way of RFC 5905 to resolve era rollover: timestamp is assumed to be
+    /// within 68 years from the pivot, e.g. from the system clock.
+    pub fn to_datetime_near(&self,pivot: &DateTime<FixedOffset>) -> ParseResult<DateTime<FixedOffset>> {
+        let pivot = NtpDate::from_datetime(pivot);
+        let difference = (self.seconds as i64 - pivot.offset as i64).rem_euclid(SECONDS_PER_ERA);
+        let mut seconds = pivot.seconds() + difference;
+        if difference >= SECONDS_PER_ERA / 2 {
+            seconds = seconds - SECONDS_PER_ERA;
+        }
+        let era = seconds.div_euclid(SECONDS_PER_ERA) as i32;
+        return self.to_datetime(era);
+    }
+    /// Returns date of timestamp in the given era.
+    pub fn with_era(&self,era: i32) -> NtpDate {
+        return NtpDate {
+            era: era,
+            offset: self.seconds,
+            fraction: (self.fraction as u64) << 32,
+        };
+    }
+}
+/// NTP date format: 64-bit signed seconds (32-bit era and 32-bit era offset) and 64-bit fraction of second.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct NtpDate {
+    /// Era number. Era 0 starts at 1900-01-01 UTC.
+    pub era: i32,
+    /// Seconds since the start of era.
+    pub offset: u32,
+    /// Fraction of second in units of 2^-64 seconds.
+    pub fraction: u64,
+}
+impl NtpDate {
+    /// Returns date of seconds since 1900-01-01 UTC and fraction of second.
+    pub fn from_seconds(seconds: i64,fraction: u64) -> NtpDate {
+        return NtpDate {
+            era: seconds.div_euclid(SECONDS_PER_ERA) as i32,
+            offset: seconds.rem_euclid(SECONDS_PER_ERA) as u32,
+            fraction: fraction,
+        };
+    }
+    /// Returns seconds since 1900-01-01 UTC.
+    pub fn seconds(&self) -> i64 {
+        return self.era as i64 * SECONDS_PER_ERA + self.offset as i64;
+    }
+    /// Returns date of date and time. Fraction of second is rounded.
+    pub fn from_datetime(datetime: &DateTime<FixedOffset>) -> NtpDate {
+        let (mut seconds, nanosecond) = seconds_since_epoch(datetime);
+        let mut fraction = nanosecond_to_fraction(nanosecond);
+        if fraction >> 64 > 0 {
+            seconds = seconds + 1;
+            fraction = 0;
+        }
+        return NtpDate::from_seconds(seconds,fraction as u64);
+    }
+    /// Returns UTC `DateTime` of date. Fraction of second is rounded to nanoseconds.
+    pub fn to_datetime(&self) -> ParseResult<DateTime<FixedOffset>> {
+        let mut seconds = self.seconds();
+        let mut nanosecond = fraction_to_nanosecond(self.fraction);
+        if nanosecond >= 1_000_000_000 {
+            seconds = seconds + 1;
+            nanosecond = nanosecond - 1_000_000_000;
+        }
+        return datetime_since_epoch(seconds,nanosecond as u32);
+    }
+    /// Returns timestamp format of date. Era number is dropped and fraction of second is truncated.
+    pub fn timestamp(&self) -> NtpTimestamp {
+        return NtpTimestamp {
+            seconds: self.offset,
+            fraction: (self.fraction >> 32) as u32,
+        };
+    }
+}
+/// NTP short format: 16-bit seconds and 16-bit fraction of second.
+///
+/// It is used for intervals such as root delay and root dispersion, not for dates.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct NtpShort {
+    /// Whole seconds.
+    pub seconds: u16,
+    /// Fraction of second in units of 2^-16 seconds.
+    pub fraction: u16,
+}
+impl NtpShort {
+    /// Returns short format of 32-bit value as it is transmitted in NTP packets.
+    pub fn from_u32(value: u32) -> NtpShort {
+        return NtpShort {
+            seconds: (value >> 16) as u16,
+            fraction: value as u16,
+        };
+    }
+    /// Returns 32-bit value as it is transmitted in NTP packets.
+    pub fn to_u32(&self) -> u32 {
+        return (self.seconds as u32) << 16 | self.fraction as u32;
+    }
+    /// Returns short format of duration with fraction of second rounded,
+    /// `None` if duration is negative or is not less than 65536 seconds after rounding.
+    pub fn from_duration(duration: &Duration) -> Option<NtpShort> {
+        let seconds = duration.num_seconds();
+        if *duration < Duration::zero() || seconds > u16::MAX as i64 {
+            return None;
+        }
+        let nanosecond = (*duration - Duration::seconds(seconds)).num_nanoseconds().unwrap_or(0) as u64;
+        let value = ((seconds as u64) << 16) + ((nanosecond << 16) + 500_000_000) / 1_000_000_000;
+        if value > u32::MAX as u64 {
+            return None;
+        }
+        return Some(NtpShort::from_u32(value as u32));
+    }
+    /// Returns duration of short format. Fraction of second is rounded to nanoseconds.
+    pub fn to_duration(&self) -> Duration {
+        let nanosecond = ((self.fraction as i64) * 1_000_000_000 + (1 << 15)) >> 16;
+        return Duration::seconds(self.seconds as i64) + Duration::nanoseconds(nanosecond);
+    }
+}
+#[cfg(test)]
+#[test]
+fn test_ntp_timestamp() {
+    use parser::parse_w3c_datetime;
+    use formatter::{format_w3c,format_ntp,format_ntp_date,format_ntp_short};
+    let testdata = [
+        ("1900-01-01T00:00:00Z", 0, 0, 0),
+        ("1970-01-01T00:00:00Z", 0, 2208988800, 0),
+        ("2015-01-20T17:35:20.5-08:00", 0, 3630792920, 0x80000000),
+        ("2036-02-07T06:28:15Z", 0, 4294967295, 0),
+        ("2036-02-07T06:28:16Z", 1, 0, 0),
+        ("1899-12-31T23:59:59Z", -1, 4294967295, 0),
+    ];
+    for &(date, era, seconds, fraction) in testdata.iter() {
+        let datetime = parse_w3c_datetime(date).unwrap();
+        let ntp = NtpDate::from_datetime(&datetime);
+        assert_eq!((ntp.era, ntp.offset, ntp.timestamp().fraction), (era, seconds, fraction), "{}", date);
+        assert_eq!(ntp.to_datetime(), Ok(datetime));
+        let timestamp = NtpTimestamp::from_datetime(&datetime);
+        assert_eq!(timestamp.to_datetime(era), Ok(datetime));
+        assert_eq!(timestamp.to_datetime_near(&datetime), Ok(datetime));
+    }
+    let timestamp = NtpTimestamp::from_u64(0x0000_0010_8000_0000);
+    assert_eq!(timestamp, NtpTimestamp { seconds: 16, fraction: 0x80000000 });
+    assert_eq!(timestamp.to_u64(), 0x0000_0010_8000_0000);
+    let pivot = parse_w3c_datetime("2035-12-31T00:00:00Z").unwrap();
+    assert_eq!(format_w3c(&timestamp.to_datetime_near(&pivot).unwrap()), "2036-02-07T06:28:32.500Z");
+    let pivot = parse_w3c_datetime("1950-01-01T00:00:00Z").unwrap();
+    assert_eq!(format_w3c(&timestamp.to_datetime_near(&pivot).unwrap()), "1900-01-01T00:00:16.500Z");
+    let datetime = parse_w3c_datetime("2015-01-20T17:35:20.5-08:00").unwrap();
+    assert_eq!(format_ntp(&NtpTimestamp::from_datetime(&datetime)), "d86980d8.80000000");
+    let date = NtpDate { era: 0, offset: 3630792920, fraction: 0x0000_0000_0000_0001 };
+    assert_eq!(format_ntp_date(&date, None), Ok("2015-01-21T01:35:20Z".to_string()));
+    assert_eq!(format_ntp_date(&date, Some(19)), Ok("2015-01-21T01:35:20.0000000000000000000Z".to_string()));
+    let date = NtpDate { era: 0, offset: 3630792920, fraction: 0x4000_0000_0000_0000 };
+    assert_eq!(format_ntp_date(&date, None), Ok("2015-01-21T01:35:20.250Z".to_string()));
+    assert_eq!(format_ntp_date(&date, Some(2)), Ok("2015-01-21T01:35:20.25Z".to_string()));
+    assert_eq!(format_ntp_date(&date, Some(0)), Ok("2015-01-21T01:35:20Z".to_string()));
+    let datetime = parse_w3c_datetime("2015-01-20T17:35:20.000000001Z").unwrap();
+    assert_eq!(NtpDate::from_datetime(&datetime).to_datetime(), Ok(datetime));
+    let testshorts = [
+        (Duration::zero(), Some(0x0000_0000)),
+        (Duration::milliseconds(1500), Some(0x0001_8000)),
+        (Duration::nanoseconds(7_629), Some(0x0000_0000)),
+        (Duration::nanoseconds(7_630), Some(0x0000_0001)),
+        (Duration::seconds(65535) + Duration::nanoseconds(999_992_370), Some(0xffff_ffff)),
+        (Duration::seconds(65535) + Duration::nanoseconds(999_992_371), None),
+        (Duration::nanoseconds(-1), None),
+    ];
+    for &(duration, value) in testshorts.iter() {
+        let short = NtpShort::from_duration(&duration);
+        assert_eq!(short.map(|short| short.to_u32()), value, "{}", duration);
+    }
+    let short = NtpShort::from_u32(0x0002_4000);
+    assert_eq!(short, NtpShort { seconds: 2, fraction: 0x4000 });
+    assert_eq!(short.to_duration(), Duration::milliseconds(2250));
+    assert_eq!(NtpShort::from_u32(1).to_duration(), Duration::nanoseconds(15_259));
+    assert_eq!(format_ntp_short(&short), "0002.4000");
+}
@@ -30,6 +30,12 @@ pub enum ParseErrorKind {
     InvalidDate,
     /// Time is not exists.
     InvalidTime,
+    /// Unknown or missing unit.
+    InvalidUnit,
+    /// Value is out of range of result type.
+    Overflow,
+    /// Year is out of range of the era, such as the year after the next era started.
+    InvalidEraYear,
     /// Date is parsed, but there is some text after date.
     StringNotEnded,
 }
@@ -50,6 +56,9 @@ impl Error for ParseErrorKind {
             &ParseErrorKind::InvalidHighValue => "Invalid value range. Value is too high.",
             &ParseErrorKind::InvalidDate => "Date is not exists.",
             &ParseErrorKind::InvalidTime => "Time is not exists.",
+            &ParseErrorKind::InvalidUnit => "Unknown or missing unit.",
+            &ParseErrorKind::Overflow => "Value is out of range.",
+            &ParseErrorKind::InvalidEraYear => "Year is out of range of the era.",
             &ParseErrorKind::StringNotEnded => "Date is parsed, but there is some text after date.",
         }
     }
@@ -71,6 +80,9 @@ impl fmt::Display for ParseErrorKind {
             &ParseErrorKind::InvalidHighValue => write!(f,"Invalid value range. Value is too high."),
             &ParseErrorKind::InvalidDate => write!(f,"Date is not exists."),
             &ParseErrorKind::InvalidTime => write!(f,"Time is not exists."),
+            &ParseErrorKind::InvalidUnit => write!(f,"Unknown or missing unit."),
+            &ParseErrorKind::Overflow => write!(f,"Value is out of range."),
+            &ParseErrorKind::InvalidEraYear => write!(f,"Year is out of range of the era."),
             &ParseErrorKind::StringNotEnded => write!(f,"Date is parsed, but there is some text after date."),
         }
     }
@@ -130,6 +142,7 @@ impl ParseError {
 }
 
 impl Error for ParseError {
+    #[allow(deprecated)]
     fn description(&self) -> &str {
         return self.error_kind.description();
     }
@@ -1,4 +1,5 @@
 use chrono::FixedOffset;
+use chrono::NaiveTime;
 use std::iter::Extend;
 use super::error::{ParseErrorKind,ParseError,ParseResult};
 fn get_text(str: &Vec<char>,begin: usize, end: usize) -> String {
@@ -27,6 +28,14 @@ pub fn parse_u32(str: &Vec<char>,position: &mut usize,length: usize,error_kind:
     }
     return Err(ParseError::invalid(error_kind,position.clone(),length));
 }
+/// Parses from 1 to `max_length` digits.
+pub fn parse_digits(str: &Vec<char>,position: &mut usize,max_length: usize,error_kind: ParseErrorKind) -> ParseResult<u32> {
+    let length = str[(*position).min(str.len())..].iter().take(max_length).take_while(|c| c.is_ascii_digit()).count();
+    if length == 0 {
+        return Err(ParseError::invalid(error_kind,position.clone(),1));
+    }
+    return parse_u32(str,position,length,error_kind);
+}
 pub fn parse_full_year(str: &Vec<char>,position: &mut usize) ->  ParseResult<i32> {
     return parse_i32(str,position,4,ParseErrorKind::InvalidYear);
 }
@@ -45,6 +54,40 @@ pub fn parse_month_number(str: &Vec<char>,position: &mut usize) ->  ParseResult<
     let result = parse_u32(str,position,2,ParseErrorKind::InvalidMonth);
     return validate_range(result,1,12,position,2);
 }
+pub const MONTH_ABBREVIATIONS: [&str; 12] = ["Jan","Feb","Mar","Apr","May","Jun","Jul","Aug","Sep","Oct","Nov","Dec"];
+pub fn parse_month_abbreviation(str: &Vec<char>,position: &mut usize) ->  ParseResult<u32> {
+    if str.len() >= *position + 3 {
+        let text = get_text(&str,*position,*position+3);
+        for (index,name) in MONTH_ABBREVIATIONS.iter().enumerate() {
+            if text == *name {
+                *position = *position + 3;
+                return Ok(index as u32 + 1);
+            }
+        }
+    }
+    return Err(ParseError::invalid(ParseErrorKind::InvalidMonth,position.clone(),3));
+}
+/// Returns index and length in chars of the longest of `names` at position.
+/// Names are compared case-insensitively and must end at word boundary.
+pub fn match_name(str: &Vec<char>,position: usize,names: &[&str]) -> Option<(usize, usize)> {
+    let mut result: Option<(usize, usize)> = None;
+    for (index,name) in names.iter().enumerate() {
+        let length = name.chars().count();
+        if length == 0 || str.len() < position + length {
+            continue;
+        }
+        let is_equal = str[position..position+length].iter().zip(name.chars()).all(|(a,b)| a.to_lowercase().eq(b.to_lowercase()));
+        let is_boundary = position + length == str.len() || !str[position + length].is_alphanumeric() || !str[position + length - 1].is_alphanumeric();
+        if is_equal && is_boundary && result.map(|(_,result_length)| length > result_length).unwrap_or(true) {
+            result = Some((index, length));
+        }
+    }
+    return result;
+}
+/// Returns length of word (letters) at position.
+pub fn word_length(str: &Vec<char>,position: usize) -> usize {
+    return str[position.min(str.len())..].iter().take_while(|c| c.is_alphabetic()).count();
+}
 pub fn parse_day_number(str: &Vec<char>,position: &mut usize) ->  ParseResult<u32> {
     let result = parse_u32(str,position,2,ParseErrorKind::InvalidDay);
     return validate_range(result,1,31,position,2);
@@ -66,6 +109,10 @@ pub fn parse_seconds(str: &Vec<char>,position: &mut usize) ->  ParseResult<u32>
     let result = parse_u32(str,position,2,ParseErrorKind::InvalidSeconds);
     return validate_range(result,0,59,position,2);
 }
+pub fn parse_seconds_leap(str: &Vec<char>,position: &mut usize) ->  ParseResult<u32> {
+    let result = parse_u32(str,position,2,ParseErrorKind::InvalidSeconds);
+    return validate_range(result,0,60,position,2);
+}
 pub fn parse_nanosecond(str: &Vec<char>,position: &mut usize) -> ParseResult<u32> {
     let mut length = 0;
     if str.len() >= *position {
@@ -88,6 +135,7 @@ pub fn parse_nanosecond(str: &Vec<char>,position: &mut usize) -> ParseResult<u32
     }
     return Err(ParseError::invalid(ParseErrorKind::InvalidNanoseconds,position.clone(),length));   
 }
+#[allow(deprecated)]
 pub fn parse_tzd(str: &Vec<char>,position: &mut usize) ->  ParseResult<FixedOffset> {
     if try!(parse_is_token(str,position,"Z")) {
         return Ok(FixedOffset::east(0));
@@ -107,6 +155,25 @@ pub fn parse_tzd(str: &Vec<char>,position: &mut usize) ->  ParseResult<FixedOffs
     }
     return Err(ParseError::invalid_token(position.clone(),1));
 }
+pub fn parse_tzd_compact(str: &Vec<char>,position: &mut usize) ->  ParseResult<FixedOffset> {
+    let is_positive = parse_is_token(str,position,"+")?;
+    let is_negative = parse_is_token(str,position,"-")?;
+    if is_positive || is_negative {
+        let hour = parse_hour_timezone(str,position)?;
+        let minute = parse_minute(str,position)?;
+        let offset = (hour * 60 * 60 + minute * 60) as i32;
+        return to_offset(if is_negative { -offset } else { offset },*position - 4,4);
+    }
+    return Err(ParseError::invalid_token(position.clone(),1));
+}
+/// Returns offset of parsed seconds east of UTC, `InvalidHighValue` error if it is out of range.
+pub fn to_offset(seconds: i32,position: usize,length: usize) -> ParseResult<FixedOffset> {
+    return FixedOffset::east_opt(seconds).ok_or(ParseError::invalid_high_value(position,length));
+}
+/// Returns time of parsed fields, `InvalidTime` error for the whole text if there is no such time.
+pub fn to_time(str: &Vec<char>,hour: u32,minute: u32,seconds: u32,nanosecond: u32) -> ParseResult<NaiveTime> {
+    return NaiveTime::from_hms_nano_opt(hour,minute,seconds,nanosecond).ok_or(ParseError::invalid(ParseErrorKind::InvalidTime,0,str.len()));
+}
 pub fn parse_token(str:&Vec<char>,position: &mut usize,token: &str) -> ParseResult<()> {
     let length = token.len();
     if str.len() >= *position + length {
@@ -144,9 +211,78 @@ pub fn parse_is_token(str:&Vec<char>,position: &mut usize,token: &str) -> ParseR
     }
     return Err(ParseError::invalid_token(position.clone(),length));
 }
+pub fn parse_is_token_or_end(str:&Vec<char>,position: &mut usize,token: &str) -> ParseResult<bool> {
+    if str.len() == *position {
+        return Ok(false);
+    }
+    return parse_is_token(str,position,token);
+}
 pub fn parse_end_of_string(str: &Vec<char>,position: &usize) -> ParseResult<()> {
     if str.len() == *position {
         return Ok(());
     }
     return Err(ParseError::invalid(ParseErrorKind::StringNotEnded,position.clone(),0));
 }
+
+/// Component of an ISO 8601 or RFC 5545 duration such as `10D`, `-2D` or `0.5S`.
+pub struct DurationComponent {
+    /// Position of the component.
+    pub position: usize,
+    /// `true` if component has its own `-` sign.
+    pub is_negative: bool,
+    /// Integer part: up to 9 digits.
+    pub value: i64,
+    /// Fraction of the value in nanoseconds, if any.
+    pub nanosecond: Option<u32>,
+    /// Unit letter.
+    pub unit: char,
+    /// Position of the unit letter.
+    pub unit_position: usize,
+}
+/// Parses optional sign and `P` of a duration. Returns `true` for `-`.
+pub fn parse_duration_start(str: &Vec<char>,position: &mut usize) -> ParseResult<bool> {
+    let is_negative = parse_is_token(str,position,"-")?;
+    if !is_negative {
+        let _ = parse_is_token(str,position,"+")?;
+    }
+    let _ = parse_token(str,position,"P")?;
+    return Ok(is_negative);
+}
+/// Parses the next duration component, passing `T` before the time components and setting `is_time`.
+/// Returns `None` at the end of string or at `/`. Units are not checked.
+pub fn parse_duration_component(str: &Vec<char>,position: &mut usize,is_time: &mut bool) -> ParseResult<Option<DurationComponent>> {
+    if !*is_time && *position < str.len() && str[*position] == 'T' {
+        *is_time = true;
+        *position = *position + 1;
+    }
+    if *position >= str.len() || str[*position] == '/' {
+        return Ok(None);
+    }
+    let begin = *position;
+    let is_negative = parse_is_token(str,position,"-")?;
+    let number_position = *position;
+    let value = parse_digits(str,position,9,ParseErrorKind::InvalidFormat)? as i64;
+    if *position < str.len() && str[*position].is_ascii_digit() {
+        return Err(ParseError::invalid(ParseErrorKind::Overflow,number_position,*position - number_position + 1));
+    }
+    let mut nanosecond = None;
+    if *position < str.len() && (str[*position] == '.' || str[*position] == ',') {
+        *position = *position + 1;
+        nanosecond = Some(parse_nanosecond(str,position)?);
+        if *position < str.len() && str[*position].is_ascii_digit() {
+            return Err(ParseError::invalid(ParseErrorKind::InvalidNanoseconds,*position,1));
+        }
+    }
+    if *position >= str.len() {
+        return Err(ParseError::invalid(ParseErrorKind::InvalidUnit,*position,0));
+    }
+    *position = *position + 1;
+    return Ok(Some(DurationComponent {
+        position: begin,
+        is_negative: is_negative,
+        value: value,
+        nanosecond: nanosecond,
+        unit: str[*position - 1],
+        unit_position: *position - 1,
+    }));
+}
@@ -1,4 +1,42 @@
 mod parse_w3c_datetime;
+mod parse_w3c_naive;
+mod parse_time_scale;
+mod parse_julian_day;
+mod parse_asn1_time;
+mod parse_syslog;
+mod parse_web_log;
+mod parse_localized;
+mod parse_duration;
+mod parse_icalendar;
+mod parse_rrule;
+mod parse_cron;
+mod parse_calendar_event;
+mod parse_business_calendar;
+mod parse_julian_date;
+mod parse_calendar_date;
+mod parse_era_date;
+mod parse_iso_duration;
+mod parse_ntp;
 mod helper;
 pub mod error;
-pub use self::parse_w3c_datetime::parse_w3c_datetime;
\ No newline at end of file
+pub mod natural;
+pub use self::parse_w3c_datetime::{parse_w3c_datetime,parse_w3c_datetime_leap};
+pub use self::parse_w3c_naive::{parse_w3c_naive,W3cDateTime};
+pub use self::parse_time_scale::parse_time_scale_datetime;
+pub use self::parse_julian_day::parse_julian_day;
+pub use self::parse_asn1_time::{parse_utc_time,parse_generalized_time,Asn1Mode};
+pub use self::parse_syslog::{parse_rfc3164_timestamp,parse_rfc3164_timestamp_with_year,parse_rfc5424_timestamp};
+pub use self::parse_web_log::{parse_clf_datetime,parse_nginx_iso8601,parse_nginx_msec,parse_iis_datetime};
+pub use self::parse_localized::parse_localized;
+pub use self::natural::parse_natural;
+pub use self::parse_duration::{parse_duration,DurationSyntax};
+pub use self::parse_icalendar::{parse_ical_date,parse_ical_datetime,parse_ical_utc_offset,parse_ical_duration,parse_ical_period,ICalDateTime,ICalPeriod};
+pub use self::parse_rrule::{parse_rrule,parse_recurrence_set};
+pub use self::parse_cron::parse_cron;
+pub use self::parse_calendar_event::parse_calendar_event;
+pub use self::parse_business_calendar::parse_business_calendar;
+pub use self::parse_julian_date::{parse_julian_date,parse_historical_date};
+pub use self::parse_calendar_date::parse_calendar_date;
+pub use self::parse_era_date::parse_era_date;
+pub use self::parse_iso_duration::{parse_iso_duration,parse_iso_interval,IsoDuration,IsoInterval};
+pub use self::parse_ntp::{parse_ntp,parse_ntp_short};
\ No newline at end of file
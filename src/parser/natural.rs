@@ -0,0 +1,374 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::Datelike;
+use chrono::Duration;
+use locale::EN;
+use arithmetic::{CalendarArithmetic,MonthEndPolicy};
+use super::helper::match_name;
+use super::error::*;
+const UNITS: [&str; 16] = ["second","seconds","sec","minute","minutes","min","hour","hours","day","days","week","weeks","month","months","year","years"];
+/// A word of text with its position and length in chars.
+struct Word {
+    text: String,
+    position: usize,
+    length: usize,
+}
+impl Word {
+    fn error(&self) -> ParseError {
+        return ParseError::invalid_token(self.position,self.length);
+    }
+}
+fn split_words(text: &str) -> Vec<Word> {
+    let mut words = Vec::new();
+    let mut current: Option<Word> = None;
+    for (position,c) in text.chars().enumerate() {
+        if c.is_whitespace() || c == ',' {
+            if let Some(word) = current.take() {
+                words.push(word);
+            }
+            continue;
+        }
+        let word = current.get_or_insert(Word { text: String::new(), position: position, length: 0 });
+        word.text.extend(c.to_lowercase());
+        word.length = word.length + 1;
+    }
+    if let Some(word) = current.take() {
+        words.push(word);
+    }
+    return words;
+}
+fn find_name(word: &Word,names: &[&str]) -> Option<usize> {
+    let chars: Vec<char> = word.text.chars().collect();
+    return match match_name(&chars,0,names) {
+        Some((index,length)) if length == chars.len() || (length + 1 == chars.len() && chars[length] == '.') => Some(index),
+        _ => None,
+    };
+}
+fn find_weekday(word: &Word) -> Option<u32> {
+    return find_name(word,&EN.weekdays).or_else(|| find_name(word,&EN.weekdays_abbreviated)).map(|index| index as u32);
+}
+fn find_month(word: &Word) -> Option<u32> {
+    return find_name(word,&EN.months).or_else(|| find_name(word,&EN.months_abbreviated)).map(|index| index as u32 + 1);
+}
+fn parse_count(word: &Word) -> Option<i64> {
+    if word.text == "a" || word.text == "an" {
+        return Some(1);
+    }
+    if word.text.len() > 6 {
+        return None;
+    }
+    return word.text.parse::<i64>().ok();
+}
+/// Returns months and duration of `count` units.
+fn unit_duration(word: &Word,count: i64) -> ParseResult<(i64, Duration)> {
+    return match find_name(word,&UNITS).map(|index| UNITS[index]) {
+        Some("second") | Some("seconds") | Some("sec") => Ok((0, Duration::seconds(count))),
+        Some("minute") | Some("minutes") | Some("min") => Ok((0, Duration::minutes(count))),
+        Some("hour") | Some("hours") => Ok((0, Duration::hours(count))),
+        Some("day") | Some("days") => Ok((0, Duration::days(count))),
+        Some("week") | Some("weeks") => Ok((0, Duration::weeks(count))),
+        Some("month") | Some("months") => Ok((count, Duration::zero())),
+        Some("year") | Some("years") => Ok((count * 12, Duration::zero())),
+        _ => Err(word.error()),
+    };
+}
+/// Parses time such as `5pm`, `5:30 pm`, `17:30` or `17:30:15` starting at word `index`.
+///
+/// Returns time and count of consumed words.
+/// Bare hour such as `5` is a time only if `is_hour_allowed`.
+fn parse_clock(words: &[Word],index: usize,is_hour_allowed: bool) -> ParseResult<Option<(NaiveTime, usize)>> {
+    let word = &words[index];
+    let mut text: &str = &word.text;
+    let mut consumed = 1;
+    let mut am_pm: Option<u32> = None;
+    for (marker,value) in &[("am",0), ("pm",12), ("a.m.",0), ("p.m.",12)] {
+        if text.len() > marker.len() && text.ends_with(marker) {
+            text = &text[..text.len() - marker.len()];
+            am_pm = Some(*value);
+            break;
+        }
+    }
+    if !text.starts_with(|c: char| c.is_ascii_digit()) {
+        return Ok(None);
+    }
+    if am_pm.is_none() && index + 1 < words.len() {
+        am_pm = match &words[index + 1].text[..] {
+            "am" | "a.m." => Some(0),
+            "pm" | "p.m." => Some(12),
+            _ => None,
+        };
+        if am_pm.is_some() {
+            consumed = 2;
+        }
+    }
+    let parts: Vec<&str> = text.split(':').collect();
+    if parts.len() == 1 && am_pm.is_none() && !is_hour_allowed {
+        return Ok(None);
+    }
+    if parts.len() > 3 || parts[0].is_empty() || parts[0].len() > 2 || parts[1..].iter().any(|part| part.len() != 2) {
+        return Err(word.error());
+    }
+    let mut values = [0u32; 3];
+    for (part_index,part) in parts.iter().enumerate() {
+        values[part_index] = match part.parse::<u32>() {
+            Ok(value) => value,
+            Err(_) => return Err(word.error()),
+        };
+    }
+    let mut hour = values[0];
+    if let Some(am_pm) = am_pm {
+        if hour == 0 || hour > 12 {
+            return Err(ParseError::invalid(ParseErrorKind::InvalidHour,word.position,word.length));
+        }
+        hour = hour % 12 + am_pm;
+    }
+    return match NaiveTime::from_hms_opt(hour,values[1],values[2]) {
+        Some(time) => Ok(Some((time, consumed))),
+        None => Err(ParseError::invalid(ParseErrorKind::InvalidTime,word.position,word.length)),
+    };
+}
+/// Parses ISO date such as `2011-12-11`.
+fn parse_iso_date(word: &Word) -> ParseResult<Option<NaiveDate>> {
+    let parts: Vec<&str> = word.text.split('-').collect();
+    if parts.len() != 3 || parts[0].len() != 4 || parts[1].len() != 2 || parts[2].len() != 2 {
+        return Ok(None);
+    }
+    let year = parts[0].parse::<i32>();
+    let month = parts[1].parse::<u32>();
+    let day = parts[2].parse::<u32>();
+    if let (Ok(year), Ok(month), Ok(day)) = (year, month, day) {
+        if let Some(date) = NaiveDate::from_ymd_opt(year,month,day) {
+            return Ok(Some(date));
+        }
+        return Err(ParseError::invalid(ParseErrorKind::InvalidDate,word.position,word.length));
+    }
+    return Ok(None);
+}
+fn parse_day_of_month(word: &Word) -> Option<u32> {
+    let text = word.text.trim_end_matches(|c: char| c.is_alphabetic() || c == '.');
+    let suffix = &word.text[text.len()..];
+    if text.is_empty() || text.len() > 2 || !(suffix.is_empty() || suffix == "." || EN.day_suffixes.contains(&suffix)) {
+        return None;
+    }
+    return text.parse::<u32>().ok().filter(|day| *day >= 1 && *day <= 31);
+}
+fn parse_year(words: &[Word],index: usize) -> Option<i32> {
+    return words.get(index).filter(|word| word.text.len() == 4).and_then(|word| word.text.parse::<i32>().ok());
+}
+/// Parses a natural language expression such as `yesterday 5pm`, `in 3 days`, `next Friday at noon`
+/// or `Dec 11 17:30` relative to `reference`.
+///
+/// Supported words (case-insensitive, English):
+///
+/// * `now`, `today`, `tomorrow`, `yesterday`;
+/// * `in N units`, `N units ago`, `N units later`, where `N` is a number, `a` or `an`
+///   and units are seconds, minutes, hours, days, weeks, months and years;
+/// * weekday names with optional `next`, `last` or `this`: `next Friday` is the first Friday after
+///   the reference date, `last Friday` is the last Friday before it, `Friday` and `this Friday`
+///   is the first Friday on or after it;
+/// * `next` or `last` with unit, such as `next week`;
+/// * `noon`, `midnight` and times such as `5pm`, `5:30 pm`, `17:30`, `at 5`;
+/// * absolute dates such as `2011-12-11`, `Dec 11`, `11 December 2011`.
+///
+/// Expressions which set a date without time (`tomorrow`, `Friday`, `Dec 11`) return midnight,
+/// offsets (`in 3 days`) keep time of reference. Result has offset of `reference`.
+///
+/// Returns `InvalidToken` error pointing at the unrecognized word.
+pub fn parse_natural(text: &str,reference: &DateTime<FixedOffset>) -> ParseResult<DateTime<FixedOffset>> {
+    let words = split_words(text);
+    if words.is_empty() {
+        return Err(ParseError::invalid_token(0,text.chars().count()));
+    }
+    let local = reference.naive_local();
+    let mut date = local.date();
+    let mut is_date_set = false;
+    let mut time: Option<NaiveTime> = None;
+    let mut months = 0i64;
+    let mut duration = Duration::zero();
+    let mut index = 0;
+    while index < words.len() {
+        let word = &words[index];
+        match &word.text[..] {
+            "now" | "at" | "on" | "the" | "of" => {
+                index = index + 1;
+                continue;
+            },
+            "today" | "tomorrow" | "yesterday" => {
+                date = local.date() + Duration::days(match &word.text[..] { "tomorrow" => 1, "yesterday" => -1, _ => 0 });
+                is_date_set = true;
+                index = index + 1;
+                continue;
+            },
+            "noon" | "midnight" => {
+                time = NaiveTime::from_hms_opt(if word.text == "noon" { 12 } else { 0 },0,0);
+                index = index + 1;
+                continue;
+            },
+            "in" => {
+                let count_word = match words.get(index + 1) {
+                    Some(count_word) => count_word,
+                    None => return Err(word.error()),
+                };
+                let count = match parse_count(count_word) {
+                    Some(count) => count,
+                    None => return Err(count_word.error()),
+                };
+                let unit_word = match words.get(index + 2) {
+                    Some(unit_word) => unit_word,
+                    None => return Err(count_word.error()),
+                };
+                let (unit_months,unit_duration) = unit_duration(unit_word,count)?;
+                months = months + unit_months;
+                duration = duration + unit_duration;
+                index = index + 3;
+                continue;
+            },
+            "next" | "last" | "this" => {
+                let target = match words.get(index + 1) {
+                    Some(target) => target,
+                    None => return Err(word.error()),
+                };
+                let sign = match &word.text[..] { "next" => 1, "last" => -1, _ => 0 };
+                if let Some(weekday) = find_weekday(target) {
+                    let today = local.date().weekday().num_days_from_monday() as i64;
+                    let days = match sign {
+                        1 => (weekday as i64 - today - 1).rem_euclid(7) + 1,
+                        -1 => -((today - weekday as i64 - 1).rem_euclid(7) + 1),
+                        _ => (weekday as i64 - today).rem_euclid(7),
+                    };
+                    date = local.date() + Duration::days(days);
+                    is_date_set = true;
+                } else if sign != 0 {
+                    let (unit_months,unit_duration) = unit_duration(target,sign)?;
+                    months = months + unit_months;
+                    duration = duration + unit_duration;
+                } else {
+                    return Err(target.error());
+                }
+                index = index + 2;
+                continue;
+            },
+            _ => {},
+        }
+        if let Some(weekday) = find_weekday(word) {
+            let today = local.date().weekday().num_days_from_monday() as i64;
+            date = local.date() + Duration::days((weekday as i64 - today).rem_euclid(7));
+            is_date_set = true;
+            index = index + 1;
+            continue;
+        }
+        if let Some(iso_date) = parse_iso_date(word)? {
+            date = iso_date;
+            is_date_set = true;
+            index = index + 1;
+            continue;
+        }
+        if let Some(month) = find_month(word) {
+            let day = match words.get(index + 1).and_then(parse_day_of_month) {
+                Some(day) => day,
+                None => return Err(words.get(index + 1).unwrap_or(word).error()),
+            };
+            let year = parse_year(&words,index + 2);
+            date = match NaiveDate::from_ymd_opt(year.unwrap_or(local.year()),month,day) {
+                Some(date) => date,
+                None => return Err(ParseError::invalid(ParseErrorKind::InvalidDate,word.position,word.length)),
+            };
+            is_date_set = true;
+            index = index + if year.is_some() { 3 } else { 2 };
+            continue;
+        }
+        if let Some(day) = parse_day_of_month(word) {
+            if let Some(month) = words.get(index + 1).and_then(find_month) {
+                let year = parse_year(&words,index + 2);
+                date = match NaiveDate::from_ymd_opt(year.unwrap_or(local.year()),month,day) {
+                    Some(date) => date,
+                    None => return Err(ParseError::invalid(ParseErrorKind::InvalidDate,word.position,word.length)),
+                };
+                is_date_set = true;
+                index = index + if year.is_some() { 3 } else { 2 };
+                continue;
+            }
+        }
+        if let Some(count) = parse_count(word) {
+            if let Some(unit_word) = words.get(index + 1) {
+                if find_name(unit_word,&UNITS).is_some() {
+                    let sign = match words.get(index + 2).map(|word| &word.text[..]) {
+                        Some("ago") => -1,
+                        Some("later") => 1,
+                        Some(_) => return Err(words[index + 2].error()),
+                        None => return Err(unit_word.error()),
+                    };
+                    let (unit_months,unit_duration) = unit_duration(unit_word,count * sign)?;
+                    months = months + unit_months;
+                    duration = duration + unit_duration;
+                    index = index + 3;
+                    continue;
+                }
+            }
+        }
+        let is_hour_allowed = index > 0 && words[index - 1].text == "at";
+        if let Some((clock,consumed)) = parse_clock(&words,index,is_hour_allowed)? {
+            time = Some(clock);
+            index = index + consumed;
+            continue;
+        }
+        return Err(word.error());
+    }
+    let default_time = if is_date_set { NaiveTime::from_hms_opt(0, 0, 0).unwrap() } else { local.time() };
+    let date = if months.abs() <= i32::MAX as i64 { date.add_months(months as i32,MonthEndPolicy::Clamp) } else { None };
+    let date = match date {
+        Some(date) => date,
+        None => return Err(ParseError::invalid_high_value(0,text.chars().count())),
+    };
+    let result = date.and_time(time.unwrap_or(default_time))
+        .checked_add_signed(duration)
+        .and_then(|result| result.checked_sub_signed(Duration::seconds(reference.offset().local_minus_utc() as i64)));
+    return match result {
+        Some(utc) => Ok(DateTime::from_utc(utc,*reference.offset())),
+        None => Err(ParseError::invalid_high_value(0,text.chars().count())),
+    };
+}
+#[cfg(test)]
+#[test]
+fn test_natural() {
+    use parser::parse_w3c_datetime;
+    use formatter::format_w3c;
+    let reference = parse_w3c_datetime("2011-12-11T10:43:00+03:00").unwrap();
+    let testdates = [
+        ("now", Ok("2011-12-11T10:43:00+03:00")),
+        ("yesterday 5pm", Ok("2011-12-10T17:00:00+03:00")),
+        ("Tomorrow at noon", Ok("2011-12-12T12:00:00+03:00")),
+        ("today", Ok("2011-12-11T00:00:00+03:00")),
+        ("midnight", Ok("2011-12-11T00:00:00+03:00")),
+        ("in 3 days", Ok("2011-12-14T10:43:00+03:00")),
+        ("in an hour", Ok("2011-12-11T11:43:00+03:00")),
+        ("2 weeks ago", Ok("2011-11-27T10:43:00+03:00")),
+        ("in 3 months", Ok("2012-03-11T10:43:00+03:00")),
+        ("next Friday", Ok("2011-12-16T00:00:00+03:00")),
+        ("last sunday at 5:30 pm", Ok("2011-12-04T17:30:00+03:00")),
+        ("sunday", Ok("2011-12-11T00:00:00+03:00")),
+        ("next sunday 9am", Ok("2011-12-18T09:00:00+03:00")),
+        ("next week", Ok("2011-12-18T10:43:00+03:00")),
+        ("Dec 31 23:59", Ok("2011-12-31T23:59:00+03:00")),
+        ("1st March 2012 at 8", Ok("2012-03-01T08:00:00+03:00")),
+        ("2012-02-29 noon", Ok("2012-02-29T12:00:00+03:00")),
+        ("tomorrow 13pm", Err(ParseErrorKind::InvalidHour)),
+        ("Feb 30", Err(ParseErrorKind::InvalidDate)),
+        ("in 3 fortnights", Err(ParseErrorKind::InvalidToken)),
+        ("", Err(ParseErrorKind::InvalidToken)),
+    ];
+    for &(date, checkdate) in testdates.iter() {
+        let dt = match parse_natural(date,&reference) {
+            Ok(dt) => Ok(format_w3c(&dt)),
+            Err(e) => Err(e.error_kind),
+        };
+        if dt != checkdate.map(|s| s.to_string()) {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, dt, checkdate);
+        }
+    }
+    assert_eq!(parse_natural("yesterday at banana",&reference).unwrap_err(), ParseError::invalid_token(13,6));
+    assert_eq!(parse_natural("in 3 fortnights",&reference).unwrap_err(), ParseError::invalid_token(5,10));
+}
@@ -0,0 +1,137 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::Duration;
+use super::helper::*;
+use super::error::*;
+/// Encoding rules of ASN.1 time values.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Asn1Mode {
+    /// Distinguished Encoding Rules: seconds and `Z` are mandatory,
+    /// fraction of second has no trailing zeros.
+    Der,
+    /// Basic Encoding Rules: seconds may be omitted, offset `+hhmm` or `-hhmm` may be used instead of `Z`.
+    Ber,
+}
+/// Parses an ASN.1 UTCTime string such as `150120173520Z`.
+///
+/// Two-digit years are mapped by X.509 rules: `50` through `99` are 1950 through 1999,
+/// `00` through `49` are 2000 through 2049.
+///
+/// Valid formats for DER: `YYMMDDhhmmssZ`.
+/// Valid formats for BER: `YYMMDDhhmmZ`, `YYMMDDhhmmssZ`, `YYMMDDhhmm+hhmm`, `YYMMDDhhmmss+hhmm`.
+pub fn parse_utc_time(text: &str,mode: Asn1Mode) -> ParseResult<DateTime<FixedOffset>> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let year = parse_u32(str,&mut position,2,ParseErrorKind::InvalidYear)? as i32;
+    let year = if year >= 50 { 1900 + year } else { 2000 + year };
+    return parse_asn1_rest(str,&mut position,year,mode,false);
+}
+/// Parses an ASN.1 GeneralizedTime string such as `20150120173520.25Z`.
+///
+/// Valid formats for DER: `YYYYMMDDhhmmssZ`, `YYYYMMDDhhmmss.sZ`.
+/// Valid formats for BER: DER formats, seconds may be omitted, fraction of second may have
+/// trailing zeros and may be separated by comma, offset `+hhmm` or `-hhmm` may be used instead of `Z`.
+pub fn parse_generalized_time(text: &str,mode: Asn1Mode) -> ParseResult<DateTime<FixedOffset>> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let year = parse_full_year(str,&mut position)?;
+    return parse_asn1_rest(str,&mut position,year,mode,true);
+}
+fn parse_asn1_rest(str: &Vec<char>,position: &mut usize,year: i32,mode: Asn1Mode,has_fraction: bool) -> ParseResult<DateTime<FixedOffset>> {
+    let month = parse_month_number(str,position)?;
+    let day = parse_day_number(str,position)?;
+    let hour = parse_hour_24(str,position)?;
+    let minute = parse_minute(str,position)?;
+    let mut seconds = 0;
+    let mut nanosecond = 0;
+    let is_seconds_omitted = mode == Asn1Mode::Ber && *position < str.len() && !str[*position].is_ascii_digit();
+    if !is_seconds_omitted {
+        seconds = parse_seconds(str,position)?;
+        if has_fraction {
+            let mut is_fraction = parse_is_token_or_end(str,position,".")?;
+            if !is_fraction && mode == Asn1Mode::Ber {
+                is_fraction = parse_is_token_or_end(str,position,",")?;
+            }
+            if is_fraction {
+                let fraction_position = *position;
+                nanosecond = parse_nanosecond(str,position)?;
+                if mode == Asn1Mode::Der && str[*position - 1] == '0' {
+                    return Err(ParseError::invalid(ParseErrorKind::InvalidNanoseconds,fraction_position,*position - fraction_position));
+                }
+            }
+        }
+    }
+    let offset;
+    if parse_is_token(str,position,"Z")? {
+        offset = FixedOffset::east_opt(0).unwrap();
+    } else if mode == Asn1Mode::Ber {
+        offset = parse_tzd_compact(str,position)?;
+    } else {
+        return Err(ParseError::invalid_token(*position,1));
+    }
+    let _ = parse_end_of_string(str,position)?;
+    if let Some(date) = NaiveDate::from_ymd_opt(year,month,day) {
+        if let Some(time) = NaiveTime::from_hms_nano_opt(hour, minute, seconds, nanosecond) {
+            let naive_date_time = date.and_time(time);
+            if let Some(naive_date_time) = naive_date_time.checked_sub_signed(Duration::seconds(offset.local_minus_utc() as i64)) {
+                return Ok(DateTime::from_utc(naive_date_time, offset));
+            }
+        }
+    }
+    return Err(ParseError::invalid_format(0,str.len()));
+}
+#[cfg(test)]
+#[test]
+fn test_asn1_time() {
+    use formatter::{format_utc_time,format_generalized_time};
+    let testdates = [
+        ("150120173520Z", Asn1Mode::Der, Ok("150120173520Z")),
+        ("500120173520Z", Asn1Mode::Der, Ok("500120173520Z")),
+        ("491231235959Z", Asn1Mode::Der, Ok("491231235959Z")),
+        ("1501201735Z", Asn1Mode::Der, Err(ParseErrorKind::InvalidSeconds)),
+        ("1501201735Z", Asn1Mode::Ber, Ok("150120173500Z")),
+        ("150120173520+0300", Asn1Mode::Der, Err(ParseErrorKind::InvalidToken)),
+        ("150120173520+0300", Asn1Mode::Ber, Ok("150120143520Z")),
+        ("1501201735-0800", Asn1Mode::Ber, Ok("150121013500Z")),
+        ("150120173520", Asn1Mode::Der, Err(ParseErrorKind::InvalidToken)),
+        ("150230173520Z", Asn1Mode::Der, Err(ParseErrorKind::InvalidFormat)),
+        ("150120173520Zs", Asn1Mode::Der, Err(ParseErrorKind::StringNotEnded)),
+    ];
+    for &(date, mode, checkdate) in testdates.iter() {
+        let dt = match parse_utc_time(date,mode) {
+            Ok(dt) => Ok(format_utc_time(&dt).unwrap()),
+            Err(e) => Err(e.error_kind),
+        };
+        if dt != checkdate.map(|s| s.to_string()) {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, dt, checkdate);
+        }
+    }
+    let testdates = [
+        ("20150120173520Z", Asn1Mode::Der, Ok("20150120173520Z")),
+        ("20150120173520.25Z", Asn1Mode::Der, Ok("20150120173520.25Z")),
+        ("20150120173520.250Z", Asn1Mode::Der, Err(ParseErrorKind::InvalidNanoseconds)),
+        ("20150120173520.250Z", Asn1Mode::Ber, Ok("20150120173520.25Z")),
+        ("20150120173520,5Z", Asn1Mode::Der, Err(ParseErrorKind::InvalidToken)),
+        ("20150120173520,5Z", Asn1Mode::Ber, Ok("20150120173520.5Z")),
+        ("20150120173520.000000001Z", Asn1Mode::Der, Ok("20150120173520.000000001Z")),
+        ("201501201735Z", Asn1Mode::Ber, Ok("20150120173500Z")),
+        ("20150120173520.25-0800", Asn1Mode::Ber, Ok("20150121013520.25Z")),
+        ("20150120173520.Z", Asn1Mode::Der, Err(ParseErrorKind::InvalidNanoseconds)),
+        ("19500120173520Z", Asn1Mode::Der, Ok("19500120173520Z")),
+    ];
+    for &(date, mode, checkdate) in testdates.iter() {
+        let dt = match parse_generalized_time(date,mode) {
+            Ok(dt) => Ok(format_generalized_time(&dt)),
+            Err(e) => Err(e.error_kind),
+        };
+        if dt != checkdate.map(|s| s.to_string()) {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, dt, checkdate);
+        }
+    }
+}
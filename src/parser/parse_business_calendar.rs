@@ -0,0 +1,211 @@
+use chrono::NaiveDate;
+use chrono::Weekday;
+use arithmetic::{BusinessCalendar,Holiday,HolidayRule,Observance};
+use super::error::*;
+const WEEKDAYS: [(&str, Weekday); 7] = [
+    ("mon", Weekday::Mon), ("tue", Weekday::Tue), ("wed", Weekday::Wed), ("thu", Weekday::Thu),
+    ("fri", Weekday::Fri), ("sat", Weekday::Sat), ("sun", Weekday::Sun),
+];
+const OBSERVANCES: [(&str, Observance); 4] = [
+    ("actual", Observance::Actual),
+    ("nearest-weekday", Observance::NearestWeekday),
+    ("next-monday", Observance::NextMonday),
+    ("sunday-to-monday", Observance::SundayToMonday),
+];
+/// Word of a line with its position in chars.
+struct Token {
+    text: String,
+    position: usize,
+}
+impl Token {
+    fn length(&self) -> usize {
+        return self.text.chars().count();
+    }
+    fn error(&self) -> ParseError {
+        return ParseError::invalid_token(self.position,self.length());
+    }
+}
+fn tokenize(str: &[char],begin: usize,end: usize) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut position = begin;
+    while position < end {
+        if str[position].is_whitespace() {
+            position += 1;
+            continue;
+        }
+        let token_begin = position;
+        while position < end && !str[position].is_whitespace() {
+            position += 1;
+        }
+        tokens.push(Token { text: str[token_begin..position].iter().collect(), position: token_begin });
+    }
+    return tokens;
+}
+fn parse_number(text: &str,position: usize,min: u32,max: u32) -> ParseResult<u32> {
+    let length = text.chars().count();
+    if text.is_empty() || length > 4 || !text.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ParseError::invalid_token(position,length));
+    }
+    let value: u32 = text.parse().unwrap();
+    if value < min {
+        return Err(ParseError::invalid_low_value(position,length));
+    }
+    if value > max {
+        return Err(ParseError::invalid_high_value(position,length));
+    }
+    return Ok(value);
+}
+fn parse_weekday(token: &Token) -> ParseResult<Weekday> {
+    let name = token.text.to_lowercase();
+    return match WEEKDAYS.iter().find(|&&(abbreviation,_)| name == abbreviation) {
+        Some(&(_,weekday)) => Ok(weekday),
+        None => Err(token.error()),
+    };
+}
+/// Parses `MM-DD`, `YYYY-MM-DD`, `easter+N`, `easter-N` or `N Weekday MM` and returns rule with count of tokens used.
+fn parse_rule(tokens: &[Token]) -> ParseResult<(HolidayRule, usize)> {
+    let token = &tokens[0];
+    let text = token.text.to_lowercase();
+    if let Some(offset) = text.strip_prefix("easter") {
+        let days = match offset.chars().next() {
+            None => 0,
+            Some(sign) if sign == '+' || sign == '-' => {
+                let days = parse_number(&offset[1..],token.position + 7,0,366)? as i32;
+                if sign == '-' { -days } else { days }
+            },
+            Some(_) => return Err(token.error()),
+        };
+        return Ok((HolidayRule::Easter(days), 1));
+    }
+    let parts: Vec<&str> = text.split('-').collect();
+    if parts.len() == 2 || parts.len() == 3 {
+        let mut position = token.position;
+        let year = if parts.len() == 3 {
+            position += parts[0].len() + 1;
+            Some(parse_number(parts[0],token.position,1,9999)? as i32)
+        } else {
+            None
+        };
+        let month = parse_number(parts[parts.len() - 2],position,1,12)?;
+        let day_position = position + parts[parts.len() - 2].len() + 1;
+        let day = parse_number(parts[parts.len() - 1],day_position,1,31)?;
+        let date = NaiveDate::from_ymd_opt(year.unwrap_or(2000),month,day);
+        return match (year, date) {
+            (_, None) => Err(ParseError::invalid_high_value(day_position,parts[parts.len() - 1].len())),
+            (Some(_), Some(date)) => Ok((HolidayRule::Date(date), 1)),
+            (None, Some(_)) => Ok((HolidayRule::Fixed(month,day), 1)),
+        };
+    }
+    if tokens.len() < 3 {
+        return Err(token.error());
+    }
+    let n = if text == "last" { -1 } else { parse_number(&text,token.position,1,5)? as i32 };
+    let weekday = parse_weekday(&tokens[1])?;
+    let month = parse_number(&tokens[2].text,tokens[2].position,1,12)?;
+    return Ok((HolidayRule::NthWeekday(month,weekday,n), 3));
+}
+/// Parses business calendar in the line-based text format:
+///
+/// ```text
+/// # Comment
+/// calendar = US federal
+/// weekend = Sat Sun
+/// 01-01 observed nearest-weekday = New Year's Day
+/// 3 Mon 01 from 1986 = Birthday of Martin Luther King, Jr.
+/// last Mon 05 = Memorial Day
+/// easter-2 from 2000 until 2030 = Good Friday
+/// 2025-01-09 = National Day of Mourning
+/// ```
+///
+/// Holiday rules are `MM-DD`, `N Weekday MM` (N is 1 through 5 or `last`), `easter+N`, `easter-N`
+/// or a single date `YYYY-MM-DD`, optionally followed by `observed` with `actual`, `nearest-weekday`,
+/// `next-monday` or `sunday-to-monday`, and by `from YYYY` and `until YYYY` years.
+pub fn parse_business_calendar(text: &str) -> ParseResult<BusinessCalendar> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut calendar = BusinessCalendar::new("");
+    let mut line_begin = 0;
+    while line_begin < str.len() {
+        let line_end = (line_begin..str.len()).find(|&position| str[position] == '\n').unwrap_or(str.len());
+        let begin = line_begin;
+        line_begin = line_end + 1;
+        let tokens = tokenize(str,begin,line_end);
+        if tokens.is_empty() || tokens[0].text.starts_with('#') {
+            continue;
+        }
+        let equals = match (begin..line_end).find(|&position| str[position] == '=') {
+            Some(equals) => equals,
+            None => return Err(ParseError::invalid_format(begin,line_end - begin)),
+        };
+        let value: String = str[equals + 1..line_end].iter().collect::<String>().trim().to_string();
+        let tokens: Vec<Token> = tokens.into_iter().filter(|token| token.position < equals).collect();
+        if tokens.is_empty() {
+            return Err(ParseError::invalid_format(begin,line_end - begin));
+        }
+        match tokens[0].text.as_str() {
+            "calendar" if tokens.len() == 1 => calendar.name = value,
+            "weekend" if tokens.len() == 1 => {
+                calendar.weekend = 0;
+                for token in tokenize(str,equals + 1,line_end) {
+                    calendar.weekend |= 1 << parse_weekday(&token)?.num_days_from_monday();
+                }
+            },
+            _ => {
+                if value.is_empty() {
+                    return Err(ParseError::invalid_format(begin,line_end - begin));
+                }
+                let (rule, mut index) = parse_rule(&tokens)?;
+                let mut holiday = Holiday::new(&value,rule);
+                while index < tokens.len() {
+                    let argument = match tokens.get(index + 1) {
+                        Some(argument) => argument,
+                        None => return Err(tokens[index].error()),
+                    };
+                    match tokens[index].text.as_str() {
+                        "observed" => holiday.observance = match OBSERVANCES.iter().find(|&&(name,_)| name == argument.text) {
+                            Some(&(_,observance)) => observance,
+                            None => return Err(argument.error()),
+                        },
+                        "from" => holiday.first_year = Some(parse_number(&argument.text,argument.position,1,9999)? as i32),
+                        "until" => holiday.last_year = Some(parse_number(&argument.text,argument.position,1,9999)? as i32),
+                        _ => return Err(tokens[index].error()),
+                    }
+                    index += 2;
+                }
+                calendar.holidays.push(holiday);
+            },
+        }
+    }
+    return Ok(calendar);
+}
+#[cfg(test)]
+#[test]
+fn test_parse_business_calendar() {
+    let calendar = parse_business_calendar("# Example\n\ncalendar = Example\nweekend = Fri Sat\n2 Sun 05 = Mother's Day\n02-29 observed next-monday from 2000 until 2099 = Leap Day\neaster+50 = Whit Tuesday\n2025-01-09 = Day of Mourning\n").unwrap();
+    assert_eq!(calendar.name, "Example");
+    assert_eq!(calendar.weekend, 1 << 4 | 1 << 5);
+    assert_eq!(calendar.holidays.len(), 4);
+    assert_eq!(calendar.holidays[0].rule, HolidayRule::NthWeekday(5,Weekday::Sun,2));
+    assert_eq!(calendar.holidays[1].observance, Observance::NextMonday);
+    assert_eq!((calendar.holidays[1].first_year, calendar.holidays[1].last_year), (Some(2000), Some(2099)));
+    assert_eq!(calendar.holidays[2].rule, HolidayRule::Easter(50));
+    assert_eq!(calendar.holidays[3].rule, HolidayRule::Date(NaiveDate::from_ymd(2025,1,9)));
+    let testerrors = [
+        ("13-01 = X", ParseError::invalid_high_value(0,2)),
+        ("02-30 = X", ParseError::invalid_high_value(3,2)),
+        ("3 Mon 01 observed sometimes = X", ParseError::invalid_token(18,9)),
+        ("3 Mon 01 from = X", ParseError::invalid_token(9,4)),
+        ("3 Mon 01", ParseError::invalid_format(0,8)),
+        ("3 Mon 01 =", ParseError::invalid_format(0,10)),
+        ("weekend = Sat Sunday", ParseError::invalid_token(14,6)),
+        ("calendar = A\n6 Mon 01 = X", ParseError::invalid_high_value(13,1)),
+        ("easter*2 = X", ParseError::invalid_token(0,8)),
+    ];
+    for &(text, ref checkerror) in testerrors.iter() {
+        let result = parse_business_calendar(text);
+        if result.as_ref().err() != Some(checkerror) {
+            panic!("Business calendar parsing failed for {:?}\nReceived: {:?}\nExpected: {:?}",
+                   text, result, checkerror);
+        }
+    }
+}
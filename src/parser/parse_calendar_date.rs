@@ -0,0 +1,150 @@
+use calendars::CalendarDate;
+use super::helper::*;
+use super::error::*;
+/// Parses a date of a non-Gregorian calendar such as `Yom Sheni, 1 Cheshvan 5784 AM`, `1 Rabi' al-Thani 1445`
+/// or `1402-07-24`.
+///
+/// Valid formats: `YYYY-MM-DD` with month number of the calendar, or optional weekday name with comma,
+/// day number, month name, year and optional era abbreviation such as `AH`.
+/// Names are matched case-insensitively. Weekday name must match the date.
+pub fn parse_calendar_date<D: CalendarDate>(text: &str) -> ParseResult<D> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    if str.len() > 4 && str[4] == '-' && str[0].is_ascii_digit() {
+        let year = parse_full_year(str,&mut position)?;
+        let _ = parse_token(str,&mut position,"-")?;
+        let month_position = position;
+        let result = parse_u32(str,&mut position,2,ParseErrorKind::InvalidMonth);
+        let month = validate_range(result,1,D::months_in_year(year),&month_position,2)?;
+        let _ = parse_token(str,&mut position,"-")?;
+        let day = parse_day_number(str,&mut position)?;
+        let _ = parse_end_of_string(str,&position)?;
+        return match D::from_ymd_opt(year,month,day) {
+            Some(date) => Ok(date),
+            None => Err(ParseError::invalid(ParseErrorKind::InvalidDate,0,position)),
+        };
+    }
+    let mut weekday: Option<(usize, usize, usize)> = None;
+    if position < str.len() && str[position].is_alphabetic() {
+        let (index,length) = match match_name(str,position,&D::WEEKDAYS) {
+            Some(weekday) => weekday,
+            None => return Err(ParseError::invalid_token(position,word_length(str,position).max(1))),
+        };
+        weekday = Some((index, position, length));
+        position = position + length;
+        let _ = parse_token(str,&mut position,",")?;
+        skip_spaces(str,&mut position);
+    }
+    let day_position = position;
+    let result = parse_digits(str,&mut position,2,ParseErrorKind::InvalidDay);
+    let day = validate_range(result,1,31,&day_position,position - day_position)?;
+    if !parse_is_token(str,&mut position," ")? {
+        return Err(ParseError::invalid_token(position,1));
+    }
+    skip_spaces(str,&mut position);
+    let month_position = position;
+    while position < str.len() && !str[position].is_ascii_digit() {
+        position = position + 1;
+    }
+    let mut month_end = position;
+    while month_end > month_position && str[month_end - 1] == ' ' {
+        month_end = month_end - 1;
+    }
+    if month_end == month_position || month_end == position {
+        return Err(ParseError::invalid(ParseErrorKind::InvalidMonth,month_position,month_end - month_position));
+    }
+    let year_position = position;
+    let result = parse_digits(str,&mut position,4,ParseErrorKind::InvalidYear);
+    let year = validate_range(result,1,9999,&year_position,position - year_position)? as i32;
+    if position < str.len() && str[position].is_ascii_digit() {
+        return Err(ParseError::invalid(ParseErrorKind::InvalidYear,year_position,position - year_position + 1));
+    }
+    let month = match match_name(str,month_position,D::month_names(year)) {
+        Some((index,length)) if month_position + length == month_end => index as u32 + 1,
+        _ => return Err(ParseError::invalid(ParseErrorKind::InvalidMonth,month_position,month_end - month_position)),
+    };
+    if parse_is_token_or_end(str,&mut position," ")? {
+        let _ = parse_token(str,&mut position,D::ERA)?;
+    }
+    let _ = parse_end_of_string(str,&position)?;
+    let date = match D::from_ymd_opt(year,month,day) {
+        Some(date) => date,
+        None => return Err(ParseError::invalid(ParseErrorKind::InvalidDate,day_position,position - day_position)),
+    };
+    if let Some((index,weekday_position,length)) = weekday {
+        if D::WEEKDAYS[index] != date.weekday_name() {
+            return Err(ParseError::invalid(ParseErrorKind::InvalidDate,weekday_position,length));
+        }
+    }
+    return Ok(date);
+}
+fn skip_spaces(str: &Vec<char>,position: &mut usize) {
+    while *position < str.len() && str[*position] == ' ' {
+        *position = *position + 1;
+    }
+}
+#[cfg(test)]
+#[test]
+fn test_calendar_date() {
+    use chrono::NaiveDate;
+    use calendars::{IslamicDate,UmmAlQuraDate,HebrewDate,PersianDate};
+    use formatter::{format_calendar_date,FormatStyle};
+    fn check<D: CalendarDate>(testdates: &[(&str, &str, Result<&str, ParseErrorKind>)]) {
+        for &(date, gregorian, checkdate) in testdates.iter() {
+            let result = match parse_calendar_date::<D>(date) {
+                Ok(parsed) => {
+                    assert_eq!(parsed.to_naive_date().map(|date| date.to_string()), Some(gregorian.to_string()));
+                    let naive = NaiveDate::parse_from_str(gregorian,"%Y-%m-%d").unwrap();
+                    let converted = D::from_naive_date(&naive).unwrap();
+                    assert_eq!(format_calendar_date(&converted,FormatStyle::Short), format_calendar_date(&parsed,FormatStyle::Short));
+                    Ok(format_calendar_date(&parsed,FormatStyle::Full))
+                },
+                Err(e) => Err(e.error_kind),
+            };
+            if result != checkdate.map(|s| s.to_string()) {
+                panic!("Calendar date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                       date, result, checkdate);
+            }
+        }
+    }
+    check::<IslamicDate>(&[
+        ("1 Rabi' al-Thani 1445", "2023-10-16", Ok("al-Ithnayn, 1 Rabi' al-Thani 1445 AH")),
+        ("al-ithnayn, 1 rabi' al-thani 1445 AH", "2023-10-16", Ok("al-Ithnayn, 1 Rabi' al-Thani 1445 AH")),
+        ("1441-05-08", "2020-01-04", Ok("as-Sabt, 8 Jumada al-Ula 1441 AH")),
+        ("30 Dhu al-Hijjah 1442", "2021-08-09", Ok("al-Ithnayn, 30 Dhu al-Hijjah 1442 AH")),
+        ("30 Dhu al-Hijjah 1443", "", Err(ParseErrorKind::InvalidDate)),
+        ("al-Ahad, 1 Rabi' al-Thani 1445", "", Err(ParseErrorKind::InvalidDate)),
+        ("1 Rabi 1445", "", Err(ParseErrorKind::InvalidMonth)),
+        ("1 Ramadan 1445 AM", "", Err(ParseErrorKind::InvalidToken)),
+        ("1445-13-01", "", Err(ParseErrorKind::InvalidHighValue)),
+    ]);
+    check::<UmmAlQuraDate>(&[
+        ("1441-05-09", "2020-01-04", Ok("as-Sabt, 9 Jumada al-Ula 1441 AH")),
+        ("1 Ramadan 1444 AH", "2023-03-23", Ok("al-Khamis, 1 Ramadan 1444 AH")),
+        ("1 Muharram 1300", "1882-11-12", Ok("al-Ahad, 1 Muharram 1300 AH")),
+        ("30 Dhu al-Hijjah 1600", "2174-11-25", Ok("al-Jumu'a, 30 Dhu al-Hijjah 1600 AH")),
+        ("29 Dhu al-Hijjah 1299", "", Err(ParseErrorKind::InvalidDate)),
+    ]);
+    check::<HebrewDate>(&[
+        ("Yom Sheni, 1 Cheshvan 5784 AM", "2023-10-16", Ok("Yom Sheni, 1 Cheshvan 5784 AM")),
+        ("14 Adar II 5784", "2024-03-24", Ok("Yom Rishon, 14 Adar II 5784 AM")),
+        ("5784-07-14", "2024-03-24", Ok("Yom Rishon, 14 Adar II 5784 AM")),
+        ("20 Adar 5785", "2025-03-20", Ok("Yom Chamishi, 20 Adar 5785 AM")),
+        ("1 Nisan 5783", "2023-03-23", Ok("Yom Chamishi, 1 Nisan 5783 AM")),
+        ("14 Adar 5784", "", Err(ParseErrorKind::InvalidMonth)),
+        ("14 Adar I 5785", "", Err(ParseErrorKind::InvalidMonth)),
+        ("5785-13-01", "", Err(ParseErrorKind::InvalidHighValue)),
+    ]);
+    check::<PersianDate>(&[
+        ("24 Mehr 1402", "2023-10-16", Ok("Doshanbeh, 24 Mehr 1402 AP")),
+        ("1403-01-01", "2024-03-20", Ok("Chaharshanbeh, 1 Farvardin 1403 AP")),
+        ("30 Esfand 1403 AP", "2025-03-20", Ok("Panjshanbeh, 30 Esfand 1403 AP")),
+        ("30 Esfand 1402", "", Err(ParseErrorKind::InvalidDate)),
+        ("24 Mehr, 1402", "", Err(ParseErrorKind::InvalidMonth)),
+    ]);
+    let date = HebrewDate::from_ymd_opt(5784,7,14).unwrap();
+    assert_eq!(format_calendar_date(&date,FormatStyle::Long), "14 Adar II 5784 AM");
+    assert_eq!(format_calendar_date(&date,FormatStyle::Medium), "14 Adar II 5784");
+    assert_eq!(format_calendar_date(&date,FormatStyle::Short), "5784-07-14");
+}
@@ -0,0 +1,337 @@
+use cron::{CalendarEvent,CalendarComponent};
+use super::error::*;
+const WEEKDAY_NAMES: [&str; 7] = ["monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday"];
+const KEYWORDS: [(&str, &str); 10] = [
+    ("minutely", "*-*-* *:*:00"),
+    ("hourly", "*-*-* *:00:00"),
+    ("daily", "*-*-* 00:00:00"),
+    ("weekly", "Mon *-*-* 00:00:00"),
+    ("monthly", "*-*-01 00:00:00"),
+    ("quarterly", "*-01,04,07,10-01 00:00:00"),
+    ("semiannually", "*-01,07-01 00:00:00"),
+    ("semi-annually", "*-01,07-01 00:00:00"),
+    ("yearly", "*-01-01 00:00:00"),
+    ("annually", "*-01-01 00:00:00"),
+];
+const ZERO: CalendarComponent = CalendarComponent { start: 0, stop: None, repeat: 0 };
+/// Returns ranges of the parts of text separated by the separator.
+fn split_ranges(str: &[char],begin: usize,end: usize,separator: char) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut part_begin = begin;
+    for position in (begin..end).filter(|&position| str[position] == separator).chain(Some(end)) {
+        ranges.push((part_begin, position));
+        part_begin = position + 1;
+    }
+    return ranges;
+}
+fn find_token(str: &[char],begin: usize,end: usize,token: &str) -> Option<usize> {
+    let token: Vec<char> = token.chars().collect();
+    return (begin..end).find(|&position| position + token.len() <= end && str[position..position + token.len()] == token[..]);
+}
+fn parse_value(str: &[char],begin: usize,end: usize,min: u32,max: u32,is_year: bool) -> ParseResult<u32> {
+    if begin == end {
+        return Err(ParseError::invalid_format(begin,0));
+    }
+    if end - begin > 4 || !str[begin..end].iter().all(|c| c.is_ascii_digit()) {
+        return Err(ParseError::invalid_token(begin,end - begin));
+    }
+    let mut value: u32 = str[begin..end].iter().collect::<String>().parse().unwrap();
+    if is_year && end - begin <= 2 {
+        value += if value < 70 { 2000 } else { 1900 };
+    }
+    if value < min {
+        return Err(ParseError::invalid_low_value(begin,end - begin));
+    }
+    if value > max {
+        return Err(ParseError::invalid_high_value(begin,end - begin));
+    }
+    return Ok(value);
+}
+/// Parses `*` or list of values, ranges `a..b` and repetitions `a/r`, `a..b/r`.
+fn parse_chain(str: &[char],begin: usize,end: usize,min: u32,max: u32,is_year: bool) -> ParseResult<Vec<CalendarComponent>> {
+    if end == begin + 1 && str[begin] == '*' {
+        return Ok(Vec::new());
+    }
+    let mut chain = Vec::new();
+    for (item_begin,item_end) in split_ranges(str,begin,end,',') {
+        let slash = (item_begin..item_end).find(|&position| str[position] == '/');
+        let base_end = slash.unwrap_or(item_end);
+        let range = find_token(str,item_begin,base_end,"..");
+        let start = if base_end == item_begin + 1 && str[item_begin] == '*' && slash.is_some() {
+            min
+        } else {
+            parse_value(str,item_begin,range.unwrap_or(base_end),min,max,is_year)?
+        };
+        let stop = match range {
+            Some(range) => Some(parse_value(str,range + 2,base_end,min,max,is_year)?),
+            None => None,
+        };
+        if stop.map_or(false,|stop| stop < start) {
+            return Err(ParseError::invalid_format(item_begin,base_end - item_begin));
+        }
+        let repeat = match slash {
+            Some(slash) => parse_value(str,slash + 1,item_end,1,max,false)?,
+            None => 0,
+        };
+        chain.push(CalendarComponent {
+            start: start,
+            stop: stop,
+            repeat: repeat,
+        });
+    }
+    chain.sort();
+    chain.dedup();
+    return Ok(chain);
+}
+fn parse_weekday(str: &[char],begin: usize,end: usize) -> ParseResult<u32> {
+    let name = str[begin..end].iter().collect::<String>().to_lowercase();
+    return match WEEKDAY_NAMES.iter().position(|weekday| *weekday == name || (name.len() == 3 && weekday.starts_with(&name))) {
+        Some(index) => Ok(index as u32),
+        None => Err(ParseError::invalid_token(begin,end - begin)),
+    };
+}
+/// Parses weekday list such as `Mon..Fri,Sun` and returns bits of the number of days from Monday.
+fn parse_weekdays(str: &[char],begin: usize,end: usize) -> ParseResult<u8> {
+    let mut bits = 0;
+    for (item_begin,item_end) in split_ranges(str,begin,end,',') {
+        if item_begin == item_end && item_end == end && item_begin > begin {
+            continue;
+        }
+        let (first, last) = match find_token(str,item_begin,item_end,"..") {
+            Some(range) => (parse_weekday(str,item_begin,range)?, parse_weekday(str,range + 2,item_end)?),
+            None => {
+                let weekday = parse_weekday(str,item_begin,item_end)?;
+                (weekday, weekday)
+            },
+        };
+        if last < first {
+            return Err(ParseError::invalid_format(item_begin,item_end - item_begin));
+        }
+        for weekday in first..last + 1 {
+            bits |= 1 << weekday;
+        }
+    }
+    return Ok(if bits == 0x7f { 0 } else { bits });
+}
+fn parse_date(str: &[char],begin: usize,end: usize,event: &mut CalendarEvent) -> ParseResult<()> {
+    let separators: Vec<usize> = (begin..end).filter(|&position| str[position] == '-' || str[position] == '~').collect();
+    let (year_end, month_begin) = match separators.len() {
+        1 => (None, begin),
+        2 if str[separators[0]] == '-' => (Some(separators[0]), separators[0] + 1),
+        _ => return Err(ParseError::invalid_format(begin,end - begin)),
+    };
+    let day_separator = separators[separators.len() - 1];
+    if let Some(year_end) = year_end {
+        event.years = parse_chain(str,begin,year_end,1970,2199,true)?;
+    }
+    event.months = parse_chain(str,month_begin,day_separator,1,12,false)?;
+    event.days = parse_chain(str,day_separator + 1,end,1,31,false)?;
+    event.is_end_of_month = str[day_separator] == '~';
+    return Ok(());
+}
+fn parse_time(str: &[char],begin: usize,end: usize,event: &mut CalendarEvent) -> ParseResult<()> {
+    let parts = split_ranges(str,begin,end,':');
+    if parts.len() < 2 || parts.len() > 3 {
+        return Err(ParseError::invalid_format(begin,end - begin));
+    }
+    event.hours = parse_chain(str,parts[0].0,parts[0].1,0,23,false)?;
+    event.minutes = parse_chain(str,parts[1].0,parts[1].1,0,59,false)?;
+    event.seconds = if parts.len() == 3 { parse_chain(str,parts[2].0,parts[2].1,0,59,false)? } else { vec![ZERO] };
+    return Ok(());
+}
+/// Parses systemd calendar event expression (`OnCalendar=` of timer units) in the whole-second subset
+/// of the syntax described in `systemd.time(7)`: `[WEEKDAYS] [[YEAR-]MONTH-DAY] [HOUR:MINUTE[:SECOND]] [TIMEZONE]`, such as `Mon..Fri *-*-* 09:00:00 Europe/Berlin`.
+///
+/// Components are `*` or lists of values, ranges `a..b` and repetitions `a/r`, `a..b/r`. Two-digit years
+/// mean 1970 through 2069. Day written after `~` is counted from the end of the month. Omitted date means `*-*-*`
+/// and omitted time means `00:00:00`. Keywords `minutely`, `hourly`, `daily`, `weekly`, `monthly`, `quarterly`,
+/// `semiannually`, `yearly` and `annually` may be followed by time zone. Fractional seconds such as `*:*:0.5`, which
+/// `systemd-analyze calendar` accepts, are rejected.
+pub fn parse_calendar_event(text: &str) -> ParseResult<CalendarEvent> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut tokens = Vec::new();
+    let mut position = 0;
+    while position < str.len() {
+        if str[position].is_whitespace() {
+            position += 1;
+            continue;
+        }
+        let begin = position;
+        while position < str.len() && !str[position].is_whitespace() {
+            position += 1;
+        }
+        tokens.push((begin, position));
+    }
+    if tokens.is_empty() {
+        return Err(ParseError::invalid_format(0,str.len()));
+    }
+    let is_alphabetic = |(begin, _): (usize, usize)| str[begin].is_alphabetic();
+    let is_time = |(begin, end): (usize, usize)| str[begin..end].contains(&':');
+    let first = str[tokens[0].0..tokens[0].1].iter().collect::<String>().to_lowercase();
+    let mut event;
+    let mut index = 0;
+    if let Some(&(_,expression)) = KEYWORDS.iter().find(|&&(keyword,_)| keyword == first) {
+        event = parse_calendar_event(expression)?;
+        index += 1;
+    } else {
+        event = CalendarEvent {
+            weekdays: 0,
+            years: Vec::new(),
+            months: Vec::new(),
+            days: Vec::new(),
+            is_end_of_month: false,
+            hours: Vec::new(),
+            minutes: Vec::new(),
+            seconds: Vec::new(),
+            timezone: None,
+        };
+        if is_alphabetic(tokens[0]) {
+            event.weekdays = parse_weekdays(str,tokens[0].0,tokens[0].1)?;
+            index += 1;
+        }
+        let mut is_time_set = false;
+        if index < tokens.len() && !is_alphabetic(tokens[index]) && !is_time(tokens[index]) {
+            parse_date(str,tokens[index].0,tokens[index].1,&mut event)?;
+            index += 1;
+        }
+        if index < tokens.len() && is_time(tokens[index]) {
+            parse_time(str,tokens[index].0,tokens[index].1,&mut event)?;
+            is_time_set = true;
+            index += 1;
+        }
+        if index == 0 {
+            return Err(ParseError::invalid_token(tokens[0].0,tokens[0].1 - tokens[0].0));
+        }
+        if !is_time_set {
+            event.hours = vec![ZERO];
+            event.minutes = vec![ZERO];
+            event.seconds = vec![ZERO];
+        }
+    }
+    if index < tokens.len() {
+        let (begin, end) = tokens[index];
+        if !is_alphabetic(tokens[index]) || !str[begin..end].iter().all(|&c| c.is_ascii_alphanumeric() || "/_+-".contains(c)) {
+            return Err(ParseError::invalid_token(begin,end - begin));
+        }
+        event.timezone = Some(str[begin..end].iter().collect());
+        index += 1;
+    }
+    if index < tokens.len() {
+        return Err(ParseError::invalid_token(tokens[index].0,tokens[index].1 - tokens[index].0));
+    }
+    return Ok(event);
+}
+#[cfg(test)]
+#[test]
+fn test_calendar_event() {
+    use chrono::{TimeZone,Utc,FixedOffset,NaiveDate,NaiveDateTime,Datelike,Duration};
+    use formatter::format_calendar_event;
+    let now = Utc.ymd(2023,10,16).and_hms(12,0,0);
+    // Normalized forms are printed by `systemd-analyze calendar`.
+    let testevents = [
+        ("Sat,Thu,Mon..Wed,Sat..Sun", "Mon..Thu,Sat,Sun *-*-* 00:00:00", Some("2023-10-17 00:00:00")),
+        ("Mon,Sun 12-*-* 2,1:23", "Mon,Sun 2012-*-* 01,02:23:00", None),
+        ("Wed *-1", "Wed *-*-01 00:00:00", Some("2023-11-01 00:00:00")),
+        ("Wed..Wed,Wed *-1", "Wed *-*-01 00:00:00", Some("2023-11-01 00:00:00")),
+        ("Wed, 17:48", "Wed *-*-* 17:48:00", Some("2023-10-18 17:48:00")),
+        ("Wed..Sat,Tue 12-10-15 1:2:3", "Tue..Sat 2012-10-15 01:02:03", None),
+        ("*-*-7 0:0:0", "*-*-07 00:00:00", Some("2023-11-07 00:00:00")),
+        ("10-15", "*-10-15 00:00:00", Some("2024-10-15 00:00:00")),
+        ("monday *-12-* 17:00", "Mon *-12-* 17:00:00", Some("2023-12-04 17:00:00")),
+        ("Mon,Fri *-*-3,1,2 *:30:45", "Mon,Fri *-*-01,02,03 *:30:45", Some("2023-11-03 00:30:45")),
+        ("12,14,13,12:20,10,30", "*-*-* 12,13,14:10,20,30:00", Some("2023-10-16 12:10:00")),
+        ("12..14:10,20,30", "*-*-* 12..14:10,20,30:00", Some("2023-10-16 12:10:00")),
+        ("mon,fri *-1/2-1,3 *:30:45", "Mon,Fri *-01/2-01,03 *:30:45", Some("2023-11-03 00:30:45")),
+        ("03-05 08:05:40", "*-03-05 08:05:40", Some("2024-03-05 08:05:40")),
+        ("08:05:40", "*-*-* 08:05:40", Some("2023-10-17 08:05:40")),
+        ("05:40", "*-*-* 05:40:00", Some("2023-10-17 05:40:00")),
+        ("Sat,Sun 12-05 08:05:40", "Sat,Sun *-12-05 08:05:40", Some("2026-12-05 08:05:40")),
+        ("Sat,Sun 08:05:40", "Sat,Sun *-*-* 08:05:40", Some("2023-10-21 08:05:40")),
+        ("2003-03-05 05:40", "2003-03-05 05:40:00", None),
+        ("2003-02..04-05", "2003-02..04-05 00:00:00", None),
+        ("2003-03-05 05:40 UTC", "2003-03-05 05:40:00 UTC", None),
+        ("minutely", "*-*-* *:*:00", Some("2023-10-16 12:01:00")),
+        ("hourly", "*-*-* *:00:00", Some("2023-10-16 13:00:00")),
+        ("daily", "*-*-* 00:00:00", Some("2023-10-17 00:00:00")),
+        ("daily UTC", "*-*-* 00:00:00 UTC", Some("2023-10-17 00:00:00")),
+        ("monthly", "*-*-01 00:00:00", Some("2023-11-01 00:00:00")),
+        ("weekly", "Mon *-*-* 00:00:00", Some("2023-10-23 00:00:00")),
+        ("weekly Pacific/Auckland", "Mon *-*-* 00:00:00 Pacific/Auckland", Some("2023-10-23 00:00:00")),
+        ("quarterly", "*-01,04,07,10-01 00:00:00", Some("2024-01-01 00:00:00")),
+        ("yearly", "*-01-01 00:00:00", Some("2024-01-01 00:00:00")),
+        ("annually", "*-01-01 00:00:00", Some("2024-01-01 00:00:00")),
+        ("*:2/3", "*-*-* *:02/3:00", Some("2023-10-16 12:02:00")),
+        ("Mon..Fri *-*-* 09:00:00 Europe/Berlin", "Mon..Fri *-*-* 09:00:00 Europe/Berlin", Some("2023-10-17 09:00:00")),
+        ("*-02~03", "*-02~03 00:00:00", Some("2024-02-27 00:00:00")),
+        ("Mon *-05~07/1", "Mon *-05~07/1 00:00:00", Some("2024-05-27 00:00:00")),
+    ];
+    for &(expression, normalized, next) in testevents.iter() {
+        let event = parse_calendar_event(expression).unwrap();
+        let result = (format_calendar_event(&event), event.next_after(&now).next().map(|datetime| datetime.naive_utc().to_string()));
+        let checkevent = (normalized.to_string(), next.map(|next| next.to_string()));
+        if result != checkevent {
+            panic!("Calendar event failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   expression, result, checkevent);
+        }
+    }
+    let testerrors = [
+        ("", ParseError::invalid_format(0,0)),
+        ("Mon..Fry", ParseError::invalid_token(5,3)),
+        ("Fri..Mon", ParseError::invalid_format(0,8)),
+        ("*-13-01", ParseError::invalid_high_value(2,2)),
+        ("*-*-* 24:00", ParseError::invalid_high_value(6,2)),
+        ("*-*-* *:*/0", ParseError::invalid_low_value(10,1)),
+        ("*-*-* 1:2:3:4", ParseError::invalid_format(6,7)),
+        ("*-*-* 05:40:23.42", ParseError::invalid_token(12,5)),
+        ("*-*-* 12:00 Europe/Berlin extra", ParseError::invalid_token(26,5)),
+    ];
+    for &(expression, ref checkerror) in testerrors.iter() {
+        let result = parse_calendar_event(expression);
+        if result.as_ref().err() != Some(checkerror) {
+            panic!("Calendar event parsing failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   expression, result, checkerror);
+        }
+    }
+    let event = parse_calendar_event("Mon..Fri 09:00").unwrap();
+    let previous: Vec<String> = event.prev_before(&now).take(2).map(|datetime| datetime.naive_utc().to_string()).collect();
+    assert_eq!(previous, ["2023-10-16 09:00:00", "2023-10-13 09:00:00"]);
+
+    // Central European time with the gap from 02:00 to 03:00 on the last Sunday of March.
+    let resolver = |tzid: Option<&str>, local: &NaiveDateTime| -> Option<FixedOffset> {
+        match tzid {
+            Some("UTC") => return Some(FixedOffset::east(0)),
+            Some("Europe/Berlin") => {},
+            _ => return Some(FixedOffset::west(5 * 3600)),
+        }
+        let last_sunday = |month: u32| {
+            let last = NaiveDate::from_ymd(local.year(),month,31);
+            return last - Duration::days(last.weekday().num_days_from_sunday() as i64);
+        };
+        let (begin, end) = (last_sunday(3).and_hms(2,0,0), last_sunday(10).and_hms(3,0,0));
+        if *local >= begin && *local < begin + Duration::hours(1) {
+            return None;
+        }
+        return Some(FixedOffset::east(if *local >= begin && *local < end { 2 * 3600 } else { 3600 }));
+    };
+    let now = now.with_timezone(&FixedOffset::east(0));
+    let testzoned = [
+        ("Mon..Fri *-*-* 09:00:00 Europe/Berlin", true, ["2023-10-17T09:00:00+02:00", "2023-10-18T09:00:00+02:00"]),
+        ("Mon..Fri *-*-* 09:00:00 Europe/Berlin", false, ["2023-10-16T09:00:00+02:00", "2023-10-13T09:00:00+02:00"]),
+        ("*-10-29 01,02:00 Europe/Berlin", true, ["2023-10-29T01:00:00+02:00", "2023-10-29T02:00:00+02:00"]),
+        ("*-03-31 02:30 Europe/Berlin", true, ["2024-03-31T03:30:00+02:00", "2025-03-31T02:30:00+02:00"]),
+        ("daily UTC", true, ["2023-10-17T00:00:00+00:00", "2023-10-18T00:00:00+00:00"]),
+        ("Mon 09:00", true, ["2023-10-16T09:00:00-05:00", "2023-10-23T09:00:00-05:00"]),
+        ("12:00 Europe/Berlin", true, ["2023-10-17T12:00:00+02:00", "2023-10-18T12:00:00+02:00"]),
+        ("16:00 Europe/Berlin", false, ["2023-10-15T16:00:00+02:00", "2023-10-14T16:00:00+02:00"]),
+    ];
+    for &(expression, is_forward, ref checkelapses) in testzoned.iter() {
+        let event = parse_calendar_event(expression).unwrap();
+        let elapses = if is_forward { event.next_after_zoned(&now,&resolver) } else { event.prev_before_zoned(&now,&resolver) };
+        let result: Vec<String> = elapses.take(2).map(|datetime| datetime.to_rfc3339()).collect();
+        if result != *checkelapses {
+            panic!("Zoned calendar event failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   expression, result, checkelapses);
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use chrono::Weekday;
+use cron::{CronSchedule,SpecialDay};
+use super::error::*;
+const MONTH_NAMES: [&str; 12] = ["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+const WEEKDAYS: [Weekday; 7] = [Weekday::Sun, Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat];
+const MACROS: [(&str, &str); 7] = [
+    ("@yearly", "0 0 1 1 *"),
+    ("@annually", "0 0 1 1 *"),
+    ("@monthly", "0 0 1 * *"),
+    ("@weekly", "0 0 * * 0"),
+    ("@daily", "0 0 * * *"),
+    ("@midnight", "0 0 * * *"),
+    ("@hourly", "0 * * * *"),
+];
+/// Range and names of cron field values.
+struct Field {
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+}
+const SECOND_FIELD: Field = Field { min: 0, max: 59, names: &[] };
+const MINUTE_FIELD: Field = Field { min: 0, max: 59, names: &[] };
+const HOUR_FIELD: Field = Field { min: 0, max: 23, names: &[] };
+const DAY_FIELD: Field = Field { min: 1, max: 31, names: &[] };
+const MONTH_FIELD: Field = Field { min: 1, max: 12, names: &MONTH_NAMES };
+const UNIX_WEEKDAY_FIELD: Field = Field { min: 0, max: 7, names: &WEEKDAY_NAMES };
+const QUARTZ_WEEKDAY_FIELD: Field = Field { min: 1, max: 7, names: &WEEKDAY_NAMES };
+const YEAR_FIELD: Field = Field { min: 1970, max: 2099, names: &[] };
+/// Splits range of text by separator and returns ranges of parts.
+fn split_ranges(str: &[char],begin: usize,end: usize,separator: char) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut part_begin = begin;
+    for position in (begin..end).filter(|&position| str[position] == separator).chain(Some(end)) {
+        ranges.push((part_begin, position));
+        part_begin = position + 1;
+    }
+    return ranges;
+}
+fn to_upper(str: &[char],begin: usize,end: usize) -> String {
+    return str[begin..end].iter().collect::<String>().to_ascii_uppercase();
+}
+fn parse_value(str: &[char],begin: usize,end: usize,field: &Field) -> ParseResult<u32> {
+    let token = to_upper(str,begin,end);
+    if let Some(index) = field.names.iter().position(|name| *name == token) {
+        return Ok(field.min + index as u32);
+    }
+    if token.is_empty() {
+        return Err(ParseError::invalid_format(begin,0));
+    }
+    if token.len() > 4 || !token.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ParseError::invalid_token(begin,end - begin));
+    }
+    let value: u32 = token.parse().unwrap();
+    if value < field.min {
+        return Err(ParseError::invalid_low_value(begin,end - begin));
+    }
+    if value > field.max {
+        return Err(ParseError::invalid_high_value(begin,end - begin));
+    }
+    return Ok(value);
+}
+/// Parses list of `*`, values and ranges with optional steps such as `1-5,10-30/5,*/15`.
+fn parse_field(str: &[char],begin: usize,end: usize,field: &Field) -> ParseResult<Vec<u32>> {
+    let mut values = Vec::new();
+    for (item_begin,item_end) in split_ranges(str,begin,end,',') {
+        let slash = (item_begin..item_end).find(|&position| str[position] == '/');
+        let base_end = slash.unwrap_or(item_end);
+        let step = match slash {
+            Some(slash) => parse_value(str,slash + 1,item_end,&Field { min: 1, max: field.max, names: &[] })?,
+            None => 1,
+        };
+        let (low, high) = if base_end == item_begin + 1 && str[item_begin] == '*' {
+            (field.min, field.max)
+        } else if let Some(dash) = (item_begin..base_end).find(|&position| str[position] == '-') {
+            let low = parse_value(str,item_begin,dash,field)?;
+            let high = parse_value(str,dash + 1,base_end,field)?;
+            if low > high {
+                return Err(ParseError::invalid_format(item_begin,base_end - item_begin));
+            }
+            (low, high)
+        } else {
+            let value = parse_value(str,item_begin,base_end,field)?;
+            (value, if slash.is_some() { field.max } else { value })
+        };
+        values.extend((low..high + 1).step_by(step as usize));
+    }
+    return Ok(values);
+}
+fn to_bits(values: &[u32]) -> u64 {
+    return values.iter().fold(0,|bits,&value| bits | 1 << value);
+}
+fn parse_days_of_month(str: &[char],begin: usize,end: usize,schedule: &mut CronSchedule) -> ParseResult<()> {
+    let token = to_upper(str,begin,end);
+    schedule.is_day_of_month_any = token == "?" || token.starts_with('*');
+    if token == "?" {
+        schedule.days_of_month = 0xffff_fffe;
+        return Ok(());
+    }
+    for (item_begin,item_end) in split_ranges(str,begin,end,',') {
+        let item = to_upper(str,item_begin,item_end);
+        if item == "L" {
+            schedule.special_days_of_month.push(SpecialDay::LastDay(0));
+        } else if item == "LW" {
+            schedule.special_days_of_month.push(SpecialDay::LastWeekday);
+        } else if item.starts_with("L-") {
+            let offset = parse_value(str,item_begin + 2,item_end,&Field { min: 1, max: 30, names: &[] })?;
+            schedule.special_days_of_month.push(SpecialDay::LastDay(offset));
+        } else if item.len() > 1 && item.ends_with('W') {
+            let day = parse_value(str,item_begin,item_end - 1,&DAY_FIELD)?;
+            schedule.special_days_of_month.push(SpecialDay::NearestWeekday(day));
+        } else {
+            schedule.days_of_month |= to_bits(&parse_field(str,item_begin,item_end,&DAY_FIELD)?) as u32;
+        }
+    }
+    return Ok(());
+}
+fn parse_days_of_week(str: &[char],begin: usize,end: usize,field: &Field,schedule: &mut CronSchedule) -> ParseResult<()> {
+    let token = to_upper(str,begin,end);
+    schedule.is_day_of_week_any = token == "?" || token.starts_with('*');
+    if token == "?" {
+        schedule.days_of_week = 0x7f;
+        return Ok(());
+    }
+    let weekday = |value: u32| (value - field.min) % 7;
+    for (item_begin,item_end) in split_ranges(str,begin,end,',') {
+        let item = to_upper(str,item_begin,item_end);
+        if item == "L" {
+            schedule.days_of_week |= 1 << 6;
+        } else if item.len() > 1 && item.ends_with('L') {
+            let value = parse_value(str,item_begin,item_end - 1,field)?;
+            schedule.special_days_of_week.push(SpecialDay::LastOfWeekday(WEEKDAYS[weekday(value) as usize]));
+        } else if let Some(hash) = (item_begin..item_end).find(|&position| str[position] == '#') {
+            let value = parse_value(str,item_begin,hash,field)?;
+            let n = parse_value(str,hash + 1,item_end,&Field { min: 1, max: 5, names: &[] })?;
+            schedule.special_days_of_week.push(SpecialDay::NthWeekday(WEEKDAYS[weekday(value) as usize],n));
+        } else {
+            let values: Vec<u32> = parse_field(str,item_begin,item_end,field)?.into_iter().map(weekday).collect();
+            schedule.days_of_week |= to_bits(&values) as u8;
+        }
+    }
+    return Ok(());
+}
+/// Parses cron expression.
+///
+/// Supported forms:
+///
+/// * classic five fields: `minute hour day-of-month month day-of-week`,
+///   day of week is 0 through 7 where both 0 and 7 mean Sunday;
+/// * Quartz six or seven fields: `second minute hour day-of-month month day-of-week [year]`,
+///   day of week is 1 through 7 where 1 means Sunday, year is 1970 through 2099;
+/// * macros `@yearly`, `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight` and `@hourly`.
+///
+/// Fields are lists of `*`, values, ranges `a-b` and steps `*/n`, `a/n`, `a-b/n`. Months and days of the week
+/// may be names such as `JAN` and `MON`. Quartz extensions are accepted in every form:
+/// `?` for unrestricted day field, `L`, `L-n`, `nW` and `LW` in day of month,
+/// `L`, `nL` (last weekday n of the month) and `n#k` (k-th weekday n of the month) in day of week.
+pub fn parse_cron(text: &str) -> ParseResult<CronSchedule> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut fields = Vec::new();
+    let mut position = 0;
+    while position < str.len() {
+        if str[position].is_whitespace() {
+            position += 1;
+            continue;
+        }
+        let begin = position;
+        while position < str.len() && !str[position].is_whitespace() {
+            position += 1;
+        }
+        fields.push((begin, position));
+    }
+    if fields.len() == 1 && str[fields[0].0] == '@' {
+        let (begin, end) = fields[0];
+        let name = str[begin..end].iter().collect::<String>().to_ascii_lowercase();
+        return match MACROS.iter().find(|&&(macro_name,_)| macro_name == name) {
+            Some(&(_,expression)) => parse_cron(expression),
+            None => Err(ParseError::invalid_token(begin,end - begin)),
+        };
+    }
+    let is_quartz = match fields.len() {
+        5 => false,
+        6 | 7 => true,
+        _ => return Err(ParseError::invalid_format(0,str.len())),
+    };
+    let mut schedule = CronSchedule {
+        seconds: 1,
+        minutes: 0,
+        hours: 0,
+        days_of_month: 0,
+        special_days_of_month: Vec::new(),
+        is_day_of_month_any: false,
+        months: 0,
+        days_of_week: 0,
+        special_days_of_week: Vec::new(),
+        is_day_of_week_any: false,
+        years: Vec::new(),
+    };
+    if is_quartz {
+        let (begin, end) = fields.remove(0);
+        schedule.seconds = to_bits(&parse_field(str,begin,end,&SECOND_FIELD)?);
+    }
+    schedule.minutes = to_bits(&parse_field(str,fields[0].0,fields[0].1,&MINUTE_FIELD)?);
+    schedule.hours = to_bits(&parse_field(str,fields[1].0,fields[1].1,&HOUR_FIELD)?) as u32;
+    parse_days_of_month(str,fields[2].0,fields[2].1,&mut schedule)?;
+    schedule.months = to_bits(&parse_field(str,fields[3].0,fields[3].1,&MONTH_FIELD)?) as u16;
+    let weekday_field = if is_quartz { &QUARTZ_WEEKDAY_FIELD } else { &UNIX_WEEKDAY_FIELD };
+    parse_days_of_week(str,fields[4].0,fields[4].1,weekday_field,&mut schedule)?;
+    if fields.len() == 6 && str[fields[5].0..fields[5].1] != ['*'] {
+        let mut years: Vec<i32> = parse_field(str,fields[5].0,fields[5].1,&YEAR_FIELD)?.into_iter().map(|year| year as i32).collect();
+        years.sort();
+        years.dedup();
+        schedule.years = years;
+    }
+    return Ok(schedule);
+}
+#[cfg(test)]
+#[test]
+fn test_cron() {
+    use chrono::{NaiveDate,NaiveDateTime};
+    let datetime = |text: &str| NaiveDateTime::parse_from_str(text,"%Y-%m-%d %H:%M:%S").unwrap();
+    let testcrons = [
+        ("*/15 9-17 * * MON-FRI", "2023-10-16 09:45:00", true),
+        ("*/15 9-17 * * MON-FRI", "2023-10-15 09:45:00", false),
+        ("*/15 9-17 * * MON-FRI", "2023-10-16 09:45:30", false),
+        ("0 0 13 * 5", "2023-10-13 00:00:00", true),
+        ("0 0 13 * 5", "2023-10-20 00:00:00", true),
+        ("0 0 13 * 5", "2023-10-19 00:00:00", false),
+        ("0 0 * * 7", "2023-10-15 00:00:00", true),
+        ("0 0 L * ?", "2024-02-29 00:00:00", true),
+        ("0 0 L-1 * ?", "2024-02-28 00:00:00", true),
+        ("0 0 15W * ?", "2023-07-14 00:00:00", true),
+        ("0 0 1W * ?", "2023-07-03 00:00:00", true),
+        ("0 0 LW * ?", "2023-09-29 00:00:00", true),
+        ("0 0 0 ? * 6#3", "2023-10-20 00:00:00", true),
+        ("0 0 ? * FRI#3", "2023-10-13 00:00:00", false),
+        ("0 0 ? * 5L", "2023-10-27 00:00:00", true),
+        ("30 0 0 ? * 1", "2023-10-15 00:00:30", true),
+        ("0 0 0 1 JAN ? 2024/2", "2026-01-01 00:00:00", true),
+        ("0 0 0 1 JAN ? 2024/2", "2025-01-01 00:00:00", false),
+        ("@daily", "2023-10-16 00:00:00", true),
+        ("@hourly", "2023-10-16 12:30:00", false),
+    ];
+    for &(cron, time, checkmatch) in testcrons.iter() {
+        let result = parse_cron(cron).unwrap().matches(&datetime(time));
+        if result != checkmatch {
+            panic!("Cron matching failed for {} at {}\nReceived: {:?}\nExpected: {:?}",
+                   cron, time, result, checkmatch);
+        }
+    }
+    let testerrors = [
+        ("* * * *", ParseError::invalid_format(0,7)),
+        ("60 * * * *", ParseError::invalid_high_value(0,2)),
+        ("* 24 * * *", ParseError::invalid_high_value(2,2)),
+        ("* * 0 * *", ParseError::invalid_low_value(4,1)),
+        ("* * * FOO *", ParseError::invalid_token(6,3)),
+        ("* * * * 1-8", ParseError::invalid_high_value(10,1)),
+        ("*/0 * * * *", ParseError::invalid_low_value(2,1)),
+        ("* 5-3 * * *", ParseError::invalid_format(2,3)),
+        ("* * 1,,2 * *", ParseError::invalid_format(6,0)),
+        ("? * * * *", ParseError::invalid_token(0,1)),
+        ("0 0 0 ? * 0", ParseError::invalid_low_value(10,1)),
+        ("0 0 0 ? * MON#6", ParseError::invalid_high_value(14,1)),
+        ("0 0 0 1 1 ? 1969", ParseError::invalid_low_value(12,4)),
+        ("@reboot", ParseError::invalid_token(0,7)),
+    ];
+    for &(cron, ref checkerror) in testerrors.iter() {
+        let result = parse_cron(cron);
+        if result.as_ref().err() != Some(checkerror) {
+            panic!("Cron parsing failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   cron, result, checkerror);
+        }
+    }
+    let schedule = parse_cron("0 0 0 29 FEB ? 2024-2099").unwrap();
+    assert_eq!(schedule.years.len(), 76);
+    assert_eq!(schedule.find_local(&NaiveDate::from_ymd(2024,3,1).and_hms(0,0,0),true), Some(datetime("2028-02-29 00:00:00")));
+    assert_eq!(schedule.find_local(&NaiveDate::from_ymd(2024,3,1).and_hms(0,0,0),false), Some(datetime("2024-02-29 00:00:00")));
+    assert_eq!(schedule.find_local(&NaiveDate::from_ymd(2024,2,29).and_hms(0,0,0),false), None);
+}
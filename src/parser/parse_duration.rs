@@ -0,0 +1,218 @@
+use chrono::Duration;
+use super::error::*;
+/// Syntax of duration strings.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DurationSyntax {
+    /// Integer counts with units `w`, `d`, `h`, `m`, `s`, `ms`, `us`, `ns`, optionally separated by spaces,
+    /// such as `1h 30m`, `2d4h`, `-90s`.
+    Compact,
+    /// Go `time.ParseDuration` syntax: signed sequence of decimal numbers with units `h`, `m`, `s`, `ms`,
+    /// `us` (`µs`), `ns`, such as `1h30m`, `1.5h`, `-300ms`. Range is limited to 64-bit count of nanoseconds.
+    Go,
+    /// Syntax of Rust `humantime` crate: integer counts with long or short units separated by optional spaces,
+    /// such as `2days 4h`, `1year 6months`. Month is 30.44 days, year is 365.25 days.
+    Humantime,
+}
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+const COMPACT_UNITS: [(&str, i128); 8] = [
+    ("w", 7 * 86400 * NANOSECONDS_PER_SECOND),
+    ("d", 86400 * NANOSECONDS_PER_SECOND),
+    ("h", 3600 * NANOSECONDS_PER_SECOND),
+    ("m", 60 * NANOSECONDS_PER_SECOND),
+    ("s", NANOSECONDS_PER_SECOND),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+];
+const GO_UNITS: [(&str, i128); 8] = [
+    ("h", 3600 * NANOSECONDS_PER_SECOND),
+    ("m", 60 * NANOSECONDS_PER_SECOND),
+    ("s", NANOSECONDS_PER_SECOND),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("\u{b5}s", 1_000),
+    ("\u{3bc}s", 1_000),
+    ("ns", 1),
+];
+const HUMANTIME_UNITS: [(&str, i128); 33] = [
+    ("years", 31_557_600 * NANOSECONDS_PER_SECOND),
+    ("year", 31_557_600 * NANOSECONDS_PER_SECOND),
+    ("y", 31_557_600 * NANOSECONDS_PER_SECOND),
+    ("months", 2_630_016 * NANOSECONDS_PER_SECOND),
+    ("month", 2_630_016 * NANOSECONDS_PER_SECOND),
+    ("M", 2_630_016 * NANOSECONDS_PER_SECOND),
+    ("weeks", 7 * 86400 * NANOSECONDS_PER_SECOND),
+    ("week", 7 * 86400 * NANOSECONDS_PER_SECOND),
+    ("w", 7 * 86400 * NANOSECONDS_PER_SECOND),
+    ("days", 86400 * NANOSECONDS_PER_SECOND),
+    ("day", 86400 * NANOSECONDS_PER_SECOND),
+    ("d", 86400 * NANOSECONDS_PER_SECOND),
+    ("hours", 3600 * NANOSECONDS_PER_SECOND),
+    ("hour", 3600 * NANOSECONDS_PER_SECOND),
+    ("hr", 3600 * NANOSECONDS_PER_SECOND),
+    ("h", 3600 * NANOSECONDS_PER_SECOND),
+    ("minutes", 60 * NANOSECONDS_PER_SECOND),
+    ("minute", 60 * NANOSECONDS_PER_SECOND),
+    ("min", 60 * NANOSECONDS_PER_SECOND),
+    ("m", 60 * NANOSECONDS_PER_SECOND),
+    ("seconds", NANOSECONDS_PER_SECOND),
+    ("second", NANOSECONDS_PER_SECOND),
+    ("sec", NANOSECONDS_PER_SECOND),
+    ("s", NANOSECONDS_PER_SECOND),
+    ("msec", 1_000_000),
+    ("ms", 1_000_000),
+    ("usec", 1_000),
+    ("us", 1_000),
+    ("nsec", 1),
+    ("ns", 1),
+    ("millis", 1_000_000),
+    ("micros", 1_000),
+    ("nanos", 1),
+];
+impl DurationSyntax {
+    fn units(&self) -> &'static [(&'static str, i128)] {
+        match *self {
+            DurationSyntax::Compact => &COMPACT_UNITS,
+            DurationSyntax::Go => &GO_UNITS,
+            DurationSyntax::Humantime => &HUMANTIME_UNITS,
+        }
+    }
+    /// Returns maximal absolute count of nanoseconds.
+    fn limit(&self) -> i128 {
+        match *self {
+            DurationSyntax::Go => i64::MAX as i128,
+            _ => (i64::MAX / 1000) as i128 * NANOSECONDS_PER_SECOND,
+        }
+    }
+}
+/// Returns `Duration` of count of nanoseconds. Count must be within the `Duration` range.
+fn duration_from_nanoseconds(nanoseconds: i128) -> Duration {
+    let seconds = nanoseconds.div_euclid(NANOSECONDS_PER_SECOND) as i64;
+    let nanosecond = nanoseconds.rem_euclid(NANOSECONDS_PER_SECOND) as i64;
+    return Duration::seconds(seconds) + Duration::nanoseconds(nanosecond);
+}
+/// Parses a duration string such as `1h 30m`, `2d4h` or `90s`.
+///
+/// Returns `InvalidUnit` error for unknown or missing unit, `Overflow` error for duration
+/// out of range, `InvalidFormat` error for missing number.
+pub fn parse_duration(text: &str,syntax: DurationSyntax) -> ParseResult<Duration> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let is_spaces_allowed = syntax != DurationSyntax::Go;
+    let skip_spaces = |position: &mut usize| {
+        while is_spaces_allowed && *position < str.len() && str[*position].is_whitespace() {
+            *position = *position + 1;
+        }
+    };
+    skip_spaces(&mut position);
+    let mut is_negative = false;
+    if syntax != DurationSyntax::Humantime && position < str.len() && (str[position] == '-' || str[position] == '+') {
+        is_negative = str[position] == '-';
+        position = position + 1;
+    }
+    let rest: String = str[position..].iter().collect();
+    if rest.trim_end() == "0" {
+        return Ok(Duration::zero());
+    }
+    if position == str.len() {
+        return Err(ParseError::invalid_format(0,str.len()));
+    }
+    let limit = syntax.limit() + if is_negative && syntax == DurationSyntax::Go { 1 } else { 0 };
+    let mut total: i128 = 0;
+    while position < str.len() {
+        let number_position = position;
+        let mut integer: i128 = 0;
+        let mut is_overflow = false;
+        while position < str.len() && str[position].is_ascii_digit() {
+            if !is_overflow {
+                integer = integer * 10 + str[position].to_digit(10).unwrap_or(0) as i128;
+                is_overflow = integer > limit;
+            }
+            position = position + 1;
+        }
+        let mut has_digits = position > number_position;
+        let mut fraction: i128 = 0;
+        let mut scale: i128 = 1;
+        if syntax == DurationSyntax::Go && position < str.len() && str[position] == '.' {
+            position = position + 1;
+            while position < str.len() && str[position].is_ascii_digit() {
+                if scale < NANOSECONDS_PER_SECOND * NANOSECONDS_PER_SECOND {
+                    fraction = fraction * 10 + str[position].to_digit(10).unwrap_or(0) as i128;
+                    scale = scale * 10;
+                }
+                has_digits = true;
+                position = position + 1;
+            }
+        }
+        if !has_digits {
+            return Err(ParseError::invalid_format(number_position,position - number_position + 1));
+        }
+        let unit_position = position;
+        while position < str.len() && !str[position].is_ascii_digit() && str[position] != '.' && !(is_spaces_allowed && str[position].is_whitespace()) {
+            position = position + 1;
+        }
+        let unit: String = str[unit_position..position].iter().collect();
+        let unit_nanoseconds = match syntax.units().iter().find(|&&(name,_)| name == unit) {
+            Some(&(_,unit_nanoseconds)) => unit_nanoseconds,
+            None => return Err(ParseError::invalid(ParseErrorKind::InvalidUnit,unit_position,position - unit_position)),
+        };
+        match integer.checked_mul(unit_nanoseconds) {
+            Some(value) if !is_overflow => total = total + value + fraction * unit_nanoseconds / scale,
+            _ => is_overflow = true,
+        }
+        if is_overflow || total > limit {
+            return Err(ParseError::invalid(ParseErrorKind::Overflow,number_position,position - number_position));
+        }
+        skip_spaces(&mut position);
+    }
+    if is_negative {
+        total = -total;
+    }
+    return Ok(duration_from_nanoseconds(total));
+}
+#[cfg(test)]
+#[test]
+fn test_duration() {
+    use formatter::format_duration;
+    let testdurations = [
+        ("1h 30m", DurationSyntax::Compact, Ok("1h30m")),
+        ("2d4h", DurationSyntax::Compact, Ok("2d4h")),
+        ("90s", DurationSyntax::Compact, Ok("1m30s")),
+        ("-1w 500ms", DurationSyntax::Compact, Ok("-7d500ms")),
+        ("0", DurationSyntax::Compact, Ok("0s")),
+        ("1.5h", DurationSyntax::Compact, Err(ParseErrorKind::InvalidUnit)),
+        ("1h30", DurationSyntax::Compact, Err(ParseErrorKind::InvalidUnit)),
+        ("1fortnight", DurationSyntax::Compact, Err(ParseErrorKind::InvalidUnit)),
+        ("h", DurationSyntax::Compact, Err(ParseErrorKind::InvalidFormat)),
+        ("", DurationSyntax::Compact, Err(ParseErrorKind::InvalidFormat)),
+        ("1h30m", DurationSyntax::Go, Ok("1h30m0s")),
+        ("1.5h", DurationSyntax::Go, Ok("1h30m0s")),
+        ("-300ms", DurationSyntax::Go, Ok("-300ms")),
+        ("1.5\u{b5}s", DurationSyntax::Go, Ok("1.5\u{b5}s")),
+        (".5s", DurationSyntax::Go, Ok("500ms")),
+        ("1h 30m", DurationSyntax::Go, Err(ParseErrorKind::InvalidUnit)),
+        ("2562047h47m16.854775807s", DurationSyntax::Go, Ok("2562047h47m16.854775807s")),
+        ("2562047h47m16.854775808s", DurationSyntax::Go, Err(ParseErrorKind::Overflow)),
+        ("-2562047h47m16.854775808s", DurationSyntax::Go, Ok("-2562047h47m16.854775808s")),
+        ("2days 4h", DurationSyntax::Humantime, Ok("2days 4h")),
+        ("1year 6months", DurationSyntax::Humantime, Ok("1year 6months")),
+        ("15minutes 90sec", DurationSyntax::Humantime, Ok("16m 30s")),
+        ("-1h", DurationSyntax::Humantime, Err(ParseErrorKind::InvalidFormat)),
+        ("99999999999999999999999y", DurationSyntax::Humantime, Err(ParseErrorKind::Overflow)),
+    ];
+    for &(duration, syntax, checkduration) in testdurations.iter() {
+        let result = match parse_duration(duration,syntax) {
+            Ok(result) => Ok(format_duration(&result,syntax)),
+            Err(e) => Err(e.error_kind),
+        };
+        if result != checkduration.map(|s| s.to_string()) {
+            panic!("Duration conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   duration, result, checkduration);
+        }
+    }
+    assert_eq!(parse_duration("1h 30x",DurationSyntax::Compact).unwrap_err(), ParseError::invalid(ParseErrorKind::InvalidUnit,5,1));
+    assert_eq!(parse_duration("1h30",DurationSyntax::Compact).unwrap_err(), ParseError::invalid(ParseErrorKind::InvalidUnit,4,0));
+    assert_eq!(parse_duration("1h 9999999999999999w",DurationSyntax::Compact).unwrap_err(), ParseError::invalid(ParseErrorKind::Overflow,3,17));
+    assert_eq!(parse_duration("90s",DurationSyntax::Compact), Ok(Duration::seconds(90)));
+}
@@ -0,0 +1,181 @@
+use chrono::NaiveDate;
+use calendars::{Era,EraFormat,THAI_MONTHS,find_era};
+use super::helper::*;
+use super::error::*;
+/// Parses a date with era year such as `令和5年10月16日`, `令和元年5月1日`, `R5.10.16`, `民國112年10月16日`
+/// or `16 ตุลาคม พ.ศ. 2566`.
+///
+/// Valid formats depend on `Era::format`: era name, year (`元` is the first year), `年`, month, `月`, day, `日`
+/// for CJK eras; day, space, Thai month name, space, era name, optional space and year for Thai eras.
+/// Any era also accepts its abbreviation (case-insensitive), year, month and day separated by `.`, `/` or `-`.
+/// Full-width digits are accepted. The date must be in the era: `平成31年5月1日` is an `InvalidEraYear` error,
+/// because Reiwa started on that day.
+pub fn parse_era_date(text: &str,eras: &[Era]) -> ParseResult<NaiveDate> {
+    let chars: Vec<char> = text.chars().map(to_ascii_digit).collect();
+    let str = &chars;
+    let mut position = 0;
+    let era;
+    let year_position;
+    let year;
+    let year_length;
+    let month;
+    let day;
+    if str.first().map_or(false,|c| c.is_ascii_digit()) {
+        day = parse_number(str,&mut position,31,ParseErrorKind::InvalidDay)?;
+        let _ = parse_char(str,&mut position,' ')?;
+        month = match match_name(str,position,&THAI_MONTHS) {
+            Some((index,length)) => {
+                position = position + length;
+                index as u32 + 1
+            },
+            None => return Err(ParseError::invalid(ParseErrorKind::InvalidMonth,position,word_length(str,position).max(1))),
+        };
+        let _ = parse_char(str,&mut position,' ')?;
+        era = match match_era(str,position,eras) {
+            Some((era,length,true)) if era.format == EraFormat::Thai => {
+                position = position + length;
+                era
+            },
+            _ => return Err(ParseError::invalid_token(position,1)),
+        };
+        let _ = parse_is_token_or_end(str,&mut position," ")?;
+        year_position = position;
+        year = parse_digits(str,&mut position,4,ParseErrorKind::InvalidYear)? as i32;
+        year_length = position - year_position;
+    } else {
+        let (found, length, is_name) = match match_era(str,position,eras) {
+            Some((era,_,true)) if era.format != EraFormat::Cjk => return Err(ParseError::invalid_token(0,1)),
+            Some(era) => era,
+            None => return Err(ParseError::invalid_token(0,1)),
+        };
+        era = found;
+        position = length;
+        year_position = position;
+        if is_name && position < str.len() && str[position] == '元' {
+            year = 1;
+            position = position + 1;
+        } else {
+            year = parse_digits(str,&mut position,4,ParseErrorKind::InvalidYear)? as i32;
+        }
+        year_length = position - year_position;
+        if is_name {
+            let _ = parse_char(str,&mut position,'年')?;
+            month = parse_number(str,&mut position,12,ParseErrorKind::InvalidMonth)?;
+            let _ = parse_char(str,&mut position,'月')?;
+            day = parse_number(str,&mut position,31,ParseErrorKind::InvalidDay)?;
+            let _ = parse_char(str,&mut position,'日')?;
+        } else {
+            let separator = match str.get(position) {
+                Some(&separator) if separator == '.' || separator == '/' || separator == '-' => separator,
+                _ => return Err(ParseError::invalid_token(position,1)),
+            };
+            position = position + 1;
+            month = parse_number(str,&mut position,12,ParseErrorKind::InvalidMonth)?;
+            let _ = parse_char(str,&mut position,separator)?;
+            day = parse_number(str,&mut position,31,ParseErrorKind::InvalidDay)?;
+        }
+    }
+    let _ = parse_end_of_string(str,&position)?;
+    if year < 1 {
+        return Err(ParseError::invalid(ParseErrorKind::InvalidEraYear,year_position,year_length));
+    }
+    let date = match NaiveDate::from_ymd_opt(era.gregorian_year(year),month,day) {
+        Some(date) => date,
+        None => return Err(ParseError::invalid(ParseErrorKind::InvalidDate,0,position)),
+    };
+    return match find_era(eras,&date) {
+        Some((found,_)) if found == era => Ok(date),
+        _ => Err(ParseError::invalid(ParseErrorKind::InvalidEraYear,0,position)),
+    };
+}
+fn to_ascii_digit(c: char) -> char {
+    if ('０'..='９').contains(&c) {
+        return (b'0' + (c as u32 - '０' as u32) as u8) as char;
+    }
+    return c;
+}
+/// Returns the era with the longest name or abbreviation at the position, its length in chars
+/// and `true` if it is the name.
+fn match_era<'a>(str: &Vec<char>,position: usize,eras: &'a [Era]) -> Option<(&'a Era, usize, bool)> {
+    let mut result: Option<(&'a Era, usize, bool)> = None;
+    for era in eras.iter() {
+        for &(text,is_name) in [(era.name, true), (era.abbreviation, false)].iter() {
+            let length = text.chars().count();
+            if length == 0 || str.len() < position + length || result.map_or(false,|(_,result_length,_)| result_length >= length) {
+                continue;
+            }
+            if str[position..position + length].iter().zip(text.chars()).all(|(a,b)| a.to_lowercase().eq(b.to_lowercase())) {
+                result = Some((era, length, is_name));
+            }
+        }
+    }
+    return result;
+}
+fn parse_char(str: &Vec<char>,position: &mut usize,token: char) -> ParseResult<()> {
+    if *position < str.len() && str[*position] == token {
+        *position = *position + 1;
+        return Ok(());
+    }
+    return Err(ParseError::invalid_token(*position,1));
+}
+fn parse_number(str: &Vec<char>,position: &mut usize,max: u32,error_kind: ParseErrorKind) -> ParseResult<u32> {
+    let begin = *position;
+    let result = parse_digits(str,position,2,error_kind);
+    return validate_range(result,1,max,&begin,*position - begin);
+}
+#[cfg(test)]
+#[test]
+fn test_era_date() {
+    use calendars::{JAPANESE_ERAS,MINGUO_ERAS,THAI_BUDDHIST_ERAS};
+    use formatter::{format_era_date,EraStyle};
+    let (japanese, minguo, thai) = (&JAPANESE_ERAS[..], &MINGUO_ERAS[..], &THAI_BUDDHIST_ERAS[..]);
+    let testdates = [
+        ("令和5年10月16日", japanese, Ok(("2023-10-16", "令和5年10月16日", "R5.10.16"))),
+        ("R5.10.16", japanese, Ok(("2023-10-16", "令和5年10月16日", "R5.10.16"))),
+        ("r05/10/16", japanese, Ok(("2023-10-16", "令和5年10月16日", "R5.10.16"))),
+        ("令和５年１０月１６日", japanese, Ok(("2023-10-16", "令和5年10月16日", "R5.10.16"))),
+        ("令和元年5月1日", japanese, Ok(("2019-05-01", "令和元年5月1日", "R1.05.01"))),
+        ("令和1年4月30日", japanese, Err(ParseErrorKind::InvalidEraYear)),
+        ("平成31年4月30日", japanese, Ok(("2019-04-30", "平成31年4月30日", "H31.04.30"))),
+        ("平成31年5月1日", japanese, Err(ParseErrorKind::InvalidEraYear)),
+        ("H32.01.01", japanese, Err(ParseErrorKind::InvalidEraYear)),
+        ("H0.01.01", japanese, Err(ParseErrorKind::InvalidEraYear)),
+        ("昭和64年1月7日", japanese, Ok(("1989-01-07", "昭和64年1月7日", "S64.01.07"))),
+        ("平成元年1月8日", japanese, Ok(("1989-01-08", "平成元年1月8日", "H1.01.08"))),
+        ("明治6年1月1日", japanese, Ok(("1873-01-01", "明治6年1月1日", "M6.01.01"))),
+        ("令和5年2月30日", japanese, Err(ParseErrorKind::InvalidDate)),
+        ("R5.13.16", japanese, Err(ParseErrorKind::InvalidHighValue)),
+        ("R5.10/16", japanese, Err(ParseErrorKind::InvalidToken)),
+        ("X5.10.16", japanese, Err(ParseErrorKind::InvalidToken)),
+        ("R元.10.16", japanese, Err(ParseErrorKind::InvalidYear)),
+        ("令和5年10月16日 ", japanese, Err(ParseErrorKind::StringNotEnded)),
+        ("民國112年10月16日", minguo, Ok(("2023-10-16", "民國112年10月16日", "ROC112.10.16"))),
+        ("民國元年1月1日", minguo, Ok(("1912-01-01", "民國元年1月1日", "ROC1.01.01"))),
+        ("民國0年1月1日", minguo, Err(ParseErrorKind::InvalidEraYear)),
+        ("BE2566.10.16", thai, Ok(("2023-10-16", "16 ตุลาคม พ.ศ. 2566", "BE2566.10.16"))),
+        ("16 ตุลาคม พ.ศ. 2566", thai, Ok(("2023-10-16", "16 ตุลาคม พ.ศ. 2566", "BE2566.10.16"))),
+        ("１ มกราคม พ.ศ.2567", thai, Ok(("2024-01-01", "1 มกราคม พ.ศ. 2567", "BE2567.01.01"))),
+        ("29 กุมภาพันธ์ พ.ศ. 2566", thai, Err(ParseErrorKind::InvalidDate)),
+        ("16 October พ.ศ. 2566", thai, Err(ParseErrorKind::InvalidMonth)),
+        ("16 ตุลาคม 2566", thai, Err(ParseErrorKind::InvalidToken)),
+        ("พ.ศ.2566年10月16日", thai, Err(ParseErrorKind::InvalidToken)),
+        ("16 ตุลาคม 令和5", japanese, Err(ParseErrorKind::InvalidToken)),
+    ];
+    for &(date, eras, checkdate) in testdates.iter() {
+        let result = match parse_era_date(date,eras) {
+            Ok(parsed) => Ok((parsed.to_string(), format_era_date(&parsed,eras,EraStyle::Full).unwrap(),
+                              format_era_date(&parsed,eras,EraStyle::Abbreviated).unwrap())),
+            Err(e) => Err(e.error_kind),
+        };
+        if result != checkdate.map(|(gregorian, full, abbreviated)| (gregorian.to_string(), full.to_string(), abbreviated.to_string())) {
+            panic!("Era date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, result, checkdate);
+        }
+    }
+    assert_eq!(format_era_date(&NaiveDate::from_ymd(1868,10,22),&JAPANESE_ERAS,EraStyle::Full), None);
+    let mut eras = JAPANESE_ERAS.to_vec();
+    eras.push(Era { name: "新元", abbreviation: "N", start: (2100, 1, 1), format: EraFormat::Cjk });
+    assert_eq!(parse_era_date("R81.12.31",&eras), Ok(NaiveDate::from_ymd(2099,12,31)));
+    assert_eq!(parse_era_date("R82.01.01",&eras).map_err(|e| e.error_kind), Err(ParseErrorKind::InvalidEraYear));
+    assert_eq!(parse_era_date("新元元年1月1日",&eras), Ok(NaiveDate::from_ymd(2100,1,1)));
+}
@@ -0,0 +1,348 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Duration;
+use super::helper::*;
+use super::error::*;
+/// iCalendar (RFC 5545) `DATE` or `DATE-TIME` value that keeps its form.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ICalDateTime {
+    /// `DATE`: `19970714`.
+    Date(NaiveDate),
+    /// Floating `DATE-TIME` which is the same local time in any time zone: `19980118T230000`.
+    Floating(NaiveDateTime),
+    /// UTC `DATE-TIME`: `19980119T070000Z`.
+    Utc(NaiveDateTime),
+    /// `DATE-TIME` in time zone of `TZID` parameter: `TZID=America/New_York:19980119T020000`.
+    Zoned(String, NaiveDateTime),
+}
+impl ICalDateTime {
+    /// Returns `true` for date and floating date and time.
+    pub fn is_floating(&self) -> bool {
+        if let ICalDateTime::Utc(_) = *self {
+            return false;
+        }
+        return self.tzid().is_none();
+    }
+    /// Returns time zone identifier of `TZID` parameter, if any.
+    pub fn tzid(&self) -> Option<&str> {
+        match *self {
+            ICalDateTime::Zoned(ref tzid,_) => Some(tzid),
+            _ => None,
+        }
+    }
+    /// Returns date and time as it was written. Date without time is treated as midnight.
+    pub fn naive_local(&self) -> NaiveDateTime {
+        match *self {
+            ICalDateTime::Date(date) => date.and_hms_opt(0, 0, 0).unwrap(),
+            ICalDateTime::Floating(datetime) => datetime,
+            ICalDateTime::Utc(datetime) => datetime,
+            ICalDateTime::Zoned(_,datetime) => datetime,
+        }
+    }
+    /// Returns UTC `DateTime` for UTC value, otherwise `DateTime` of local date and time with `default` offset.
+    ///
+    /// Offset of `TZID` is not resolved: it should be found by caller and passed as `default`.
+    pub fn with_default_offset(&self, default: FixedOffset) -> Option<DateTime<FixedOffset>> {
+        let offset = match *self {
+            ICalDateTime::Utc(_) => FixedOffset::east_opt(0).unwrap(),
+            _ => default,
+        };
+        return self.naive_local().checked_sub_signed(Duration::seconds(offset.local_minus_utc() as i64))
+            .map(|utc| DateTime::from_utc(utc, offset));
+    }
+}
+/// iCalendar (RFC 5545) `PERIOD` value.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ICalPeriod {
+    /// Start and end: `19970101T180000Z/19970102T070000Z`.
+    Explicit(ICalDateTime, ICalDateTime),
+    /// Start and duration: `19970101T180000Z/PT5H30M`.
+    Start(ICalDateTime, Duration),
+}
+fn parse_ical_date_part(str: &Vec<char>,position: &mut usize) -> ParseResult<NaiveDate> {
+    let begin = *position;
+    let year = parse_full_year(str,position)?;
+    let month = parse_month_number(str,position)?;
+    let day = parse_day_number(str,position)?;
+    return match NaiveDate::from_ymd_opt(year,month,day) {
+        Some(date) => Ok(date),
+        None => Err(ParseError::invalid(ParseErrorKind::InvalidDate,begin,*position - begin)),
+    };
+}
+fn parse_ical_datetime_part(str: &Vec<char>,position: &mut usize,tzid: Option<String>) -> ParseResult<ICalDateTime> {
+    let date = parse_ical_date_part(str,position)?;
+    if !parse_is_token_or_end(str,position,"T")? {
+        if tzid.is_some() {
+            return Err(ParseError::invalid_token(*position,1));
+        }
+        return Ok(ICalDateTime::Date(date));
+    }
+    let hour = parse_hour_24(str,position)?;
+    let minute = parse_minute(str,position)?;
+    // Leap second 60 is kept as the 59th second with chrono's leap nanosecond, as W3C parser does.
+    let datetime = match parse_seconds_leap(str,position)? {
+        60 => date.and_time(to_time(str,hour,minute,59,1_000_000_000)?),
+        seconds => date.and_time(to_time(str,hour,minute,seconds,0)?),
+    };
+    if parse_is_token_or_end(str,position,"Z")? {
+        if tzid.is_some() {
+            return Err(ParseError::invalid_token(*position - 1,1));
+        }
+        return Ok(ICalDateTime::Utc(datetime));
+    }
+    return Ok(match tzid {
+        Some(tzid) => ICalDateTime::Zoned(tzid,datetime),
+        None => ICalDateTime::Floating(datetime),
+    });
+}
+/// Parses an iCalendar `DATE` value such as `19970714`.
+pub fn parse_ical_date(text: &str) -> ParseResult<NaiveDate> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let date = parse_ical_date_part(str,&mut position)?;
+    let _ = parse_end_of_string(str,&position)?;
+    return Ok(date);
+}
+/// Parses an iCalendar `DATE` or `DATE-TIME` value.
+///
+/// Valid formats: `YYYYMMDD`, `YYYYMMDDThhmmss`, `YYYYMMDDThhmmssZ`
+/// and `TZID=tzid:YYYYMMDDThhmmss` (`tzid` may be quoted), as in `DTSTART;TZID=America/New_York:19980119T020000`.
+/// Second 60 is accepted as a leap second without checking a leap second table.
+pub fn parse_ical_datetime(text: &str) -> ParseResult<ICalDateTime> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let mut tzid: Option<String> = None;
+    if text.starts_with("TZID=") {
+        position = 5;
+        let is_quoted = parse_is_token(str,&mut position,"\"")?;
+        let begin = position;
+        let terminator = if is_quoted { '"' } else { ':' };
+        while position < str.len() && str[position] != terminator {
+            position = position + 1;
+        }
+        if position == begin {
+            return Err(ParseError::invalid_token(begin,1));
+        }
+        tzid = Some(str[begin..position].iter().collect());
+        if is_quoted {
+            let _ = parse_token(str,&mut position,"\"")?;
+        }
+        let _ = parse_token(str,&mut position,":")?;
+    }
+    let datetime = parse_ical_datetime_part(str,&mut position,tzid)?;
+    let _ = parse_end_of_string(str,&position)?;
+    return Ok(datetime);
+}
+/// Parses an iCalendar `UTC-OFFSET` value such as `-0500` or `+013045`.
+///
+/// `-0000` is not valid.
+pub fn parse_ical_utc_offset(text: &str) -> ParseResult<FixedOffset> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let is_positive = parse_is_token(str,&mut position,"+")?;
+    if !is_positive {
+        let _ = parse_token(str,&mut position,"-")?;
+    }
+    let hour = parse_hour_24(str,&mut position)?;
+    let minute = parse_minute(str,&mut position)?;
+    let mut seconds = 0;
+    if position < str.len() {
+        seconds = parse_seconds(str,&mut position)?;
+    }
+    let _ = parse_end_of_string(str,&position)?;
+    let offset = (hour * 3600 + minute * 60 + seconds) as i32;
+    if is_positive {
+        return to_offset(offset,0,str.len());
+    }
+    if offset == 0 {
+        return Err(ParseError::invalid_format(0,str.len()));
+    }
+    return to_offset(-offset,0,str.len());
+}
+fn parse_ical_duration_part(str: &Vec<char>,position: &mut usize) -> ParseResult<Duration> {
+    let begin = *position;
+    let is_negative = parse_duration_start(str,position)?;
+    let mut duration = Duration::zero();
+    let mut is_time = false;
+    let mut has_value = false;
+    // Units in the order of RFC 5545: weeks are not combined with other units.
+    let units = ['W', 'D', 'H', 'M', 'S'];
+    let mut unit_index = 0;
+    while let Some(component) = parse_duration_component(str,position,&mut is_time)? {
+        if component.is_negative {
+            return Err(ParseError::invalid_format(component.position,1));
+        }
+        let found = units.iter().position(|&unit| unit == component.unit);
+        let is_valid = component.nanosecond.is_none() && match found {
+            Some(0) => !has_value && !is_time,
+            Some(1) => !is_time && unit_index < 2,
+            Some(index) => is_time && index >= unit_index.max(2),
+            None => false,
+        };
+        if !is_valid {
+            return Err(ParseError::invalid(ParseErrorKind::InvalidUnit,component.unit_position,1));
+        }
+        duration = duration + match component.unit {
+            'W' => Duration::weeks(component.value),
+            'D' => Duration::days(component.value),
+            'H' => Duration::hours(component.value),
+            'M' => Duration::minutes(component.value),
+            _ => Duration::seconds(component.value),
+        };
+        unit_index = found.unwrap_or(0) + 1;
+        has_value = true;
+        if component.unit == 'W' && *position < str.len() && str[*position] != '/' {
+            return Err(ParseError::invalid_token(*position,1));
+        }
+    }
+    if !has_value || (is_time && unit_index <= 2) {
+        return Err(ParseError::invalid_format(begin,*position - begin));
+    }
+    return Ok(if is_negative { -duration } else { duration });
+}
+/// Parses an iCalendar `DURATION` value such as `P15DT5H0M20S`, `-PT15M` or `P7W`.
+pub fn parse_ical_duration(text: &str) -> ParseResult<Duration> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let duration = parse_ical_duration_part(str,&mut position)?;
+    let _ = parse_end_of_string(str,&position)?;
+    return Ok(duration);
+}
+/// Parses an iCalendar `PERIOD` value such as `19970101T180000Z/19970102T070000Z` or `19970101T180000Z/PT5H30M`.
+///
+/// Start and end must be `DATE-TIME` values. Duration must be positive.
+pub fn parse_ical_period(text: &str) -> ParseResult<ICalPeriod> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let start = parse_ical_datetime_part(str,&mut position,None)?;
+    if let ICalDateTime::Date(_) = start {
+        return Err(ParseError::invalid_token(position,1));
+    }
+    let _ = parse_token(str,&mut position,"/")?;
+    let end_position = position;
+    if position < str.len() && (str[position] == 'P' || str[position] == '+' || str[position] == '-') {
+        let duration = parse_ical_duration_part(str,&mut position)?;
+        let _ = parse_end_of_string(str,&position)?;
+        if duration <= Duration::zero() {
+            return Err(ParseError::invalid_low_value(end_position,position - end_position));
+        }
+        return Ok(ICalPeriod::Start(start,duration));
+    }
+    let end = parse_ical_datetime_part(str,&mut position,None)?;
+    if let ICalDateTime::Date(_) = end {
+        return Err(ParseError::invalid_token(position,1));
+    }
+    let _ = parse_end_of_string(str,&position)?;
+    return Ok(ICalPeriod::Explicit(start,end));
+}
+#[cfg(test)]
+#[test]
+fn test_icalendar() {
+    use chrono::Timelike;
+    use formatter::{format_ical_datetime,format_ical_utc_offset,format_ical_duration,format_ical_period};
+    let testdates = [
+        ("19970714", Ok("19970714")),
+        ("19980118T230000", Ok("19980118T230000")),
+        ("19980119T070000Z", Ok("19980119T070000Z")),
+        ("TZID=America/New_York:19980119T020000", Ok("TZID=America/New_York:19980119T020000")),
+        ("TZID=\"Europe/Moscow\":19980119T020000", Ok("TZID=Europe/Moscow:19980119T020000")),
+        ("TZID=America/New_York:19980119T070000Z", Err(ParseErrorKind::InvalidToken)),
+        ("TZID=America/New_York:19980119", Err(ParseErrorKind::InvalidToken)),
+        ("19980119T0700", Err(ParseErrorKind::InvalidSeconds)),
+        ("19981231T235960Z", Ok("19981231T235960Z")),
+        ("TZID=America/New_York:19981231T185960", Ok("TZID=America/New_York:19981231T185960")),
+        ("19981231T235961Z", Err(ParseErrorKind::InvalidHighValue)),
+        ("19970230", Err(ParseErrorKind::InvalidDate)),
+        ("1997-07-14", Err(ParseErrorKind::InvalidMonth)),
+    ];
+    for &(date, checkdate) in testdates.iter() {
+        let dt = match parse_ical_datetime(date) {
+            Ok(dt) => Ok(format_ical_datetime(&dt)),
+            Err(e) => Err(e.error_kind),
+        };
+        if dt != checkdate.map(|s| s.to_string()) {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, dt, checkdate);
+        }
+    }
+    let floating = parse_ical_datetime("19980118T230000").unwrap();
+    assert!(floating.is_floating());
+    assert_eq!(floating.with_default_offset(FixedOffset::east(3600)).map(|dt| dt.to_rfc3339()), Some("1998-01-18T23:00:00+01:00".to_string()));
+    let utc = parse_ical_datetime("19980119T070000Z").unwrap();
+    assert!(!utc.is_floating());
+    assert_eq!(utc.with_default_offset(FixedOffset::east(3600)).map(|dt| dt.to_rfc3339()), Some("1998-01-19T07:00:00+00:00".to_string()));
+    assert_eq!(parse_ical_datetime("TZID=America/New_York:19980119T020000").unwrap().tzid(), Some("America/New_York"));
+    assert_eq!(parse_ical_date("19970714"), Ok(NaiveDate::from_ymd(1997,7,14)));
+    assert_eq!(parse_ical_datetime("19981231T235960Z").map(|dt| dt.naive_local().nanosecond()), Ok(1_000_000_000));
+
+    let testoffsets = [
+        ("-0500", Ok("-0500")),
+        ("+0100", Ok("+0100")),
+        ("+013045", Ok("+013045")),
+        ("+0000", Ok("+0000")),
+        ("-0000", Err(ParseErrorKind::InvalidFormat)),
+        ("0500", Err(ParseErrorKind::InvalidToken)),
+        ("+05:00", Err(ParseErrorKind::InvalidMinute)),
+    ];
+    for &(offset, checkoffset) in testoffsets.iter() {
+        let result = match parse_ical_utc_offset(offset) {
+            Ok(result) => Ok(format_ical_utc_offset(&result)),
+            Err(e) => Err(e.error_kind),
+        };
+        if result != checkoffset.map(|s| s.to_string()) {
+            panic!("Offset conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   offset, result, checkoffset);
+        }
+    }
+
+    let testdurations = [
+        ("P15DT5H0M20S", Ok("P15DT5H0M20S")),
+        ("P7W", Ok("P7W")),
+        ("P14D", Ok("P2W")),
+        ("-PT15M", Ok("-PT15M")),
+        ("+PT1H", Ok("PT1H")),
+        ("PT1H5S", Ok("PT1H0M5S")),
+        ("P1DT", Err(ParseErrorKind::InvalidFormat)),
+        ("P", Err(ParseErrorKind::InvalidFormat)),
+        ("P1Y", Err(ParseErrorKind::InvalidUnit)),
+        ("PT5M1H", Err(ParseErrorKind::InvalidUnit)),
+        ("P1W2D", Err(ParseErrorKind::InvalidToken)),
+        ("P1234567890D", Err(ParseErrorKind::Overflow)),
+    ];
+    for &(duration, checkduration) in testdurations.iter() {
+        let result = match parse_ical_duration(duration) {
+            Ok(result) => Ok(format_ical_duration(&result)),
+            Err(e) => Err(e.error_kind),
+        };
+        if result != checkduration.map(|s| s.to_string()) {
+            panic!("Duration conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   duration, result, checkduration);
+        }
+    }
+
+    let testperiods = [
+        ("19970101T180000Z/19970102T070000Z", Ok("19970101T180000Z/19970102T070000Z")),
+        ("19970101T180000Z/PT5H30M", Ok("19970101T180000Z/PT5H30M")),
+        ("19970101T180000/PT5H30M", Ok("19970101T180000/PT5H30M")),
+        ("19970101/PT5H30M", Err(ParseErrorKind::InvalidToken)),
+        ("19970101T180000Z/-PT5H30M", Err(ParseErrorKind::InvalidLowValue)),
+        ("19970101T180000Z/19970102", Err(ParseErrorKind::InvalidToken)),
+    ];
+    for &(period, checkperiod) in testperiods.iter() {
+        let result = match parse_ical_period(period) {
+            Ok(result) => Ok(format_ical_period(&result)),
+            Err(e) => Err(e.error_kind),
+        };
+        if result != checkperiod.map(|s| s.to_string()) {
+            panic!("Period conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   period, result, checkperiod);
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Duration;
+use arithmetic::{CalendarArithmetic,MonthEndPolicy};
+use super::parse_w3c_datetime::parse_w3c_datetime;
+use super::helper::*;
+use super::error::*;
+/// ISO 8601 duration with calendar components such as `P1Y2M10DT2H30M`.
+///
+/// Years and months have no fixed length: they are added by `CalendarArithmetic::add_iso_duration`
+/// with a `MonthEndPolicy`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct IsoDuration {
+    /// Years.
+    pub years: i32,
+    /// Months.
+    pub months: i32,
+    /// Days. Weeks are counted as 7 days.
+    pub days: i64,
+    /// Hours, minutes and seconds.
+    pub time: Duration,
+}
+impl IsoDuration {
+    /// Returns duration of the given years and months only.
+    pub fn months(years: i32,months: i32) -> IsoDuration {
+        return IsoDuration { years: years, months: months, days: 0, time: Duration::zero() };
+    }
+    /// Returns duration with all components negated. Returns `None` on overflow.
+    pub fn checked_neg(&self) -> Option<IsoDuration> {
+        return Some(IsoDuration {
+            years: self.years.checked_neg()?,
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            time: -self.time,
+        });
+    }
+}
+/// ISO 8601 time interval.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum IsoInterval {
+    /// Start and end: `2007-03-01T13:00:00Z/2008-05-11T15:30:00Z`.
+    StartEnd(DateTime<FixedOffset>, DateTime<FixedOffset>),
+    /// Start and duration: `2007-03-01T13:00:00Z/P1Y2M10DT2H30M`.
+    StartDuration(DateTime<FixedOffset>, IsoDuration),
+    /// Duration and end: `P1Y2M10DT2H30M/2008-05-11T15:30:00Z`.
+    DurationEnd(IsoDuration, DateTime<FixedOffset>),
+}
+impl IsoInterval {
+    /// Returns start of the interval. Duration is subtracted from the end with `policy`.
+    /// Returns `None` on overflow.
+    pub fn start(&self,policy: MonthEndPolicy) -> Option<DateTime<FixedOffset>> {
+        return match *self {
+            IsoInterval::StartEnd(start,_) => Some(start),
+            IsoInterval::StartDuration(start,_) => Some(start),
+            IsoInterval::DurationEnd(ref duration,end) => end.add_iso_duration(&duration.checked_neg()?,policy),
+        };
+    }
+    /// Returns end of the interval. Duration is added to the start with `policy`.
+    /// Returns `None` on overflow.
+    pub fn end(&self,policy: MonthEndPolicy) -> Option<DateTime<FixedOffset>> {
+        return match *self {
+            IsoInterval::StartEnd(_,end) => Some(end),
+            IsoInterval::StartDuration(start,ref duration) => start.add_iso_duration(duration,policy),
+            IsoInterval::DurationEnd(_,end) => Some(end),
+        };
+    }
+}
+fn parse_iso_duration_part(str: &Vec<char>,position: &mut usize) -> ParseResult<IsoDuration> {
+    let begin = *position;
+    let is_negative = parse_duration_start(str,position)?;
+    let mut duration = IsoDuration::months(0,0);
+    let mut is_time = false;
+    let mut has_value = false;
+    let mut has_time_value = false;
+    // Units in the order of ISO 8601, each at most once.
+    let units = ['Y', 'M', 'W', 'D', 'H', 'M', 'S'];
+    let mut unit_index = 0;
+    while let Some(component) = parse_duration_component(str,position,&mut is_time)? {
+        let (first, last) = if is_time { (4, units.len()) } else { (0, 4) };
+        let found = (first.max(unit_index)..last).find(|&index| units[index] == component.unit);
+        let index = match found {
+            Some(index) if component.nanosecond.is_none() || index == 6 => index,
+            _ => return Err(ParseError::invalid(ParseErrorKind::InvalidUnit,component.unit_position,1)),
+        };
+        let value = if component.is_negative { -component.value } else { component.value };
+        match index {
+            0 => duration.years = value as i32,
+            1 => duration.months = value as i32,
+            2 => duration.days = duration.days + value * 7,
+            3 => duration.days = duration.days + value,
+            4 => duration.time = duration.time + Duration::hours(value),
+            5 => duration.time = duration.time + Duration::minutes(value),
+            _ => {
+                let seconds = Duration::seconds(component.value) + Duration::nanoseconds(component.nanosecond.unwrap_or(0) as i64);
+                duration.time = duration.time + if component.is_negative { -seconds } else { seconds };
+            },
+        }
+        unit_index = index + 1;
+        has_value = true;
+        has_time_value = has_time_value || is_time;
+    }
+    if !has_value || (is_time && !has_time_value) {
+        return Err(ParseError::invalid_format(begin,*position - begin));
+    }
+    if is_negative {
+        return duration.checked_neg().ok_or(ParseError::invalid(ParseErrorKind::Overflow,begin,*position - begin));
+    }
+    return Ok(duration);
+}
+fn parse_interval_datetime(str: &Vec<char>,position: &mut usize) -> ParseResult<DateTime<FixedOffset>> {
+    let begin = *position;
+    while *position < str.len() && str[*position] != '/' {
+        *position = *position + 1;
+    }
+    let text: String = str[begin..*position].iter().collect();
+    return parse_w3c_datetime(&text)
+        .map_err(|e| ParseError::invalid(e.error_kind,begin + e.position_begin,e.position_end - e.position_begin));
+}
+fn is_duration_start(str: &Vec<char>,position: usize) -> bool {
+    return position < str.len() && (str[position] == 'P' || str[position] == '+' || str[position] == '-');
+}
+/// Parses an ISO 8601 duration such as `P1Y2M10DT2H30M`, `P3W`, `PT0.5S` or `-P1M`.
+///
+/// Units are years, months, weeks and days, then after `T` hours, minutes and seconds,
+/// each at most once and in this order. Only seconds may have a fraction.
+/// Components may have their own sign as `format_iso_duration` writes them: `P1M-2D`.
+pub fn parse_iso_duration(text: &str) -> ParseResult<IsoDuration> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let duration = parse_iso_duration_part(str,&mut position)?;
+    let _ = parse_end_of_string(str,&position)?;
+    return Ok(duration);
+}
+/// Parses an ISO 8601 time interval such as `2007-03-01T13:00:00Z/2008-05-11T15:30:00Z`,
+/// `2007-03-01T13:00:00Z/P1Y2M10DT2H30M` or `P1Y2M10DT2H30M/2008-05-11T15:30:00Z`.
+///
+/// Start and end are W3C date and time strings.
+pub fn parse_iso_interval(text: &str) -> ParseResult<IsoInterval> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    if is_duration_start(str,position) {
+        let duration = parse_iso_duration_part(str,&mut position)?;
+        let _ = parse_token(str,&mut position,"/")?;
+        let end = parse_interval_datetime(str,&mut position)?;
+        return Ok(IsoInterval::DurationEnd(duration,end));
+    }
+    let start = parse_interval_datetime(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"/")?;
+    if is_duration_start(str,position) {
+        let duration = parse_iso_duration_part(str,&mut position)?;
+        let _ = parse_end_of_string(str,&position)?;
+        return Ok(IsoInterval::StartDuration(start,duration));
+    }
+    let end = parse_interval_datetime(str,&mut position)?;
+    let _ = parse_end_of_string(str,&position)?;
+    return Ok(IsoInterval::StartEnd(start,end));
+}
+#[cfg(test)]
+#[test]
+fn test_iso_duration() {
+    use formatter::{format_iso_duration,format_iso_interval,format_w3c};
+    let testdurations = [
+        ("P1Y2M10DT2H30M", Ok("P1Y2M10DT2H30M")),
+        ("P3W", Ok("P21D")),
+        ("P1W2D", Ok("P9D")),
+        ("PT0.5S", Ok("PT0.5S")),
+        ("PT1,25S", Ok("PT1.25S")),
+        ("-P1M", Ok("-P1M")),
+        ("+P1Y", Ok("P1Y")),
+        ("PT36H", Ok("PT36H")),
+        ("P0D", Ok("PT0S")),
+        ("P", Err(ParseErrorKind::InvalidFormat)),
+        ("P1YT", Err(ParseErrorKind::InvalidFormat)),
+        ("P1M1Y", Err(ParseErrorKind::InvalidUnit)),
+        ("P1H", Err(ParseErrorKind::InvalidUnit)),
+        ("PT1D", Err(ParseErrorKind::InvalidUnit)),
+        ("P1.5Y", Err(ParseErrorKind::InvalidUnit)),
+        ("PT1.5M", Err(ParseErrorKind::InvalidUnit)),
+        ("P1234567890Y", Err(ParseErrorKind::Overflow)),
+        ("1Y", Err(ParseErrorKind::InvalidToken)),
+        ("P1M-2D", Ok("P1M-2D")),
+        ("-P1M-2D", Ok("P-1M2D")),
+        ("P1DT-0.5S", Ok("P1DT-0.5S")),
+        ("P-1Y-2M", Ok("-P1Y2M")),
+        ("P--1D", Err(ParseErrorKind::InvalidFormat)),
+        ("P1D-", Err(ParseErrorKind::InvalidFormat)),
+    ];
+    for &(duration, checkduration) in testdurations.iter() {
+        let result = match parse_iso_duration(duration) {
+            Ok(result) => Ok(format_iso_duration(&result)),
+            Err(e) => Err(e.error_kind),
+        };
+        if result != checkduration.map(|s| s.to_string()) {
+            panic!("Duration conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   duration, result, checkduration);
+        }
+    }
+
+    let testintervals = [
+        ("2007-03-01T13:00:00Z/2008-05-11T15:30:00Z", Ok(("2007-03-01T13:00:00Z", "2008-05-11T15:30:00Z"))),
+        ("2007-03-01T13:00:00Z/P1Y2M10DT2H30M", Ok(("2007-03-01T13:00:00Z", "2008-05-11T15:30:00Z"))),
+        ("P1Y2M10DT2H30M/2008-05-11T15:30:00Z", Ok(("2007-03-01T13:00:00Z", "2008-05-11T15:30:00Z"))),
+        ("2024-01-31T00:00:00+03:00/P1M", Ok(("2024-01-31T00:00:00+03:00", "2024-02-29T00:00:00+03:00"))),
+        ("2023-01-31T00:00:00Z/P1M1D", Ok(("2023-01-31T00:00:00Z", "2023-03-01T00:00:00Z"))),
+        ("P1M/2023-03-31T12:00:00Z", Ok(("2023-02-28T12:00:00Z", "2023-03-31T12:00:00Z"))),
+        ("2024-02-29T00:00:00Z/P1Y", Ok(("2024-02-29T00:00:00Z", "2025-02-28T00:00:00Z"))),
+        ("2007-03-01T13:00:00Z", Err(ParseErrorKind::InvalidToken)),
+        ("2007-03-01T13:00:00Z/P1Y/", Err(ParseErrorKind::StringNotEnded)),
+        ("P1Y/P1M", Err(ParseErrorKind::InvalidYear)),
+    ];
+    for &(interval, checkinterval) in testintervals.iter() {
+        let result = match parse_iso_interval(interval) {
+            Ok(result) => {
+                assert_eq!(parse_iso_interval(&format_iso_interval(&result)), Ok(result));
+                Ok((result.start(MonthEndPolicy::Clamp).map(|start| format_w3c(&start)).unwrap_or_default(),
+                    result.end(MonthEndPolicy::Clamp).map(|end| format_w3c(&end)).unwrap_or_default()))
+            },
+            Err(e) => Err(e.error_kind),
+        };
+        if result != checkinterval.map(|(start,end)| (start.to_string(),end.to_string())) {
+            panic!("Interval conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   interval, result, checkinterval);
+        }
+    }
+    let roundtrips = [
+        IsoDuration { years: 1, months: 0, days: -2, time: Duration::zero() },
+        IsoDuration { years: 0, months: -1, days: 3, time: Duration::hours(-25) - Duration::milliseconds(500) },
+        IsoDuration { years: 0, months: 0, days: 1, time: Duration::milliseconds(-500) },
+        IsoDuration { years: -1, months: -2, days: -3, time: Duration::seconds(-61) },
+        IsoDuration { years: 2, months: 3, days: 0, time: Duration::nanoseconds(1) },
+    ];
+    for duration in roundtrips.iter() {
+        let text = format_iso_duration(duration);
+        assert_eq!(parse_iso_duration(&text), Ok(*duration), "{}", text);
+    }
+    let interval = parse_iso_interval("2023-02-28T00:00:00Z/P1M").unwrap();
+    assert_eq!(interval.end(MonthEndPolicy::PreserveEndOfMonth).map(|end| format_w3c(&end)), Some("2023-03-31T00:00:00Z".to_string()));
+    let interval = parse_iso_interval("2023-01-31T00:00:00Z/P1M").unwrap();
+    assert_eq!(interval.end(MonthEndPolicy::Overflow).map(|end| format_w3c(&end)), Some("2023-03-03T00:00:00Z".to_string()));
+}
@@ -0,0 +1,138 @@
+use chrono::Datelike;
+use julian_calendar::{JulianDate,Cutover,HistoricalDate};
+use super::helper::*;
+use super::error::*;
+fn parse_ymd(str: &Vec<char>,position: &mut usize) -> ParseResult<(i32, u32, u32)> {
+    let year = parse_full_year(str,position)?;
+    let _ = parse_token(str,position,"-")?;
+    let month = parse_month_number(str,position)?;
+    let _ = parse_token(str,position,"-")?;
+    let day = parse_day_number(str,position)?;
+    return Ok((year, month, day));
+}
+/// Parses a Julian calendar date with optional Gregorian dual dating such as `1700-03-11/22`.
+///
+/// Valid formats: `YYYY-MM-DD`, `YYYY-MM-DD/DD`, `YYYY-MM-DD/MM-DD`, `YYYY-MM-DD/YYYY-MM-DD`,
+/// where the part after slash is the same day in the Gregorian calendar with omitted leading
+/// components equal to the Julian ones.
+///
+/// Dual dating is checked: a Gregorian part which is not the same day as the Julian date is rejected
+/// with `InvalidDate`. For example `1700-02-11/22` is an error, since Julian 1700-02-11 is Gregorian
+/// 1700-02-21 and the gap grows to 11 days only after Julian 1700-02-29.
+pub fn parse_julian_date(text: &str) -> ParseResult<JulianDate> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let (year, month, day) = parse_ymd(str,&mut position)?;
+    let date = match JulianDate::from_ymd_opt(year,month,day) {
+        Some(date) => date,
+        None => return Err(ParseError::invalid(ParseErrorKind::InvalidDate,0,position)),
+    };
+    if !parse_is_token_or_end(str,&mut position,"/")? {
+        let _ = parse_end_of_string(str,&position)?;
+        return Ok(date);
+    }
+    let begin = position;
+    let (mut gregorian_year, mut gregorian_month) = (year, month);
+    let gregorian_day;
+    match str.len() - begin {
+        2 => {
+            gregorian_day = parse_day_number(str,&mut position)?;
+        },
+        5 => {
+            gregorian_month = parse_month_number(str,&mut position)?;
+            let _ = parse_token(str,&mut position,"-")?;
+            gregorian_day = parse_day_number(str,&mut position)?;
+        },
+        10 => {
+            let (y, m, d) = parse_ymd(str,&mut position)?;
+            gregorian_year = y;
+            gregorian_month = m;
+            gregorian_day = d;
+        },
+        _ => return Err(ParseError::invalid_format(begin,str.len() - begin)),
+    }
+    let gregorian = match date.to_naive_date() {
+        Some(gregorian) => gregorian,
+        None => return Err(ParseError::invalid(ParseErrorKind::Overflow,0,begin - 1)),
+    };
+    if (gregorian.year(), gregorian.month(), gregorian.day()) != (gregorian_year, gregorian_month, gregorian_day) {
+        return Err(ParseError::invalid(ParseErrorKind::InvalidDate,begin,position - begin));
+    }
+    return Ok(date);
+}
+/// Parses a `YYYY-MM-DD` date of the civil calendar that switched to the Gregorian calendar at the cutover.
+///
+/// Dates before the cutover are read as Julian, days skipped by the cutover are an `InvalidDate` error.
+pub fn parse_historical_date(text: &str,cutover: &Cutover) -> ParseResult<HistoricalDate> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let (year, month, day) = parse_ymd(str,&mut position)?;
+    let _ = parse_end_of_string(str,&position)?;
+    return match cutover.from_historical_ymd(year,month,day) {
+        Some(date) => Ok(date),
+        None => Err(ParseError::invalid(ParseErrorKind::InvalidDate,0,position)),
+    };
+}
+#[cfg(test)]
+#[test]
+fn test_julian_date() {
+    use chrono::NaiveDate;
+    use formatter::{format_julian_date,format_dual_date,format_historical_date};
+    let testdates = [
+        ("1700-03-11", Ok("1700-03-11/22")),
+        ("1700-03-11/22", Ok("1700-03-11/22")),
+        ("1700-02-11/21", Ok("1700-02-11/21")),
+        // Julian 1700-02-11 is Gregorian 1700-02-21, the 11 days gap starts after 1700-02-29.
+        ("1700-02-11/22", Err(ParseErrorKind::InvalidDate)),
+        ("1700-02-19/03-01", Ok("1700-02-19/03-01")),
+        ("1700-02-29", Ok("1700-02-29/03-11")),
+        ("1700-12-25/1701-01-05", Ok("1700-12-25/1701-01-05")),
+        ("1752-09-02/13", Ok("1752-09-02/13")),
+        ("1701-02-29", Err(ParseErrorKind::InvalidDate)),
+        ("1700-13-01", Err(ParseErrorKind::InvalidHighValue)),
+        ("1700-03-11/", Err(ParseErrorKind::InvalidFormat)),
+        ("1700-03-11/2", Err(ParseErrorKind::InvalidFormat)),
+        ("1700-03-11 22", Err(ParseErrorKind::StringNotEnded)),
+        ("1700-03-11/03-22", Ok("1700-03-11/22")),
+    ];
+    for &(date, checkdate) in testdates.iter() {
+        let result = match parse_julian_date(date) {
+            Ok(date) => Ok(format_dual_date(&date).unwrap()),
+            Err(e) => Err(e.error_kind),
+        };
+        if result != checkdate.map(|s| s.to_string()) {
+            panic!("Julian date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, result, checkdate);
+        }
+    }
+    assert_eq!(parse_julian_date("1582-10-04").map(|date| format_julian_date(&date)), Ok("1582-10-04".to_string()));
+    assert_eq!(parse_julian_date("1582-10-04").map(|date| date.to_naive_date()), Ok(Some(NaiveDate::from_ymd(1582,10,14))));
+
+    let britain = Cutover::great_britain();
+    let testhistorical = [
+        ("1752-09-02", Ok((false, "1752-09-13"))),
+        ("1752-09-03", Err(ParseErrorKind::InvalidDate)),
+        ("1752-09-13", Err(ParseErrorKind::InvalidDate)),
+        ("1752-09-14", Ok((true, "1752-09-14"))),
+        ("1700-02-29", Ok((false, "1700-03-11"))),
+        ("1800-02-29", Err(ParseErrorKind::InvalidDate)),
+    ];
+    for &(date, checkdate) in testhistorical.iter() {
+        let result = match parse_historical_date(date,&britain) {
+            Ok(historical) => {
+                let gregorian = historical.to_naive_date().unwrap();
+                assert_eq!(format_historical_date(&gregorian,&britain), date);
+                Ok((britain.is_gregorian(&gregorian), gregorian.format("%Y-%m-%d").to_string()))
+            },
+            Err(e) => Err(e.error_kind),
+        };
+        if result != checkdate.map(|(is_gregorian, s)| (is_gregorian, s.to_string())) {
+            panic!("Historical date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, result, checkdate);
+        }
+    }
+    assert_eq!(parse_historical_date("1582-10-10",&Cutover::default()).map_err(|e| e.error_kind), Err(ParseErrorKind::InvalidDate));
+    assert_eq!(parse_historical_date("1582-10-10",&Cutover::russia()).map(|date| date.ymd()), Ok((1582, 10, 10)));
+}
@@ -0,0 +1,124 @@
+use julian_day::{DayEpoch,DayNumber,NANOSECONDS_PER_DAY};
+use super::error::*;
+/// Maximal count of fraction digits.
+const MAX_FRACTION_DIGITS: usize = 20;
+/// Parses day number with day counting abbreviation such as `JD 2457043.23` or `MJD 57042.73`.
+///
+/// Valid formats: `JD D`, `JD D.d`, `JD -D.d`, where `JD` is one of `JD`, `MJD`, `RJD`, `TJD`.
+///
+/// Fraction of day is converted exactly and rounded to nanoseconds.
+pub fn parse_julian_day(text: &str) -> ParseResult<(DayNumber, DayEpoch)> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    while position < str.len() && str[position].is_ascii_uppercase() {
+        position = position + 1;
+    }
+    let name: String = str[..position].iter().cloned().collect();
+    let epoch = match DayEpoch::from_name(&name) {
+        Some(epoch) => epoch,
+        None => return Err(ParseError::invalid_token(0,position)),
+    };
+    if position >= str.len() || str[position] != ' ' {
+        return Err(ParseError::invalid_token(position,1));
+    }
+    position = position + 1;
+    let is_negative = position < str.len() && str[position] == '-';
+    if is_negative {
+        position = position + 1;
+    }
+    let day_position = position;
+    let mut day: i64 = 0;
+    while position < str.len() && str[position].is_ascii_digit() {
+        let digit = str[position].to_digit(10).unwrap_or(0) as i64;
+        day = match day.checked_mul(10).and_then(|day| day.checked_add(digit)) {
+            Some(day) => day,
+            None => return Err(ParseError::invalid_high_value(day_position,position - day_position + 1)),
+        };
+        position = position + 1;
+    }
+    if position == day_position {
+        return Err(ParseError::invalid(ParseErrorKind::InvalidDay,day_position,0));
+    }
+    let mut nanosecond = 0;
+    if position < str.len() && str[position] == '.' {
+        position = position + 1;
+        let fraction_position = position;
+        let mut fraction: u128 = 0;
+        while position < str.len() && str[position].is_ascii_digit() {
+            fraction = fraction * 10 + str[position].to_digit(10).unwrap_or(0) as u128;
+            position = position + 1;
+            if position - fraction_position > MAX_FRACTION_DIGITS {
+                return Err(ParseError::invalid(ParseErrorKind::InvalidNanoseconds,fraction_position,position - fraction_position));
+            }
+        }
+        let length = position - fraction_position;
+        if length == 0 {
+            return Err(ParseError::invalid(ParseErrorKind::InvalidNanoseconds,fraction_position,0));
+        }
+        let denominator = 10u128.pow(length as u32);
+        nanosecond = ((fraction * NANOSECONDS_PER_DAY as u128 + denominator / 2) / denominator) as u64;
+    }
+    if position != str.len() {
+        return Err(ParseError::invalid(ParseErrorKind::StringNotEnded,position,0));
+    }
+    let mut value = DayNumber {
+        day: day,
+        nanosecond: nanosecond,
+    };
+    if value.nanosecond >= NANOSECONDS_PER_DAY {
+        value.day = match value.day.checked_add(1) {
+            Some(day) => day,
+            None => return Err(ParseError::invalid(ParseErrorKind::Overflow,day_position,position - day_position)),
+        };
+        value.nanosecond = value.nanosecond - NANOSECONDS_PER_DAY;
+    }
+    if is_negative {
+        if value.nanosecond > 0 {
+            value.day = -value.day - 1;
+            value.nanosecond = NANOSECONDS_PER_DAY - value.nanosecond;
+        } else {
+            value.day = -value.day;
+        }
+    }
+    return Ok((value, epoch));
+}
+#[cfg(test)]
+#[test]
+fn test_julian_day() {
+    use formatter::format_julian_day;
+    let testdata = [
+        ("JD 2457043.23", Ok("JD 2457043.23000")),
+        ("MJD 57042.73", Ok("MJD 57042.73000")),
+        ("RJD 57043", Ok("RJD 57043.00000")),
+        ("TJD -0.25", Ok("TJD -0.25000")),
+        ("JD 2457043.999999999999", Ok("JD 2457044.00000")),
+        ("JD2457043.23", Err(ParseErrorKind::InvalidToken)),
+        ("XJD 2457043.23", Err(ParseErrorKind::InvalidToken)),
+        ("JD ", Err(ParseErrorKind::InvalidDay)),
+        ("JD 2457043.", Err(ParseErrorKind::InvalidNanoseconds)),
+        ("JD 2457043.2x", Err(ParseErrorKind::StringNotEnded)),
+        ("JD 99999999999999999999", Err(ParseErrorKind::InvalidHighValue)),
+        ("JD 9223372036854775807.99999999999999999999", Err(ParseErrorKind::Overflow)),
+        ("JD -9223372036854775807.99999999999999999999", Err(ParseErrorKind::Overflow)),
+        ("JD 9223372036854775807.9999", Ok("JD 9223372036854775807.99990")),
+        ("JD 9223372036854775807.999999", Err(ParseErrorKind::Overflow)),
+        ("JD -9223372036854775807.9999", Ok("JD -9223372036854775807.99990")),
+    ];
+    for &(text, expected) in testdata.iter() {
+        let result = match parse_julian_day(text) {
+            Ok((value, epoch)) => format_julian_day(&value, epoch, 5).ok_or(ParseErrorKind::Overflow),
+            Err(e) => Err(e.error_kind),
+        };
+        if result != expected.map(|s| s.to_string()) {
+            panic!("Day number conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   text, result, expected);
+        }
+    }
+    let (value, epoch) = parse_julian_day("JD 2451545.0").unwrap();
+    assert_eq!(format!("{}", value.to_naive(epoch).unwrap()), "2000-01-01 12:00:00");
+    assert_eq!(format_julian_day(&DayNumber { day: i64::MAX, nanosecond: 86_399_999_999_999 }, DayEpoch::JulianDay, 3), None);
+    assert_eq!(format_julian_day(&DayNumber { day: i64::MAX, nanosecond: 86_399_999_999_999 }, DayEpoch::JulianDay, 20),
+               Some("JD 9223372036854775807.99999999999998842593".to_string()));
+    assert_eq!(format_julian_day(&DayNumber { day: i64::MIN, nanosecond: 0 }, DayEpoch::JulianDay, 3), Some("JD -9223372036854775808.000".to_string()));
+}
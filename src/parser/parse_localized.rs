@@ -0,0 +1,191 @@
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::Datelike;
+use locale::Locale;
+use super::helper::*;
+use super::error::*;
+/// Parses a human date such as `11 декабря 2011 в 01:43` or `Sunday, December 11th, 2011 at 1:43 AM`.
+///
+/// Valid formats: optional weekday name, then day number and month name in any order, year,
+/// then optional time `h:mm` or `h:mm:ss` (with AM/PM marker in locales which have one).
+/// Month names may be full in nominative or genitive case or abbreviated with optional dot.
+/// Parts may be separated by spaces, commas and connector words of the locale, such as `в`, `à`, `um`, `de`.
+///
+/// Returns date and time, if it is present. Weekday name must match the date.
+pub fn parse_localized(text: &str,locale: &Locale) -> ParseResult<(NaiveDate, Option<NaiveTime>)> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    skip_separators(str,&mut position,locale);
+    let mut weekday: Option<(u32, usize, usize)> = None;
+    if position < str.len() && str[position].is_alphabetic() {
+        if let Some((index,length)) = match_weekday(str,position,locale) {
+            weekday = Some((index as u32, position, length));
+            position = position + length;
+            let _ = parse_is_token_or_end(str,&mut position,".")?;
+            skip_separators(str,&mut position,locale);
+        }
+    }
+    let day;
+    let month;
+    if position < str.len() && str[position].is_ascii_digit() {
+        day = parse_day(str,&mut position,locale)?;
+        skip_separators(str,&mut position,locale);
+        month = parse_month_name(str,&mut position,locale)?;
+    } else {
+        month = parse_month_name(str,&mut position,locale)?;
+        skip_separators(str,&mut position,locale);
+        day = parse_day(str,&mut position,locale)?;
+    }
+    skip_separators(str,&mut position,locale);
+    let year_position = position;
+    let year = parse_full_year(str,&mut position)?;
+    if position < str.len() && str[position].is_ascii_digit() {
+        return Err(ParseError::invalid(ParseErrorKind::InvalidYear,year_position,5));
+    }
+    let date = match NaiveDate::from_ymd_opt(year,month,day) {
+        Some(date) => date,
+        None => return Err(ParseError::invalid(ParseErrorKind::InvalidDate,0,position)),
+    };
+    if let Some((weekday,weekday_position,length)) = weekday {
+        if date.weekday().num_days_from_monday() != weekday {
+            return Err(ParseError::invalid(ParseErrorKind::InvalidDate,weekday_position,length));
+        }
+    }
+    skip_separators(str,&mut position,locale);
+    if position == str.len() {
+        return Ok((date, None));
+    }
+    let time = parse_time(str,&mut position,locale)?;
+    skip_separators(str,&mut position,locale);
+    let _ = parse_end_of_string(str,&position)?;
+    return Ok((date, Some(time)));
+}
+fn skip_separators(str: &Vec<char>,position: &mut usize,locale: &Locale) {
+    loop {
+        while *position < str.len() && (str[*position].is_whitespace() || str[*position] == ',') {
+            *position = *position + 1;
+        }
+        match match_name(str,*position,locale.connectors) {
+            Some((_,length)) => *position = *position + length,
+            None => return,
+        }
+    }
+}
+fn match_weekday(str: &Vec<char>,position: usize,locale: &Locale) -> Option<(usize, usize)> {
+    let full = match_name(str,position,&locale.weekdays);
+    let abbreviated = match_name(str,position,&locale.weekdays_abbreviated);
+    return match (full, abbreviated) {
+        (Some(full), Some(abbreviated)) if abbreviated.1 > full.1 => Some(abbreviated),
+        (Some(full), _) => Some(full),
+        (None, abbreviated) => abbreviated,
+    };
+}
+fn parse_month_name(str: &Vec<char>,position: &mut usize,locale: &Locale) -> ParseResult<u32> {
+    let mut result: Option<(usize, usize)> = None;
+    for names in &[&locale.months, &locale.months_genitive, &locale.months_abbreviated] {
+        if let Some((index,length)) = match_name(str,*position,*names) {
+            if result.map(|(_,result_length)| length > result_length).unwrap_or(true) {
+                result = Some((index, length));
+            }
+        }
+    }
+    return match result {
+        Some((index,length)) => {
+            *position = *position + length;
+            let _ = parse_is_token_or_end(str,position,".")?;
+            Ok(index as u32 + 1)
+        },
+        None => Err(ParseError::invalid(ParseErrorKind::InvalidMonth,position.clone(),word_length(str,*position).max(1))),
+    };
+}
+fn parse_day(str: &Vec<char>,position: &mut usize,locale: &Locale) -> ParseResult<u32> {
+    let day_position = *position;
+    let result = parse_digits(str,position,2,ParseErrorKind::InvalidDay);
+    let day = validate_range(result,1,31,&day_position,*position - day_position)?;
+    if let Some((_,length)) = match_name(str,*position,locale.day_suffixes) {
+        *position = *position + length;
+    }
+    return Ok(day);
+}
+fn parse_time(str: &Vec<char>,position: &mut usize,locale: &Locale) -> ParseResult<NaiveTime> {
+    let hour_position = *position;
+    let mut hour = parse_digits(str,position,2,ParseErrorKind::InvalidHour)?;
+    let hour_length = *position - hour_position;
+    let separator = match locale.time_separators.iter().find(|separator| {
+        let mut separator_position = *position;
+        parse_is_token(str,&mut separator_position,separator).unwrap_or(false)
+    }) {
+        Some(separator) => *separator,
+        None => return Err(ParseError::invalid_token(position.clone(),1)),
+    };
+    let _ = parse_token(str,position,separator)?;
+    let minute = parse_minute(str,position)?;
+    let mut seconds = 0;
+    if parse_is_token_or_end(str,position,":")? {
+        seconds = parse_seconds(str,position)?;
+    }
+    let marker_position = *position + str[*position..].iter().take_while(|c| c.is_whitespace()).count();
+    if let Some(am_pm) = locale.am_pm {
+        if let Some((index,length)) = match_name(str,marker_position,&am_pm) {
+            if hour == 0 || hour > 12 {
+                return Err(ParseError::invalid(ParseErrorKind::InvalidHour,hour_position,hour_length));
+            }
+            hour = hour % 12 + 12 * index as u32;
+            *position = marker_position + length;
+        }
+    }
+    if hour > 23 {
+        return Err(ParseError::invalid_high_value(hour_position,hour_length));
+    }
+    return to_time(str,hour,minute,seconds,0);
+}
+#[cfg(test)]
+#[test]
+fn test_localized() {
+    use locale::{EN,RU,DE,FR,ES,PL,CS};
+    let testdates = [
+        ("11 декабря 2011 в 01:43", &RU, Ok("2011-12-11 01:43:00")),
+        ("воскресенье, 11 дек. 2011 г. в 01:43", &RU, Ok("2011-12-11 01:43:00")),
+        ("1 Мая 2015", &RU, Ok("2015-05-01")),
+        ("май 1 2015", &RU, Ok("2015-05-01")),
+        ("пн, 11 декабря 2011", &RU, Err(ParseErrorKind::InvalidDate)),
+        ("11 декабрь2011", &RU, Err(ParseErrorKind::InvalidMonth)),
+        ("Sunday, December 11th, 2011 at 1:43 AM", &EN, Ok("2011-12-11 01:43:00")),
+        ("11 Dec 2011 12:05:09 pm", &EN, Ok("2011-12-11 12:05:09")),
+        ("Dec 11, 2011 12:05 AM", &EN, Ok("2011-12-11 00:05:00")),
+        ("Dec 11, 2011 13:05 PM", &EN, Err(ParseErrorKind::InvalidHour)),
+        ("Dec 32, 2011", &EN, Err(ParseErrorKind::InvalidHighValue)),
+        ("Feb 30, 2011", &EN, Err(ParseErrorKind::InvalidDate)),
+        ("Dec 11, 2011 1:43 tomorrow", &EN, Err(ParseErrorKind::StringNotEnded)),
+        ("Sonntag, den 11. Dezember 2011 um 01:43 Uhr", &DE, Ok("2011-12-11 01:43:00")),
+        ("11. März 2011", &DE, Ok("2011-03-11")),
+        ("dimanche 11 décembre 2011 à 01h43", &FR, Ok("2011-12-11 01:43:00")),
+        ("1er mars 2011", &FR, Ok("2011-03-01")),
+        ("11 de diciembre de 2011 a las 01:43", &ES, Ok("2011-12-11 01:43:00")),
+        ("miércoles, 1 de junio del 2011", &ES, Ok("2011-06-01")),
+        ("niedziela, 11 grudnia 2011 o 01:43", &PL, Ok("2011-12-11 01:43:00")),
+        ("1 marca 2011 r.", &PL, Ok("2011-03-01")),
+        ("11 grudzień 2011", &PL, Ok("2011-12-11")),
+        ("poniedziałek, 11 grudnia 2011", &PL, Err(ParseErrorKind::InvalidDate)),
+        ("neděle 11. prosince 2011 v 1:43", &CS, Ok("2011-12-11 01:43:00")),
+        ("11. března 2011", &CS, Ok("2011-03-11")),
+        ("1. červenec 2011", &CS, Ok("2011-07-01")),
+    ];
+    for &(date, locale, checkdate) in testdates.iter() {
+        let dt = match parse_localized(date,locale) {
+            Ok((date,Some(time))) => Ok(format!("{}", date.and_time(time))),
+            Ok((date,None)) => Ok(format!("{}", date)),
+            Err(e) => Err(e.error_kind),
+        };
+        if dt != checkdate.map(|s| s.to_string()) {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, dt, checkdate);
+        }
+    }
+    assert_eq!(parse_localized("11 Dekember 2011",&DE).unwrap_err(), ParseError::invalid(ParseErrorKind::InvalidMonth,3,8));
+    assert_eq!(Locale::builtin("ru-RU").map(|locale| locale.code), Some("ru"));
+    assert_eq!(Locale::builtin("pl-PL").map(|locale| locale.code), Some("pl"));
+    assert_eq!(Locale::builtin("cs").map(|locale| locale.months_genitive[11]), Some("prosince"));
+    assert!(Locale::builtin("uk").is_none());
+}
@@ -0,0 +1,69 @@
+use ntp::{NtpTimestamp,NtpShort};
+use super::error::*;
+/// Parses `length` hexadecimal digits.
+fn parse_hex(str: &Vec<char>,position: &mut usize,length: usize) -> ParseResult<u32> {
+    let mut value = 0;
+    for index in *position..*position + length {
+        match str.get(index).and_then(|c| c.to_digit(16)) {
+            Some(digit) => value = value << 4 | digit,
+            None => return Err(ParseError::invalid_format(index,1)),
+        }
+    }
+    *position = *position + length;
+    return Ok(value);
+}
+/// Parses hexadecimal seconds and fraction separated by dot, each of `length` digits.
+fn parse_hex_pair(text: &str,length: usize) -> ParseResult<(u32, u32)> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let seconds = parse_hex(str,&mut position,length)?;
+    if position >= str.len() || str[position] != '.' {
+        return Err(ParseError::invalid_token(position,1));
+    }
+    position = position + 1;
+    let fraction = parse_hex(str,&mut position,length)?;
+    if position < str.len() {
+        return Err(ParseError::invalid_token(position,str.len() - position));
+    }
+    return Ok((seconds, fraction));
+}
+/// Parses NTP timestamp of hexadecimal seconds and fraction such as `d85a1ad8.80000000`.
+pub fn parse_ntp(text: &str) -> ParseResult<NtpTimestamp> {
+    let (seconds, fraction) = parse_hex_pair(text,8)?;
+    return Ok(NtpTimestamp {
+        seconds: seconds,
+        fraction: fraction,
+    });
+}
+/// Parses NTP short format of hexadecimal seconds and fraction such as `0001.8000`.
+pub fn parse_ntp_short(text: &str) -> ParseResult<NtpShort> {
+    let (seconds, fraction) = parse_hex_pair(text,4)?;
+    return Ok(NtpShort {
+        seconds: seconds as u16,
+        fraction: fraction as u16,
+    });
+}
+#[cfg(test)]
+#[test]
+fn test_parse_ntp() {
+    use formatter::{format_ntp,format_ntp_short};
+    assert_eq!(parse_ntp("d86980d8.80000000"), Ok(NtpTimestamp { seconds: 0xd86980d8, fraction: 0x80000000 }));
+    assert_eq!(parse_ntp("D86980D8.80000000").map(|timestamp| format_ntp(&timestamp)), Ok("d86980d8.80000000".to_string()));
+    assert_eq!(parse_ntp_short("0001.8000"), Ok(NtpShort { seconds: 1, fraction: 0x8000 }));
+    assert_eq!(parse_ntp_short("ffff.ffff").map(|short| format_ntp_short(&short)), Ok("ffff.ffff".to_string()));
+    let testerrors = [
+        ("0001.800", ParseError::invalid_format(8,1)),
+        ("0001.80000", ParseError::invalid_token(9,1)),
+        ("00001.8000", ParseError::invalid_token(4,1)),
+        ("000g.8000", ParseError::invalid_format(3,1)),
+        ("0001-8000", ParseError::invalid_token(4,1)),
+        ("", ParseError::invalid_format(0,1)),
+    ];
+    for &(text, ref error) in testerrors.iter() {
+        let result = parse_ntp_short(text);
+        if result.as_ref().err() != Some(error) {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}", text, result, error);
+        }
+    }
+}
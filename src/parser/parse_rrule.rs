@@ -0,0 +1,257 @@
+use recurrence::{Frequency,RecurrenceRule,RecurrenceSet,weekday_from_name};
+use super::parse_icalendar::{parse_ical_datetime,ICalDateTime};
+use super::error::*;
+/// Returns error with positions moved by `offset`.
+fn shift_error(error: ParseError,offset: usize) -> ParseError {
+    return ParseError {
+        error_kind: error.error_kind,
+        position_begin: error.position_begin + offset,
+        position_end: error.position_end + offset,
+    };
+}
+/// Splits text by separator and returns parts with their positions in chars.
+fn split_with_positions(str: &[char],begin: usize,end: usize,separator: char) -> Vec<(usize, String)> {
+    let mut parts = Vec::new();
+    let mut part_begin = begin;
+    for position in begin..end + 1 {
+        if position == end || str[position] == separator {
+            parts.push((part_begin, str[part_begin..position].iter().collect()));
+            part_begin = position + 1;
+        }
+    }
+    return parts;
+}
+fn parse_number(text: &str,position: usize,min: i32,max: i32,is_signed: bool) -> ParseResult<i32> {
+    let length = text.chars().count();
+    let digits = if is_signed { text.trim_start_matches(&['+','-'][..]) } else { text };
+    if digits.is_empty() || digits.len() > 3 || !digits.chars().all(|c| c.is_ascii_digit()) || length - digits.len() > 1 {
+        return Err(ParseError::invalid_format(position,length));
+    }
+    let value = digits.parse::<i32>().unwrap_or(0);
+    let value = if text.starts_with('-') { -value } else { value };
+    if is_signed && value == 0 {
+        return Err(ParseError::invalid_format(position,length));
+    }
+    if value.abs() < min || (!is_signed && value < min) {
+        return Err(ParseError::invalid_low_value(position,length));
+    }
+    if value.abs() > max {
+        return Err(ParseError::invalid_high_value(position,length));
+    }
+    return Ok(value);
+}
+/// Parses positive integer of any length, such as `INTERVAL` and `COUNT` values.
+fn parse_positive(text: &str,position: usize) -> ParseResult<u32> {
+    let length = text.chars().count();
+    if text.is_empty() || !text.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ParseError::invalid_format(position,length));
+    }
+    return match text.parse::<u32>() {
+        Ok(0) => Err(ParseError::invalid_low_value(position,length)),
+        Ok(value) => Ok(value),
+        Err(_) => Err(ParseError::invalid(ParseErrorKind::Overflow,position,length)),
+    };
+}
+fn parse_numbers(str: &[char],position: usize,end: usize,min: i32,max: i32,is_signed: bool) -> ParseResult<Vec<i32>> {
+    let mut values = Vec::new();
+    for (position,text) in split_with_positions(str,position,end,',') {
+        values.push(parse_number(&text,position,min,max,is_signed)?);
+    }
+    return Ok(values);
+}
+/// Parses an iCalendar (RFC 5545) recurrence rule such as `FREQ=MONTHLY;BYDAY=-1FR;COUNT=10`.
+///
+/// Optional `RRULE:` prefix is skipped. Returns `InvalidToken` error for unknown parts and names,
+/// `InvalidFormat` error for missing `FREQ`, repeated parts and both `COUNT` and `UNTIL`.
+pub fn parse_rrule(text: &str) -> ParseResult<RecurrenceRule> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let begin = if text.starts_with("RRULE:") { 6 } else { 0 };
+    let mut rule = RecurrenceRule::new(Frequency::Yearly);
+    let mut names: Vec<String> = Vec::new();
+    for (position,part) in split_with_positions(str,begin,str.len(),';') {
+        let (name, value) = match part.find('=') {
+            Some(index) => (part[..index].to_string(), part[index + 1..].to_string()),
+            None => return Err(ParseError::invalid_token(position,part.chars().count())),
+        };
+        if names.contains(&name) {
+            return Err(ParseError::invalid_format(position,part.chars().count()));
+        }
+        let value_position = position + name.chars().count() + 1;
+        let value_end = position + part.chars().count();
+        let value_length = value_end - value_position;
+        let to_u32 = |values: Vec<i32>| values.into_iter().map(|value| value as u32).collect();
+        match name.as_str() {
+            "FREQ" => {
+                rule.frequency = match Frequency::from_name(&value) {
+                    Some(frequency) => frequency,
+                    None => return Err(ParseError::invalid_token(value_position,value_length)),
+                };
+            },
+            "INTERVAL" => rule.interval = parse_positive(&value,value_position)?,
+            "COUNT" => rule.count = Some(parse_positive(&value,value_position)?),
+            "UNTIL" => {
+                let until = parse_ical_datetime(&value).map_err(|error| shift_error(error,value_position))?;
+                if let ICalDateTime::Zoned(_,_) = until {
+                    return Err(ParseError::invalid_token(value_position,value_length));
+                }
+                rule.until = Some(until);
+            },
+            "BYSECOND" => rule.by_second = to_u32(parse_numbers(str,value_position,value_end,0,60,false)?),
+            "BYMINUTE" => rule.by_minute = to_u32(parse_numbers(str,value_position,value_end,0,59,false)?),
+            "BYHOUR" => rule.by_hour = to_u32(parse_numbers(str,value_position,value_end,0,23,false)?),
+            "BYDAY" => {
+                let mut by_day = Vec::new();
+                for (position,text) in split_with_positions(str,value_position,value_end,',') {
+                    let text: Vec<char> = text.chars().collect();
+                    let length = text.len();
+                    let name_position = length.saturating_sub(2);
+                    let name: String = text[name_position..].iter().collect();
+                    let weekday = match weekday_from_name(&name) {
+                        Some(weekday) => weekday,
+                        None => return Err(ParseError::invalid_token(position,length)),
+                    };
+                    let mut index = 0;
+                    if name_position > 0 {
+                        let index_text: String = text[..name_position].iter().collect();
+                        index = parse_number(&index_text,position,1,53,true)?;
+                    }
+                    by_day.push((index, weekday));
+                }
+                rule.by_day = by_day;
+            },
+            "BYMONTHDAY" => rule.by_month_day = parse_numbers(str,value_position,value_end,1,31,true)?,
+            "BYYEARDAY" => rule.by_year_day = parse_numbers(str,value_position,value_end,1,366,true)?,
+            "BYWEEKNO" => rule.by_week_no = parse_numbers(str,value_position,value_end,1,53,true)?,
+            "BYMONTH" => rule.by_month = to_u32(parse_numbers(str,value_position,value_end,1,12,false)?),
+            "BYSETPOS" => rule.by_set_pos = parse_numbers(str,value_position,value_end,1,366,true)?,
+            "WKST" => {
+                rule.week_start = match weekday_from_name(&value) {
+                    Some(weekday) => weekday,
+                    None => return Err(ParseError::invalid_token(value_position,value_length)),
+                };
+            },
+            _ => return Err(ParseError::invalid_token(position,name.chars().count())),
+        }
+        names.push(name);
+    }
+    if !names.iter().any(|name| name == "FREQ") || (rule.count.is_some() && rule.until.is_some()) {
+        return Err(ParseError::invalid_format(0,str.len()));
+    }
+    return Ok(rule);
+}
+/// Parses iCalendar recurrence set: lines of `DTSTART`, `RRULE`, `RDATE` and `EXDATE` properties such as
+///
+/// ```text
+/// DTSTART;TZID=America/New_York:19970902T090000
+/// RRULE:FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13
+/// EXDATE;TZID=America/New_York:19970902T090000
+/// ```
+///
+/// Lines must be unfolded. `RDATE` and `EXDATE` values may be lists separated by commas;
+/// `PERIOD` values of `RDATE` are not supported. Parameters other than `TZID` are ignored.
+pub fn parse_recurrence_set(text: &str) -> ParseResult<RecurrenceSet> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut dtstart: Option<ICalDateTime> = None;
+    let mut rules = Vec::new();
+    let mut rdates = Vec::new();
+    let mut exdates = Vec::new();
+    for (position,line) in split_with_positions(str,0,str.len(),'\n') {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            continue;
+        }
+        let colon = match line.find(':') {
+            Some(colon) => colon,
+            None => return Err(ParseError::invalid_token(position,line.chars().count())),
+        };
+        let name_end = line[..colon].find(';').unwrap_or(colon);
+        let name = &line[..name_end];
+        let value = &line[colon + 1..];
+        let value_position = position + line[..colon + 1].chars().count();
+        let mut prefix = String::new();
+        for parameter in line[name_end..colon].split(';') {
+            if parameter.starts_with("TZID=") {
+                prefix = format!("{}:",parameter);
+            }
+        }
+        let parse_values = |values: &mut Vec<ICalDateTime>| -> ParseResult<()> {
+            let value_chars: Vec<char> = value.chars().collect();
+            for (item_position,item) in split_with_positions(&value_chars,0,value_chars.len(),',') {
+                let offset = (value_position + item_position).saturating_sub(prefix.chars().count());
+                values.push(parse_ical_datetime(&format!("{}{}",prefix,item)).map_err(|error| shift_error(error,offset))?);
+            }
+            return Ok(());
+        };
+        match name {
+            "DTSTART" if dtstart.is_none() => {
+                let mut values = Vec::new();
+                parse_values(&mut values)?;
+                if values.len() != 1 {
+                    return Err(ParseError::invalid_format(value_position,value.chars().count()));
+                }
+                dtstart = values.pop();
+            },
+            "RRULE" => rules.push(parse_rrule(value).map_err(|error| shift_error(error,value_position))?),
+            "RDATE" => parse_values(&mut rdates)?,
+            "EXDATE" => parse_values(&mut exdates)?,
+            _ => return Err(ParseError::invalid_token(position,name.chars().count())),
+        }
+    }
+    return match dtstart {
+        Some(dtstart) => Ok(RecurrenceSet {
+            dtstart: dtstart,
+            rules: rules,
+            rdates: rdates,
+            exdates: exdates,
+        }),
+        None => Err(ParseError::invalid_format(0,str.len())),
+    };
+}
+#[cfg(test)]
+#[test]
+fn test_rrule() {
+    use formatter::format_rrule;
+    let testrules = [
+        ("FREQ=DAILY;COUNT=10", Ok("FREQ=DAILY;COUNT=10")),
+        ("RRULE:FREQ=WEEKLY;UNTIL=19971007T000000Z;WKST=SU;BYDAY=TU,TH", Ok("FREQ=WEEKLY;UNTIL=19971007T000000Z;BYDAY=TU,TH;WKST=SU")),
+        ("FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU", Ok("FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU")),
+        ("FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO", Ok("FREQ=YEARLY;BYDAY=MO;BYWEEKNO=20")),
+        ("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-2", Ok("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-2")),
+        ("FREQ=YEARLY;UNTIL=20000131;BYMONTH=1", Ok("FREQ=YEARLY;UNTIL=20000131;BYMONTH=1")),
+        ("COUNT=10", Err(ParseErrorKind::InvalidFormat)),
+        ("FREQ=DAILY;COUNT=10;UNTIL=19971224T000000Z", Err(ParseErrorKind::InvalidFormat)),
+        ("FREQ=DAILY;FREQ=WEEKLY", Err(ParseErrorKind::InvalidFormat)),
+        ("FREQ=FORTNIGHTLY", Err(ParseErrorKind::InvalidToken)),
+        ("FREQ=DAILY;BYFOO=1", Err(ParseErrorKind::InvalidToken)),
+        ("FREQ=MONTHLY;BYMONTHDAY=0", Err(ParseErrorKind::InvalidFormat)),
+        ("FREQ=MONTHLY;BYMONTHDAY=32", Err(ParseErrorKind::InvalidHighValue)),
+        ("FREQ=MONTHLY;BYDAY=1XX", Err(ParseErrorKind::InvalidToken)),
+        ("FREQ=DAILY;INTERVAL=0", Err(ParseErrorKind::InvalidLowValue)),
+        ("FREQ=DAILY;COUNT=1000", Ok("FREQ=DAILY;COUNT=1000")),
+        ("FREQ=MINUTELY;INTERVAL=1440", Ok("FREQ=MINUTELY;INTERVAL=1440")),
+        ("FREQ=DAILY;COUNT=4294967296", Err(ParseErrorKind::Overflow)),
+        ("FREQ=DAILY;COUNT=+5", Err(ParseErrorKind::InvalidFormat)),
+        ("FREQ=DAILY;BYDAY=€", Err(ParseErrorKind::InvalidToken)),
+        ("FREQ=DAILY;BYDAY=1€MO", Err(ParseErrorKind::InvalidFormat)),
+    ];
+    for &(rule, checkrule) in testrules.iter() {
+        let result = match parse_rrule(rule) {
+            Ok(result) => Ok(format_rrule(&result)),
+            Err(e) => Err(e.error_kind),
+        };
+        if result != checkrule.map(|s| s.to_string()) {
+            panic!("Rule conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   rule, result, checkrule);
+        }
+    }
+    assert_eq!(parse_rrule("FREQ=MONTHLY;BYMONTHDAY=1,40").unwrap_err(), ParseError::invalid_high_value(26,2));
+    let set = parse_recurrence_set("DTSTART;TZID=America/New_York:19970902T090000\r\nRRULE:FREQ=DAILY;COUNT=10\r\nEXDATE;TZID=America/New_York:19970903T090000,19970904T090000\r\n").unwrap();
+    assert_eq!(set.dtstart.tzid(), Some("America/New_York"));
+    assert_eq!(set.rules.len(), 1);
+    assert_eq!(set.exdates.len(), 2);
+    assert_eq!(parse_recurrence_set("DTSTART:19970902T090000\nRRULE:FREQ=DAILY;COUNT=x").unwrap_err(), ParseError::invalid_format(47,1));
+    assert_eq!(parse_recurrence_set("RRULE:FREQ=DAILY").unwrap_err().error_kind, ParseErrorKind::InvalidFormat);
+    assert_eq!(parse_recurrence_set("DTSTART:19970902T090000\nEXRULE:FREQ=DAILY").unwrap_err(), ParseError::invalid_token(24,6));
+}
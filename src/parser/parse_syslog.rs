@@ -0,0 +1,169 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::NaiveDateTime;
+use chrono::Datelike;
+use chrono::Duration;
+use super::helper::*;
+use super::error::*;
+/// Parses a BSD syslog (RFC 3164) timestamp such as `Oct 11 22:14:15` in the given year.
+///
+/// Valid formats: `Mmm dd hh:mm:ss`, `Mmm  d hh:mm:ss`, where `Mmm` is English month abbreviation.
+///
+/// Timestamp has no time zone, so local date and time of the sender is returned.
+pub fn parse_rfc3164_timestamp_with_year(text: &str,year: i32) -> ParseResult<NaiveDateTime> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let (month, day, time) = parse_rfc3164_parts(str)?;
+    if let Some(date) = NaiveDate::from_ymd_opt(year,month,day) {
+        return Ok(date.and_time(time));
+    }
+    return Err(ParseError::invalid(ParseErrorKind::InvalidDate,0,6));
+}
+/// Parses a BSD syslog (RFC 3164) timestamp such as `Oct 11 22:14:15` inferring the year.
+///
+/// Year is chosen from the year of `reference` (usually time of receiving the message),
+/// the year before and the year after, so the result is nearest to `reference`.
+/// So `Dec 31 23:59:59` received at `Jan 1` is dated by the previous year.
+pub fn parse_rfc3164_timestamp(text: &str,reference: &NaiveDateTime) -> ParseResult<NaiveDateTime> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let (month, day, time) = parse_rfc3164_parts(str)?;
+    let mut result: Option<NaiveDateTime> = None;
+    for year in &[reference.year() - 1, reference.year(), reference.year() + 1] {
+        if let Some(date) = NaiveDate::from_ymd_opt(*year,month,day) {
+            let candidate = date.and_time(time);
+            let distance = candidate.signed_duration_since(*reference).num_seconds().abs();
+            let is_nearer = match result {
+                Some(result) => distance < result.signed_duration_since(*reference).num_seconds().abs(),
+                None => true,
+            };
+            if is_nearer {
+                result = Some(candidate);
+            }
+        }
+    }
+    return match result {
+        Some(result) => Ok(result),
+        None => Err(ParseError::invalid(ParseErrorKind::InvalidDate,0,6)),
+    };
+}
+fn parse_rfc3164_parts(str: &Vec<char>) -> ParseResult<(u32, u32, NaiveTime)> {
+    let mut position = 0;
+    let month = parse_month_abbreviation(str,&mut position)?;
+    let _ = parse_token(str,&mut position," ")?;
+    let day;
+    if parse_is_token(str,&mut position," ")? {
+        let result = parse_u32(str,&mut position,1,ParseErrorKind::InvalidDay);
+        day = validate_range(result,1,9,&position,1)?;
+    } else {
+        day = parse_day_number(str,&mut position)?;
+    }
+    let _ = parse_token(str,&mut position," ")?;
+    let hour = parse_hour_24(str,&mut position)?;
+    let _ = parse_token(str,&mut position,":")?;
+    let minute = parse_minute(str,&mut position)?;
+    let _ = parse_token(str,&mut position,":")?;
+    let seconds = parse_seconds(str,&mut position)?;
+    let _ = parse_end_of_string(str,&position)?;
+    return Ok((month, day, to_time(str,hour,minute,seconds,0)?));
+}
+/// Parses a syslog (RFC 5424) timestamp such as `2003-10-11T22:14:15.003Z`.
+///
+/// Valid formats: `-` (NILVALUE, returns `None`),
+/// `YYYY-MM-DDThh:mm:ssTZD`,
+/// `YYYY-MM-DDThh:mm:ss.sTZD` with up to 6 digits of fraction of second.
+pub fn parse_rfc5424_timestamp(text: &str) -> ParseResult<Option<DateTime<FixedOffset>>> {
+    if text == "-" {
+        return Ok(None);
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let year = parse_full_year(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"-")?;
+    let month = parse_month_number(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"-")?;
+    let day = parse_day_number(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"T")?;
+    let hour = parse_hour_24(str,&mut position)?;
+    let _ = parse_token(str,&mut position,":")?;
+    let minute = parse_minute(str,&mut position)?;
+    let _ = parse_token(str,&mut position,":")?;
+    let seconds = parse_seconds(str,&mut position)?;
+    let mut nanosecond = 0;
+    if parse_is_token(str,&mut position,".")? {
+        let fraction_position = position;
+        nanosecond = parse_nanosecond(str,&mut position)?;
+        if position - fraction_position > 6 {
+            return Err(ParseError::invalid(ParseErrorKind::InvalidNanoseconds,fraction_position,position - fraction_position));
+        }
+    }
+    let offset = parse_tzd(str,&mut position)?;
+    let _ = parse_end_of_string(str,&position)?;
+    if let Some(date) = NaiveDate::from_ymd_opt(year,month,day) {
+        let naive_date_time = date.and_time(to_time(str,hour,minute,seconds,nanosecond)?);
+        if let Some(naive_date_time) = naive_date_time.checked_sub_signed(Duration::seconds(offset.local_minus_utc() as i64)) {
+            return Ok(Some(DateTime::from_utc(naive_date_time, offset)));
+        }
+    }
+    return Err(ParseError::invalid_format(0,str.len()));
+}
+#[cfg(test)]
+#[test]
+fn test_rfc3164() {
+    let reference = NaiveDate::from_ymd(2016, 1, 1).and_hms(0, 0, 5);
+    let testdates = [
+        ("Oct 11 22:14:15", Ok("2015-10-11 22:14:15")),
+        ("Dec 31 23:59:59", Ok("2015-12-31 23:59:59")),
+        ("Jan  1 00:00:10", Ok("2016-01-01 00:00:10")),
+        ("Feb 29 12:00:00", Ok("2016-02-29 12:00:00")),
+        ("Jan 01 00:00:10", Ok("2016-01-01 00:00:10")),
+        ("Feb 30 12:00:00", Err(ParseErrorKind::InvalidDate)),
+        ("Foo 11 22:14:15", Err(ParseErrorKind::InvalidMonth)),
+        ("Oct 1 22:14:15", Err(ParseErrorKind::InvalidDay)),
+        ("Oct  0 22:14:15", Err(ParseErrorKind::InvalidLowValue)),
+        ("Oct 11 22:14", Err(ParseErrorKind::InvalidToken)),
+        ("Oct 11 22:14:15 host", Err(ParseErrorKind::StringNotEnded)),
+    ];
+    for &(date, checkdate) in testdates.iter() {
+        let dt = match parse_rfc3164_timestamp(date,&reference) {
+            Ok(dt) => Ok(format!("{}", dt)),
+            Err(e) => Err(e.error_kind),
+        };
+        if dt != checkdate.map(|s| s.to_string()) {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, dt, checkdate);
+        }
+    }
+    assert_eq!(format!("{}", parse_rfc3164_timestamp_with_year("Dec 31 23:59:59",2016).unwrap()), "2016-12-31 23:59:59");
+    assert_eq!(parse_rfc3164_timestamp_with_year("Feb 29 12:00:00",2015).unwrap_err().error_kind, ParseErrorKind::InvalidDate);
+    assert_eq!(parse_rfc3164_timestamp("Oct 11 22:14:1x",&reference).unwrap_err(), ParseError::invalid(ParseErrorKind::InvalidSeconds,13,2));
+}
+#[cfg(test)]
+#[test]
+fn test_rfc5424() {
+    use formatter::format_w3c;
+    let testdates = [
+        ("1985-04-12T23:20:50.52Z", Ok(Some("1985-04-12T23:20:50.520Z"))),
+        ("1985-04-12T19:20:50.52-04:00", Ok(Some("1985-04-12T19:20:50.520-04:00"))),
+        ("2003-10-11T22:14:15.003Z", Ok(Some("2003-10-11T22:14:15.003Z"))),
+        ("2003-08-24T05:14:15.000003-07:00", Ok(Some("2003-08-24T05:14:15.000003-07:00"))),
+        ("-", Ok(None)),
+        ("2003-08-24T05:14:15.000000003-07:00", Err(ParseErrorKind::InvalidNanoseconds)),
+        ("2003-08-24T05:14-07:00", Err(ParseErrorKind::InvalidToken)),
+        ("2003-08-24T05:14:15", Err(ParseErrorKind::InvalidToken)),
+        ("--", Err(ParseErrorKind::InvalidYear)),
+    ];
+    for &(date, checkdate) in testdates.iter() {
+        let dt = match parse_rfc5424_timestamp(date) {
+            Ok(dt) => Ok(dt.map(|dt| format_w3c(&dt))),
+            Err(e) => Err(e.error_kind),
+        };
+        if dt != checkdate.map(|s| s.map(|s| s.to_string())) {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, dt, checkdate);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::NaiveDateTime;
+use time_scale::TimeScale;
+use super::helper::*;
+use super::error::*;
+/// Parses date and time with time scale abbreviation such as `2015-01-20T17:35:20 TAI`.
+///
+/// Valid formats: `YYYY-MM-DDThh:mm SCALE`,
+/// `YYYY-MM-DDThh:mm:ss SCALE`,
+/// `YYYY-MM-DDThh:mm:ss.s SCALE`
+///
+/// where `SCALE` is one of `UTC`, `TAI`, `GPS`, `GST`, `BDT`.
+pub fn parse_time_scale_datetime(text: &str) -> ParseResult<(NaiveDateTime, TimeScale)> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let year = parse_full_year(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"-")?;
+    let month = parse_month_number(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"-")?;
+    let day = parse_day_number(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"T")?;
+    let hour = parse_hour_24(str,&mut position)?;
+    let _ = parse_token(str,&mut position,":")?;
+    let minute = parse_minute(str,&mut position)?;
+    let mut seconds = 0;
+    let mut nanosecond = 0;
+    if parse_is_token(str,&mut position,":")? {
+        seconds = parse_seconds(str,&mut position)?;
+        if parse_is_token(str,&mut position,".")? {
+            nanosecond = parse_nanosecond(str,&mut position)?;
+        }
+    }
+    let _ = parse_token(str,&mut position," ")?;
+    let scale_position = position;
+    let name: String = str[position..].iter().cloned().collect();
+    let scale = match TimeScale::from_name(&name) {
+        Some(scale) => scale,
+        None => return Err(ParseError::invalid_token(scale_position,str.len() - scale_position)),
+    };
+    if let Some(date) = NaiveDate::from_ymd_opt(year,month,day) {
+        if let Some(time) = NaiveTime::from_hms_nano_opt(hour, minute, seconds, nanosecond) {
+            return Ok((date.and_time(time), scale));
+        }
+    }
+    return Err(ParseError::invalid_format(0,str.len()));
+}
+#[cfg(test)]
+#[test]
+fn test_time_scale() {
+    use formatter::format_time_scale;
+    let testdates = [
+        ("2015-01-20T17:35:20 TAI", Ok("2015-01-20T17:35:20 TAI")),
+        ("2015-01-20T17:35 GPS", Ok("2015-01-20T17:35:00 GPS")),
+        ("2015-01-20T17:35:20.001 GST", Ok("2015-01-20T17:35:20.001 GST")),
+        ("2015-01-20T17:35:20 BDT", Ok("2015-01-20T17:35:20 BDT")),
+        ("2015-01-20T17:35:20 UTC", Ok("2015-01-20T17:35:20 UTC")),
+        ("2015-01-20T17:35:20 XYZ", Err(ParseErrorKind::InvalidToken)),
+        ("2015-01-20T17:35:20TAI", Err(ParseErrorKind::InvalidToken)),
+        ("2015-01-20T17:35:20 TAIs", Err(ParseErrorKind::InvalidToken)),
+        ("2015-02-30T17:35:20 TAI", Err(ParseErrorKind::InvalidFormat)),
+        ("2015-01-20 TAI", Err(ParseErrorKind::InvalidToken)),
+    ];
+    for &(date, checkdate) in testdates.iter() {
+        let dt = match parse_time_scale_datetime(date) {
+            Ok((datetime, scale)) => Ok(format_time_scale(&datetime, scale)),
+            Err(e) => Err(e.error_kind),
+        };
+        if dt != checkdate.map(|s| s.to_string()) {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, dt, checkdate);
+        }
+    }
+}
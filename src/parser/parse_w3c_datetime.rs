@@ -2,7 +2,10 @@ use chrono::DateTime;
 use chrono::FixedOffset;
 use chrono::NaiveDate;
 use chrono::NaiveTime;
+use chrono::NaiveDateTime;
 use chrono::Duration;
+use chrono::Timelike;
+use leap_seconds::LeapSecondTable;
 use super::helper::*;
 use super::error::*;
 /// Parses an W3C date and time string then returns a new `DateTime` with a parsed `FixedOffset`.
@@ -17,7 +20,23 @@ use super::error::*;
 /// Invalid formats: 
 /// `YYYY`,
 /// `YYYY-MM`
+///
+/// Date without time is treated as midnight UTC. Use `parse_w3c_naive`
+/// to distinguish zone-less input from UTC.
 pub fn parse_w3c_datetime(text: &str) ->  ParseResult<DateTime<FixedOffset>> {
+    return parse_w3c(text,None);
+}
+/// Parses an W3C date and time string allowing leap second `23:59:60` (UTC).
+///
+/// Leap second is accepted only if it is listed in `leap_seconds` table and
+/// is represented with nanosecond value of 1 000 000 000 or greater.
+///
+/// Valid formats are the same as for `parse_w3c_datetime`.
+pub fn parse_w3c_datetime_leap(text: &str,leap_seconds: &LeapSecondTable) ->  ParseResult<DateTime<FixedOffset>> {
+    return parse_w3c(text,Some(leap_seconds));
+}
+#[allow(deprecated)]
+fn parse_w3c(text: &str,leap_seconds: Option<&LeapSecondTable>) ->  ParseResult<DateTime<FixedOffset>> {
     // https://www.w3.org/TR/NOTE-datetime
     // Year:
     //   YYYY (eg 1997)
@@ -53,13 +72,19 @@ pub fn parse_w3c_datetime(text: &str) ->  ParseResult<DateTime<FixedOffset>> {
     let mut minute = 0;
     let mut seconds = 0;
     let mut nanosecond = 0;
+    let mut seconds_position = 0;
     let mut offset = FixedOffset::east(0);
     if try!(parse_token_or_end(str,&mut position,"T")) {
         hour = try!(parse_hour_24(str,&mut position));
         let _ = try!(parse_token(str,&mut position,":"));
         minute = try!(parse_minute(str,&mut position));
         if try!(parse_is_token(str,&mut position,":")) {
-            seconds = try!(parse_seconds(str,&mut position));
+            seconds_position = position;
+            if leap_seconds.is_some() {
+                seconds = try!(parse_seconds_leap(str,&mut position));
+            } else {
+                seconds = try!(parse_seconds(str,&mut position));
+            }
             if try!(parse_is_token(str,&mut position,".")) {
                 nanosecond = try!(parse_nanosecond(str,&mut position));
             }
@@ -69,6 +94,14 @@ pub fn parse_w3c_datetime(text: &str) ->  ParseResult<DateTime<FixedOffset>> {
         }        
     }
     let _ = try!(parse_end_of_string(str,&position));
+    if let Some(leap_seconds) = leap_seconds {
+        if seconds == 60 {
+            if let Some(date) = NaiveDate::from_ymd_opt(year,month,day) {
+                return parse_leap_second(leap_seconds,date.and_time(to_time(str,hour,minute,59,0)?),nanosecond,offset,seconds_position);
+            }
+            return Err(ParseError::invalid_format(0,str.len()));
+        }
+    }
     if let Some(date) = NaiveDate::from_ymd_opt(year,month,day) {
         if let Some(time) = NaiveTime::from_hms_nano_opt(hour, minute, seconds, nanosecond) {
             let naive_date_time = date.and_time(time);
@@ -79,6 +112,17 @@ pub fn parse_w3c_datetime(text: &str) ->  ParseResult<DateTime<FixedOffset>> {
     }
     return Err(ParseError::invalid_format(0,str.len()));
 }
+fn parse_leap_second(leap_seconds: &LeapSecondTable,second_before: NaiveDateTime,nanosecond: u32,offset: FixedOffset,position: usize) -> ParseResult<DateTime<FixedOffset>> {
+    // Leap second can not be shifted by offset with chrono arithmetics, so 59th second is shifted instead.
+    if let Some(utc) = second_before.checked_sub_signed(Duration::seconds(offset.local_minus_utc() as i64)) {
+        if utc.hour() == 23 && utc.minute() == 59 && leap_seconds.is_leap_second_day(utc.date()) {
+            if let Some(utc) = utc.with_nanosecond(1_000_000_000 + nanosecond) {
+                return Ok(DateTime::from_utc(utc, offset));
+            }
+        }
+    }
+    return Err(ParseError::invalid(ParseErrorKind::InvalidTime,position,2));
+}
 #[cfg(test)]
 #[test]
 fn test_w3c() {
@@ -149,3 +193,32 @@ fn test_w3c_invalid() {
     assert!(parse_w3c_datetime("11 декабря 2011 в 01:43").is_err());
     assert!(parse_w3c_datetime("2015-03-04T15:34:45.008+05:0011 декабря 2011 в 01:43").is_err());
 }
+
+#[cfg(test)]
+#[test]
+fn test_w3c_leap_second() {
+    use formatter::format_w3c;
+    let table = LeapSecondTable::builtin();
+    let testdates = [
+        ("2016-12-31T23:59:60Z", Ok("2016-12-31T23:59:60Z")),
+        ("2016-12-31T23:59:60.25Z", Ok("2016-12-31T23:59:60.250Z")),
+        ("2016-12-31T15:59:60-08:00", Ok("2016-12-31T15:59:60-08:00")),
+        ("2015-07-01T02:59:60+03:00", Ok("2015-07-01T02:59:60+03:00")),
+        ("2016-12-31T23:59:59Z", Ok("2016-12-31T23:59:59Z")),
+        ("2015-12-31T23:59:60Z", Err(ParseErrorKind::InvalidTime)),
+        ("2016-12-31T23:58:60Z", Err(ParseErrorKind::InvalidTime)),
+        ("2016-12-31T23:59:60+01:00", Err(ParseErrorKind::InvalidTime)),
+        ("2016-12-31T23:59:61Z", Err(ParseErrorKind::InvalidHighValue)),
+    ];
+    for &(date, checkdate) in testdates.iter() {
+        let dt = match parse_w3c_datetime_leap(date,&table) {
+            Ok(dt) => Ok(format_w3c(&dt)),
+            Err(e) => Err(e.error_kind),
+        };
+        if dt != checkdate.map(|s| s.to_string()) {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, dt, checkdate);
+        }
+    }
+    assert!(parse_w3c_datetime("2016-12-31T23:59:60Z").is_err());
+}
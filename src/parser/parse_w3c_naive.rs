@@ -0,0 +1,141 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::NaiveDateTime;
+use chrono::Duration;
+use super::helper::*;
+use super::error::*;
+/// W3C date and time that keeps information about presence of time zone designator.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum W3cDateTime {
+    /// Complete date without time: `YYYY-MM-DD`.
+    Date(NaiveDate),
+    /// Date and time without time zone designator: `YYYY-MM-DDThh:mm:ss`.
+    Local(NaiveDateTime),
+    /// Date and time with time zone designator: `YYYY-MM-DDThh:mm:ssTZD`.
+    Fixed(DateTime<FixedOffset>),
+}
+impl W3cDateTime {
+    /// Returns `true` if time zone designator was present.
+    pub fn has_offset(&self) -> bool {
+        return self.offset().is_some();
+    }
+    /// Returns parsed offset, if any.
+    pub fn offset(&self) -> Option<FixedOffset> {
+        match *self {
+            W3cDateTime::Fixed(datetime) => Some(datetime.timezone()),
+            _ => None,
+        }
+    }
+    /// Returns date and time as it was written, ignoring time zone designator.
+    /// Date without time is treated as midnight.
+    pub fn naive_local(&self) -> NaiveDateTime {
+        match *self {
+            W3cDateTime::Date(date) => date.and_hms_opt(0, 0, 0).unwrap(),
+            W3cDateTime::Local(datetime) => datetime,
+            W3cDateTime::Fixed(datetime) => datetime.naive_local(),
+        }
+    }
+    /// Returns `DateTime` with parsed offset or with `default` offset if time zone designator was not present.
+    pub fn with_default_offset(&self, default: FixedOffset) -> Option<DateTime<FixedOffset>> {
+        match *self {
+            W3cDateTime::Fixed(datetime) => Some(datetime),
+            _ => {
+                let naive_date_time = self.naive_local();
+                if let Some(naive_date_time) = naive_date_time.checked_sub_signed(Duration::seconds(default.local_minus_utc() as i64)) {
+                    return Some(DateTime::from_utc(naive_date_time, default));
+                }
+                return None;
+            },
+        }
+    }
+}
+/// Parses an W3C date and time string without forcing UTC for zone-less input.
+///
+/// W3C note: https://www.w3.org/TR/NOTE-datetime
+///
+/// Valid formats: `YYYY-MM-DD`,
+/// `YYYY-MM-DDThh:mm`,
+/// `YYYY-MM-DDThh:mm:ss`,
+/// `YYYY-MM-DDThh:mm:ss.s`,
+/// and each of the time formats followed by `TZD`.
+///
+/// Returns `W3cDateTime::Fixed` only if time zone designator is present.
+pub fn parse_w3c_naive(text: &str) -> ParseResult<W3cDateTime> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let year = parse_full_year(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"-")?;
+    let month = parse_month_number(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"-")?;
+    let day = parse_day_number(str,&mut position)?;
+    let date = match NaiveDate::from_ymd_opt(year,month,day) {
+        Some(date) => date,
+        None => return Err(ParseError::invalid(ParseErrorKind::InvalidDate,0,position)),
+    };
+    if !parse_token_or_end(str,&mut position,"T")? {
+        return Ok(W3cDateTime::Date(date));
+    }
+    let hour = parse_hour_24(str,&mut position)?;
+    let _ = parse_token(str,&mut position,":")?;
+    let minute = parse_minute(str,&mut position)?;
+    let mut seconds = 0;
+    let mut nanosecond = 0;
+    if parse_is_token_or_end(str,&mut position,":")? {
+        seconds = parse_seconds(str,&mut position)?;
+        if parse_is_token_or_end(str,&mut position,".")? {
+            nanosecond = parse_nanosecond(str,&mut position)?;
+        }
+    }
+    let time = match NaiveTime::from_hms_nano_opt(hour, minute, seconds, nanosecond) {
+        Some(time) => time,
+        None => return Err(ParseError::invalid(ParseErrorKind::InvalidTime,0,position)),
+    };
+    let naive_date_time = date.and_time(time);
+    if str.len() == position {
+        return Ok(W3cDateTime::Local(naive_date_time));
+    }
+    let offset = parse_tzd(str,&mut position)?;
+    let _ = parse_end_of_string(str,&position)?;
+    if let Some(utc) = naive_date_time.checked_sub_signed(Duration::seconds(offset.local_minus_utc() as i64)) {
+        return Ok(W3cDateTime::Fixed(DateTime::from_utc(utc, offset)));
+    }
+    return Err(ParseError::invalid_format(0,str.len()));
+}
+#[cfg(test)]
+#[test]
+fn test_w3c_naive() {
+    use formatter::format_w3c;
+    let testdates = [
+        ("2015-01-20", Ok("2015-01-20 00:00:00"), false),
+        ("2015-01-20T17:35", Ok("2015-01-20 17:35:00"), false),
+        ("2015-01-20T17:35:20", Ok("2015-01-20 17:35:20"), false),
+        ("2015-01-20T17:35:20.001", Ok("2015-01-20 17:35:20.001"), false),
+        ("2015-01-20T17:35:20-08:00", Ok("2015-01-20 17:35:20"), true),
+        ("2015-01-20T17:35Z", Ok("2015-01-20 17:35:00"), true),
+        ("2015-02-30", Err(ParseErrorKind::InvalidDate), false),
+        ("2015-01-20T", Err(ParseErrorKind::InvalidHour), false),
+        ("2015-01-20T17", Err(ParseErrorKind::InvalidToken), false),
+        ("2015-01-20T17:35:20.", Err(ParseErrorKind::InvalidNanoseconds), false),
+        ("2015-01-20T17:35:20s", Err(ParseErrorKind::InvalidToken), false),
+        ("2015-01-20T17:35:20Zs", Err(ParseErrorKind::StringNotEnded), false),
+    ];
+    for &(date, checkdate, has_offset) in testdates.iter() {
+        let dt = match parse_w3c_naive(date) {
+            Ok(dt) => {
+                assert_eq!(dt.has_offset(), has_offset, "{}", date);
+                Ok(format!("{}", dt.naive_local()))
+            },
+            Err(e) => Err(e.error_kind),
+        };
+        if dt != checkdate.map(|s| s.to_string()) {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, dt, checkdate);
+        }
+    }
+    let dt = parse_w3c_naive("2015-01-20T17:35:20").unwrap();
+    let dt = dt.with_default_offset(FixedOffset::east(3 * 3600)).unwrap();
+    assert_eq!(format_w3c(&dt), "2015-01-20T17:35:20+03:00");
+}
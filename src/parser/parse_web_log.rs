@@ -0,0 +1,189 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::Duration;
+use super::helper::*;
+use super::error::*;
+/// Parses an Apache Common Log Format timestamp such as `[10/Oct/2000:13:55:36 -0700]`.
+///
+/// Valid formats: `[DD/Mmm/YYYY:hh:mm:ss +hhmm]` and the same without brackets,
+/// where `Mmm` is English month abbreviation.
+pub fn parse_clf_datetime(text: &str) -> ParseResult<DateTime<FixedOffset>> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let has_brackets = parse_is_token(str,&mut position,"[")?;
+    let day = parse_day_number(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"/")?;
+    let month = parse_month_abbreviation(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"/")?;
+    let year = parse_full_year(str,&mut position)?;
+    let _ = parse_token(str,&mut position,":")?;
+    let hour = parse_hour_24(str,&mut position)?;
+    let _ = parse_token(str,&mut position,":")?;
+    let minute = parse_minute(str,&mut position)?;
+    let _ = parse_token(str,&mut position,":")?;
+    let seconds = parse_seconds(str,&mut position)?;
+    let _ = parse_token(str,&mut position," ")?;
+    let offset = parse_tzd_compact(str,&mut position)?;
+    if has_brackets {
+        let _ = parse_token(str,&mut position,"]")?;
+    }
+    let _ = parse_end_of_string(str,&position)?;
+    return to_datetime(str,year,month,day,to_time(str,hour,minute,seconds,0)?,offset);
+}
+/// Parses an nginx `$time_iso8601` timestamp such as `2000-10-10T13:55:36-07:00`.
+///
+/// Valid formats: `YYYY-MM-DDThh:mm:ssTZD`.
+pub fn parse_nginx_iso8601(text: &str) -> ParseResult<DateTime<FixedOffset>> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let mut position = 0;
+    let year = parse_full_year(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"-")?;
+    let month = parse_month_number(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"-")?;
+    let day = parse_day_number(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"T")?;
+    let hour = parse_hour_24(str,&mut position)?;
+    let _ = parse_token(str,&mut position,":")?;
+    let minute = parse_minute(str,&mut position)?;
+    let _ = parse_token(str,&mut position,":")?;
+    let seconds = parse_seconds(str,&mut position)?;
+    let offset = parse_tzd(str,&mut position)?;
+    let _ = parse_end_of_string(str,&position)?;
+    return to_datetime(str,year,month,day,to_time(str,hour,minute,seconds,0)?,offset);
+}
+/// Parses an nginx `$msec` timestamp such as `971211336.123`: seconds since Unix epoch with milliseconds.
+///
+/// Returns UTC `DateTime`. Time before 1970 is negative such as `-0.500`.
+pub fn parse_nginx_msec(text: &str) -> ParseResult<DateTime<FixedOffset>> {
+    let chars: Vec<char> = text.chars().collect();
+    let str = &chars;
+    let is_negative = !str.is_empty() && str[0] == '-';
+    let begin = if is_negative { 1 } else { 0 };
+    let mut position = begin;
+    while position < str.len() && str[position].is_ascii_digit() {
+        position = position + 1;
+    }
+    if position == begin || position - begin > 12 {
+        return Err(ParseError::invalid(ParseErrorKind::InvalidSeconds,begin,position - begin));
+    }
+    let seconds = parse_i64_digits(str,begin,position)?;
+    let _ = parse_token(str,&mut position,".")?;
+    let milliseconds = parse_u32(str,&mut position,3,ParseErrorKind::InvalidNanoseconds)?;
+    let _ = parse_end_of_string(str,&position)?;
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let mut duration = Duration::seconds(seconds) + Duration::milliseconds(milliseconds as i64);
+    if is_negative {
+        duration = -duration;
+    }
+    if let Some(utc) = epoch.checked_add_signed(duration) {
+        return Ok(DateTime::from_utc(utc,FixedOffset::east_opt(0).unwrap()));
+    }
+    if is_negative {
+        return Err(ParseError::invalid_low_value(0,str.len()));
+    }
+    return Err(ParseError::invalid_high_value(0,str.len()));
+}
+fn parse_i64_digits(str: &Vec<char>,begin: usize,end: usize) -> ParseResult<i64> {
+    let text: String = str[begin..end].iter().cloned().collect();
+    if let Ok(value) = text.parse::<i64>() {
+        return Ok(value);
+    }
+    return Err(ParseError::invalid(ParseErrorKind::InvalidSeconds,begin,end - begin));
+}
+/// Parses IIS W3C Extended Log `date` and `time` fields such as `2000-10-10` and `20:55:36`.
+///
+/// Fields are in UTC. Valid formats: `YYYY-MM-DD` for date, `hh:mm:ss` and `hh:mm:ss.s` for time.
+///
+/// Error positions in time field are counted as if fields are separated by one space, as in the log line.
+pub fn parse_iis_datetime(date: &str,time: &str) -> ParseResult<DateTime<FixedOffset>> {
+    let chars: Vec<char> = date.chars().chain(" ".chars()).chain(time.chars()).collect();
+    let str = &chars;
+    let mut position = 0;
+    let year = parse_full_year(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"-")?;
+    let month = parse_month_number(str,&mut position)?;
+    let _ = parse_token(str,&mut position,"-")?;
+    let day = parse_day_number(str,&mut position)?;
+    let _ = parse_token(str,&mut position," ")?;
+    let hour = parse_hour_24(str,&mut position)?;
+    let _ = parse_token(str,&mut position,":")?;
+    let minute = parse_minute(str,&mut position)?;
+    let _ = parse_token(str,&mut position,":")?;
+    let seconds = parse_seconds(str,&mut position)?;
+    let mut nanosecond = 0;
+    if parse_is_token_or_end(str,&mut position,".")? {
+        nanosecond = parse_nanosecond(str,&mut position)?;
+    }
+    let _ = parse_end_of_string(str,&position)?;
+    return to_datetime(str,year,month,day,to_time(str,hour,minute,seconds,nanosecond)?,to_offset(0,0,0)?);
+}
+fn to_datetime(str: &Vec<char>,year: i32,month: u32,day: u32,time: NaiveTime,offset: FixedOffset) -> ParseResult<DateTime<FixedOffset>> {
+    if let Some(date) = NaiveDate::from_ymd_opt(year,month,day) {
+        let naive_date_time = date.and_time(time);
+        if let Some(naive_date_time) = naive_date_time.checked_sub_signed(Duration::seconds(offset.local_minus_utc() as i64)) {
+            return Ok(DateTime::from_utc(naive_date_time, offset));
+        }
+    }
+    return Err(ParseError::invalid_format(0,str.len()));
+}
+#[cfg(test)]
+#[test]
+fn test_web_log() {
+    use formatter::{format_clf,format_nginx_iso8601,format_nginx_msec,format_iis};
+    use parser::parse_w3c_datetime;
+    let testdates = [
+        ("[10/Oct/2000:13:55:36 -0700]", Ok("[10/Oct/2000:13:55:36 -0700]")),
+        ("10/Oct/2000:13:55:36 +0000", Ok("[10/Oct/2000:13:55:36 +0000]")),
+        ("[10/Oct/2000:13:55:36 -0700", Err(ParseErrorKind::InvalidToken)),
+        ("[10/Okt/2000:13:55:36 -0700]", Err(ParseErrorKind::InvalidMonth)),
+        ("[10/Oct/2000:13:55:36 -07:00]", Err(ParseErrorKind::InvalidMinute)),
+        ("[31/Feb/2000:13:55:36 -0700]", Err(ParseErrorKind::InvalidFormat)),
+        ("[10/Oct/2000:13:55:36 -0700] \"GET", Err(ParseErrorKind::StringNotEnded)),
+    ];
+    for &(date, checkdate) in testdates.iter() {
+        let dt = match parse_clf_datetime(date) {
+            Ok(dt) => Ok(format_clf(&dt)),
+            Err(e) => Err(e.error_kind),
+        };
+        if dt != checkdate.map(|s| s.to_string()) {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, dt, checkdate);
+        }
+    }
+    let datetime = parse_nginx_iso8601("2000-10-10T13:55:36-07:00").unwrap();
+    assert_eq!(format_nginx_iso8601(&datetime), "2000-10-10T13:55:36-07:00");
+    assert_eq!(format_nginx_iso8601(&parse_nginx_iso8601("2000-10-10T13:55:36Z").unwrap()), "2000-10-10T13:55:36+00:00");
+    assert_eq!(parse_nginx_iso8601("2000-10-10T13:55-07:00").unwrap_err().error_kind, ParseErrorKind::InvalidToken);
+    let msec = parse_nginx_msec("971211336.123").unwrap();
+    assert_eq!(msec.timestamp(), datetime.timestamp());
+    let testmsecs = [
+        ("2000-10-10T20:55:36.123Z", "971211336.123"),
+        ("1970-01-01T00:00:00Z", "0.000"),
+        ("1970-01-01T00:00:00.001Z", "0.001"),
+        ("1969-12-31T23:59:59.999Z", "-0.001"),
+        ("1969-12-31T23:59:59.500Z", "-0.500"),
+        ("1969-12-31T23:59:59Z", "-1.000"),
+        ("1900-01-01T00:00:00.250Z", "-2208988799.750"),
+    ];
+    for &(date, checkmsec) in testmsecs.iter() {
+        let datetime = parse_w3c_datetime(date).unwrap();
+        let msec = format_nginx_msec(&datetime);
+        if msec != checkmsec {
+            panic!("Date conversion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   date, msec, checkmsec);
+        }
+        assert_eq!(parse_nginx_msec(&msec), Ok(datetime));
+    }
+    assert_eq!(parse_nginx_msec("-.500").unwrap_err().error_kind, ParseErrorKind::InvalidSeconds);
+    assert_eq!(parse_nginx_msec("971211336.12").unwrap_err().error_kind, ParseErrorKind::InvalidNanoseconds);
+    assert_eq!(parse_nginx_msec(".123").unwrap_err().error_kind, ParseErrorKind::InvalidSeconds);
+    let iis = parse_iis_datetime("2000-10-10","20:55:36").unwrap();
+    assert_eq!(iis, datetime);
+    assert_eq!(format_iis(&datetime), ("2000-10-10".to_string(),"20:55:36".to_string()));
+    assert_eq!(parse_iis_datetime("2000-10-10","20:65:36").unwrap_err().error_kind, ParseErrorKind::InvalidHighValue);
+    assert_eq!(parse_iis_datetime("2000-10-10","20:55").unwrap_err(), ParseError::invalid_token(16,1));
+}
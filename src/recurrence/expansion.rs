@@ -0,0 +1,502 @@
+use std::collections::VecDeque;
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::NaiveDateTime;
+use chrono::Datelike;
+use chrono::Timelike;
+use chrono::Duration;
+use chrono::Weekday;
+use parser::ICalDateTime;
+use arithmetic::{days_in_month,days_in_year};
+use super::rule::{Frequency,RecurrenceRule};
+/// Returns UTC offset of local date and time in time zone of `TZID` (`None` for floating time).
+/// Returns `None` if local time does not exist.
+pub type OffsetResolver<'a> = &'a dyn Fn(Option<&str>, &NaiveDateTime) -> Option<FixedOffset>;
+/// Returns instant of the local time in time zone of `tzid` with offset found by `resolver`.
+/// A local time skipped by a transition (such as `02:30` when clocks jump from `02:00` to `03:00`)
+/// is resolved with the offset before the transition (RFC 5545, section 3.3.5) and returned
+/// with the offset after it.
+pub fn resolve_local(resolver: OffsetResolver,tzid: Option<&str>,local: &NaiveDateTime) -> Option<DateTime<FixedOffset>> {
+    let (offset, result_offset) = match resolver(tzid,local) {
+        Some(offset) => (offset, offset),
+        None => {
+            let before = resolver(tzid,&local.checked_sub_signed(Duration::days(1))?)?;
+            let after = resolver(tzid,&local.checked_add_signed(Duration::days(1))?)?;
+            (before, after)
+        },
+    };
+    return local.checked_sub_signed(Duration::seconds(offset.local_minus_utc() as i64))
+        .map(|utc| DateTime::from_utc(utc, result_offset));
+}
+/// Periods without occurrences are not searched beyond this year.
+const MAX_YEAR: i32 = 9999;
+fn days_from(weekday: Weekday,week_start: Weekday) -> i64 {
+    return (weekday.num_days_from_monday() as i64 - week_start.num_days_from_monday() as i64).rem_euclid(7);
+}
+/// Returns the first day of week 1 of year: the first week with at least 4 days in the year.
+fn week_one_start(year: i32,week_start: Weekday) -> Option<NaiveDate> {
+    let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+    let offset = days_from(first.weekday(),week_start);
+    if offset <= 3 {
+        return first.checked_sub_signed(Duration::days(offset));
+    }
+    return first.checked_add_signed(Duration::days(7 - offset));
+}
+/// Returns week number of date (1-based) and count of weeks in its week-numbering year.
+fn week_number(date: NaiveDate,week_start: Weekday) -> Option<(i64, i64)> {
+    let mut year = date.year();
+    if date < week_one_start(year,week_start)? {
+        year = year - 1;
+    } else if date >= week_one_start(year + 1,week_start)? {
+        year = year + 1;
+    }
+    let week_one = week_one_start(year,week_start)?;
+    let weeks = week_one_start(year + 1,week_start)?.signed_duration_since(week_one).num_days() / 7;
+    return Some((date.signed_duration_since(week_one).num_days() / 7 + 1, weeks));
+}
+fn gcd(a: i64,b: i64) -> i64 {
+    return if b == 0 { a } else { gcd(b,a % b) };
+}
+/// Returns `true` if position (1-based) of item among `length` items matches positive or negative `index`.
+fn is_index(index: i32,position: i64,length: i64) -> bool {
+    return index as i64 == position || index as i64 == position - length - 1;
+}
+/// Iterator over occurrences of one recurrence rule.
+///
+/// Created by `RecurrenceRule::occurrences`. Occurrences are sorted. `DTSTART` itself is not added,
+/// unless it matches the rule.
+pub struct RuleOccurrences<'a> {
+    rule: RecurrenceRule,
+    dtstart: &'a ICalDateTime,
+    start: NaiveDateTime,
+    resolver: OffsetResolver<'a>,
+    period: NaiveDateTime,
+    buffer: VecDeque<NaiveDateTime>,
+    emitted: u32,
+    is_finished: bool,
+}
+impl RecurrenceRule {
+    /// Returns iterator over occurrences of rule starting at `dtstart`.
+    ///
+    /// Occurrences are expanded in local time of `dtstart`, so daily 09:00 stays 09:00 across
+    /// daylight saving time changes. Offset of each occurrence is returned by `resolver`;
+    /// occurrences which do not exist in local time (resolver returns `None`) are moved forward
+    /// by the length of the gap, as RFC 5545 requires.
+    /// UTC `dtstart` does not use resolver.
+    pub fn occurrences<'a>(&self,dtstart: &'a ICalDateTime,resolver: OffsetResolver<'a>) -> RuleOccurrences<'a> {
+        let start = dtstart.naive_local();
+        let mut rule = self.clone();
+        rule.interval = rule.interval.max(1);
+        if rule.by_week_no.is_empty() && rule.by_year_day.is_empty() && rule.by_month_day.is_empty() && rule.by_day.is_empty() {
+            match rule.frequency {
+                Frequency::Yearly => {
+                    if rule.by_month.is_empty() {
+                        rule.by_month = vec![start.month()];
+                    }
+                    rule.by_month_day = vec![start.day() as i32];
+                },
+                Frequency::Monthly => rule.by_month_day = vec![start.day() as i32],
+                Frequency::Weekly => rule.by_day = vec![(0, start.weekday())],
+                _ => {},
+            }
+        }
+        if rule.by_hour.is_empty() && rule.frequency > Frequency::Hourly {
+            rule.by_hour = vec![start.hour()];
+        }
+        if rule.by_minute.is_empty() && rule.frequency > Frequency::Minutely {
+            rule.by_minute = vec![start.minute()];
+        }
+        if rule.by_second.is_empty() && rule.frequency > Frequency::Secondly {
+            rule.by_second = vec![start.second()];
+        }
+        rule.by_hour.sort();
+        rule.by_minute.sort();
+        rule.by_second.sort();
+        let date = start.date();
+        let period = match rule.frequency {
+            Frequency::Yearly => date.with_ordinal(1).and_then(|date| date.and_hms_opt(0, 0, 0)),
+            Frequency::Monthly => date.with_day(1).and_then(|date| date.and_hms_opt(0, 0, 0)),
+            Frequency::Weekly => date.checked_sub_signed(Duration::days(days_from(date.weekday(),rule.week_start))).and_then(|date| date.and_hms_opt(0, 0, 0)),
+            Frequency::Daily => date.and_hms_opt(0, 0, 0),
+            Frequency::Hourly => date.and_hms_opt(start.hour(), 0, 0),
+            Frequency::Minutely => date.and_hms_opt(start.hour(), start.minute(), 0),
+            Frequency::Secondly => date.and_hms_opt(start.hour(), start.minute(), start.second()),
+        };
+        let mut occurrences = RuleOccurrences {
+            rule: rule,
+            dtstart: dtstart,
+            start: start,
+            resolver: resolver,
+            period: period.unwrap_or(start),
+            buffer: VecDeque::new(),
+            emitted: 0,
+            is_finished: period.is_none(),
+        };
+        occurrences.is_finished = occurrences.is_finished || occurrences.is_never_matched();
+        return occurrences;
+    }
+}
+impl<'a> RuleOccurrences<'a> {
+    /// Returns `true` if no period reachable with the interval can match the rule, such as
+    /// `FREQ=MINUTELY;INTERVAL=2;BYMINUTE=1` from an even minute or `BYMONTH=2;BYMONTHDAY=30`.
+    fn is_never_matched(&self) -> bool {
+        let rule = &self.rule;
+        let interval = rule.interval as i64;
+        const MAX_DAYS: [i32; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+        let months: Vec<u32> = if rule.by_month.is_empty() { (1..13).collect() } else { rule.by_month.clone() };
+        if !rule.by_month_day.is_empty() && rule.by_month_day.iter().all(|&index| {
+            months.iter().all(|&month| index.abs() > MAX_DAYS[month as usize - 1])
+        }) {
+            return true;
+        }
+        match rule.frequency {
+            Frequency::Monthly => {
+                let step = gcd(interval,12);
+                let month = self.period.month() as i64;
+                return !months.iter().any(|&candidate| (candidate as i64 - month).rem_euclid(step) == 0);
+            },
+            Frequency::Daily if !rule.by_day.is_empty() => {
+                let step = gcd(interval,7);
+                let weekday = self.period.weekday().num_days_from_monday() as i64;
+                return !rule.by_day.iter().any(|&(_,candidate)| (candidate.num_days_from_monday() as i64 - weekday).rem_euclid(step) == 0);
+            },
+            Frequency::Hourly | Frequency::Minutely | Frequency::Secondly => {
+                // Periods start at the same time of day modulo `step` seconds.
+                let unit = match rule.frequency {
+                    Frequency::Hourly => 3600,
+                    Frequency::Minutely => 60,
+                    _ => 1,
+                };
+                let step = gcd(interval * unit,86400);
+                let start = self.period.num_seconds_from_midnight() as i64;
+                let values = |values: &Vec<u32>,max: u32,is_expanded: bool| -> Vec<u32> {
+                    if !is_expanded {
+                        return vec![0];
+                    }
+                    return if values.is_empty() { (0..max).collect() } else { values.clone() };
+                };
+                let hours = values(&rule.by_hour,24,true);
+                let minutes = values(&rule.by_minute,60,rule.frequency <= Frequency::Minutely);
+                let seconds = values(&rule.by_second,61,rule.frequency == Frequency::Secondly);
+                for &hour in &hours {
+                    for &minute in &minutes {
+                        for &second in &seconds {
+                            let period = (hour * 3600 + minute * 60 + second) as i64;
+                            if (period - start).rem_euclid(step) == 0 {
+                                return false;
+                            }
+                        }
+                    }
+                }
+                return true;
+            },
+            _ => return false,
+        }
+    }
+    fn is_day_matched(&self,date: NaiveDate) -> bool {
+        let rule = &self.rule;
+        if !rule.by_month.is_empty() && !rule.by_month.contains(&date.month()) {
+            return false;
+        }
+        if !rule.by_week_no.is_empty() {
+            let (week, weeks) = match week_number(date,rule.week_start) {
+                Some(week) => week,
+                None => return false,
+            };
+            if !rule.by_week_no.iter().any(|&index| is_index(index,week,weeks)) {
+                return false;
+            }
+        }
+        if !rule.by_year_day.is_empty() {
+            let length = days_in_year(date.year()) as i64;
+            if !rule.by_year_day.iter().any(|&index| is_index(index,date.ordinal() as i64,length)) {
+                return false;
+            }
+        }
+        if !rule.by_month_day.is_empty() {
+            let length = days_in_month(date.year(),date.month()) as i64;
+            if !rule.by_month_day.iter().any(|&index| is_index(index,date.day() as i64,length)) {
+                return false;
+            }
+        }
+        if !rule.by_day.is_empty() {
+            let is_month_scope = rule.frequency == Frequency::Monthly || (rule.frequency == Frequency::Yearly && !rule.by_month.is_empty());
+            let (day, length) = if is_month_scope {
+                (date.day() as i64, days_in_month(date.year(),date.month()) as i64)
+            } else {
+                (date.ordinal() as i64, days_in_year(date.year()) as i64)
+            };
+            let is_ordinal_allowed = rule.frequency >= Frequency::Monthly;
+            let is_matched = rule.by_day.iter().any(|&(index,weekday)| {
+                if weekday != date.weekday() {
+                    return false;
+                }
+                if index == 0 || !is_ordinal_allowed {
+                    return true;
+                }
+                return is_index(index,(day - 1) / 7 + 1,(length - day) / 7 + (day - 1) / 7 + 1);
+            });
+            if !is_matched {
+                return false;
+            }
+        }
+        return true;
+    }
+    fn period_days(&self) -> Vec<NaiveDate> {
+        let date = self.period.date();
+        let (first, length) = match self.rule.frequency {
+            Frequency::Yearly if !self.rule.by_week_no.is_empty() => {
+                match (week_one_start(date.year(),self.rule.week_start), week_one_start(date.year() + 1,self.rule.week_start)) {
+                    (Some(first), Some(next)) => (first, next.signed_duration_since(first).num_days()),
+                    _ => return Vec::new(),
+                }
+            },
+            Frequency::Yearly => (date, days_in_year(date.year()) as i64),
+            Frequency::Monthly => (date, days_in_month(date.year(),date.month()) as i64),
+            Frequency::Weekly => (date, 7),
+            _ => (date, 1),
+        };
+        return (0..length).map(|day| first + Duration::days(day)).filter(|&day| self.is_day_matched(day)).collect();
+    }
+    fn period_times(&self) -> Vec<NaiveTime> {
+        let rule = &self.rule;
+        let select = |values: &Vec<u32>,current: u32,is_expanded: bool| -> Vec<u32> {
+            if is_expanded {
+                return values.clone();
+            }
+            if values.is_empty() || values.contains(&current) {
+                return vec![current];
+            }
+            return Vec::new();
+        };
+        let hours = select(&rule.by_hour,self.period.hour(),rule.frequency > Frequency::Hourly);
+        let minutes = select(&rule.by_minute,self.period.minute(),rule.frequency > Frequency::Minutely);
+        let seconds = select(&rule.by_second,self.period.second(),rule.frequency > Frequency::Secondly);
+        let mut times = Vec::new();
+        for &hour in &hours {
+            for &minute in &minutes {
+                for &second in &seconds {
+                    // Leap second 60 is not representable by NaiveTime::from_hms.
+                    if let Some(time) = NaiveTime::from_hms_opt(hour,minute,second.min(59)) {
+                        times.push(time);
+                    }
+                }
+            }
+        }
+        times.dedup();
+        return times;
+    }
+    /// Fills buffer with occurrences of current period and moves to the next period.
+    /// Returns `false` if there are no more periods.
+    fn expand_period(&mut self) -> bool {
+        if self.period.year() > MAX_YEAR {
+            return false;
+        }
+        let days = self.period_days();
+        let times = if days.is_empty() { Vec::new() } else { self.period_times() };
+        let mut candidates: Vec<NaiveDateTime> = Vec::new();
+        for &day in &days {
+            for &time in &times {
+                candidates.push(day.and_time(time));
+            }
+        }
+        if !self.rule.by_set_pos.is_empty() {
+            let length = candidates.len() as i64;
+            let mut selected: Vec<NaiveDateTime> = self.rule.by_set_pos.iter().filter_map(|&index| {
+                let position = if index > 0 { index as i64 - 1 } else { length + index as i64 };
+                if position >= 0 && position < length { Some(candidates[position as usize]) } else { None }
+            }).collect();
+            selected.sort();
+            selected.dedup();
+            candidates = selected;
+        }
+        for candidate in candidates {
+            if candidate >= self.start {
+                self.buffer.push_back(candidate);
+            }
+        }
+        let interval = self.rule.interval as i64;
+        let next = match self.rule.frequency {
+            Frequency::Yearly => NaiveDate::from_ymd_opt(self.period.year() + interval as i32, 1, 1).and_then(|date| date.and_hms_opt(0, 0, 0)),
+            Frequency::Monthly => {
+                let month = self.period.year() as i64 * 12 + self.period.month0() as i64 + interval;
+                NaiveDate::from_ymd_opt(month.div_euclid(12) as i32, month.rem_euclid(12) as u32 + 1, 1).and_then(|date| date.and_hms_opt(0, 0, 0))
+            },
+            Frequency::Weekly => self.period.checked_add_signed(Duration::weeks(interval)),
+            Frequency::Daily => self.period.checked_add_signed(Duration::days(interval)),
+            _ => {
+                let unit = match self.rule.frequency {
+                    Frequency::Hourly => 3600,
+                    Frequency::Minutely => 60,
+                    _ => 1,
+                };
+                let step = interval * unit;
+                // Skip the rest of day or hour which can not match, keeping the interval alignment.
+                let boundary = if days.is_empty() {
+                    self.period.date().and_hms_opt(0, 0, 0).map(|day| day + Duration::days(1))
+                } else if times.is_empty() && self.rule.frequency < Frequency::Hourly && !self.rule.by_hour.contains(&self.period.hour()) && !self.rule.by_hour.is_empty() {
+                    self.period.date().and_hms_opt(self.period.hour(), 0, 0).map(|hour| hour + Duration::hours(1))
+                } else {
+                    None
+                };
+                let steps = match boundary {
+                    Some(boundary) => (boundary.signed_duration_since(self.period).num_seconds() + step - 1) / step,
+                    None => 1,
+                };
+                self.period.checked_add_signed(Duration::seconds(steps.max(1) * step))
+            },
+        };
+        match next {
+            Some(next) => self.period = next,
+            None => self.period = NaiveDate::from_ymd_opt(MAX_YEAR + 1, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+        }
+        return true;
+    }
+    /// Returns instant of the local time, see `resolve_local`.
+    fn resolve(&self,local: &NaiveDateTime) -> Option<DateTime<FixedOffset>> {
+        if let ICalDateTime::Utc(_) = *self.dtstart {
+            return Some(DateTime::from_utc(*local, FixedOffset::east_opt(0).unwrap()));
+        }
+        return resolve_local(self.resolver,self.dtstart.tzid(),local);
+    }
+}
+impl<'a> Iterator for RuleOccurrences<'a> {
+    type Item = DateTime<FixedOffset>;
+    fn next(&mut self) -> Option<DateTime<FixedOffset>> {
+        while !self.is_finished {
+            let local = match self.buffer.pop_front() {
+                Some(local) => local,
+                None => {
+                    if !self.expand_period() {
+                        self.is_finished = true;
+                    }
+                    continue;
+                },
+            };
+            if let Some(count) = self.rule.count {
+                if self.emitted >= count {
+                    self.is_finished = true;
+                    continue;
+                }
+            }
+            let datetime = match self.resolve(&local) {
+                Some(datetime) => datetime,
+                None => continue,
+            };
+            let is_after_until = match self.rule.until {
+                Some(ICalDateTime::Utc(until)) => datetime.naive_utc() > until,
+                Some(ICalDateTime::Date(until)) => local.date() > until,
+                Some(ICalDateTime::Floating(until)) => local > until,
+                Some(ICalDateTime::Zoned(_,until)) => local > until,
+                None => false,
+            };
+            if is_after_until {
+                self.is_finished = true;
+                continue;
+            }
+            self.emitted = self.emitted + 1;
+            return Some(datetime);
+        }
+        return None;
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_recurrence() {
+    use chrono::TimeZone;
+    use parser::parse_recurrence_set;
+    // US Eastern time before 2007: daylight time from first Sunday of April to last Sunday of October.
+    let eastern = |_: Option<&str>, datetime: &NaiveDateTime| -> Option<FixedOffset> {
+        let year = datetime.year();
+        let april = NaiveDate::from_ymd(year,4,1);
+        let begin = april + Duration::days(((7 - april.weekday().num_days_from_sunday()) % 7) as i64);
+        let october = NaiveDate::from_ymd(year,10,31);
+        let end = october - Duration::days(october.weekday().num_days_from_sunday() as i64);
+        if *datetime >= begin.and_hms(2,0,0) && *datetime < begin.and_hms(3,0,0) {
+            return None;
+        }
+        let is_daylight = *datetime >= begin.and_hms(2,0,0) && *datetime < end.and_hms(2,0,0);
+        return Some(FixedOffset::west(if is_daylight { 4 * 3600 } else { 5 * 3600 }));
+    };
+    let expand = |start: &str, rules: &str, limit: usize| -> Vec<DateTime<FixedOffset>> {
+        let text = format!("DTSTART;TZID=America/New_York:{}\n{}",start,rules);
+        let set = parse_recurrence_set(&text).unwrap();
+        return set.occurrences(&eastern).take(limit).collect();
+    };
+    let dates = |occurrences: &[DateTime<FixedOffset>]| -> Vec<String> {
+        return occurrences.iter().map(|datetime| datetime.format("%Y-%m-%d").to_string()).collect();
+    };
+    let testrules = [
+        ("19970902T090000", "RRULE:FREQ=DAILY;COUNT=10", 10, "1997-09-02", "1997-09-11"),
+        ("19970902T090000", "RRULE:FREQ=DAILY;UNTIL=19971224T000000Z", 113, "1997-09-02", "1997-12-23"),
+        ("19970902T090000", "RRULE:FREQ=DAILY;INTERVAL=10;COUNT=5", 5, "1997-09-02", "1997-10-12"),
+        ("19980101T090000", "RRULE:FREQ=YEARLY;UNTIL=20000131T140000Z;BYMONTH=1;BYDAY=SU,MO,TU,WE,TH,FR,SA", 93, "1998-01-01", "2000-01-31"),
+        ("19970902T090000", "RRULE:FREQ=WEEKLY;UNTIL=19971007T000000Z;WKST=SU;BYDAY=TU,TH", 10, "1997-09-02", "1997-10-02"),
+        ("19970901T090000", "RRULE:FREQ=WEEKLY;INTERVAL=2;UNTIL=19971224T000000Z;WKST=SU;BYDAY=MO,WE,FR", 25, "1997-09-01", "1997-12-22"),
+        ("19970905T090000", "RRULE:FREQ=MONTHLY;COUNT=10;BYDAY=1FR", 10, "1997-09-05", "1998-06-05"),
+        ("19970922T090000", "RRULE:FREQ=MONTHLY;COUNT=6;BYDAY=-2MO", 6, "1997-09-22", "1998-02-16"),
+        ("19970101T090000", "RRULE:FREQ=YEARLY;INTERVAL=3;COUNT=10;BYYEARDAY=1,100,200", 10, "1997-01-01", "2006-01-01"),
+        ("20070115T090000", "RRULE:FREQ=MONTHLY;BYMONTHDAY=15,30;COUNT=5", 5, "2007-01-15", "2007-03-30"),
+    ];
+    for &(start, rules, count, first, last) in testrules.iter() {
+        let result = dates(&expand(start,rules,200));
+        let checkrule = (count, first.to_string(), last.to_string());
+        let received = (result.len(), result[0].clone(), result[result.len() - 1].clone());
+        if received != checkrule {
+            panic!("Recurrence expansion failed for {}\nReceived: {:?}\nExpected: {:?}",
+                   rules, received, checkrule);
+        }
+    }
+    let occurrences = expand("19970902T090000","RRULE:FREQ=DAILY;UNTIL=19971224T000000Z",200);
+    assert_eq!(occurrences[0], FixedOffset::west(4 * 3600).ymd(1997,9,2).and_hms(9,0,0));
+    assert_eq!(occurrences[112], FixedOffset::west(5 * 3600).ymd(1997,12,23).and_hms(9,0,0));
+    assert_eq!(dates(&expand("19970928T090000","RRULE:FREQ=MONTHLY;BYMONTHDAY=-3",3)), ["1997-09-28", "1997-10-29", "1997-11-28"]);
+    assert_eq!(dates(&expand("19970902T090000","EXDATE;TZID=America/New_York:19970902T090000\nRRULE:FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13",5)),
+               ["1998-02-13", "1998-03-13", "1998-11-13", "1999-08-13", "2000-10-13"]);
+    assert_eq!(dates(&expand("19970512T090000","RRULE:FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO",3)), ["1997-05-12", "1998-05-11", "1999-05-17"]);
+    assert_eq!(dates(&expand("19961105T090000","RRULE:FREQ=YEARLY;INTERVAL=4;BYMONTH=11;BYDAY=TU;BYMONTHDAY=2,3,4,5,6,7,8",3)),
+               ["1996-11-05", "2000-11-07", "2004-11-02"]);
+    assert_eq!(dates(&expand("19970904T090000","RRULE:FREQ=MONTHLY;COUNT=3;BYDAY=TU,WE,TH;BYSETPOS=3",3)), ["1997-09-04", "1997-10-07", "1997-11-06"]);
+    assert_eq!(dates(&expand("19970929T090000","RRULE:FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-2",3)), ["1997-09-29", "1997-10-30", "1997-11-27"]);
+    assert_eq!(dates(&expand("19970805T090000","RRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO",4)), ["1997-08-05", "1997-08-10", "1997-08-19", "1997-08-24"]);
+    assert_eq!(dates(&expand("19970805T090000","RRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU",4)), ["1997-08-05", "1997-08-17", "1997-08-19", "1997-08-31"]);
+    let times: Vec<String> = expand("19970902T090000","RRULE:FREQ=MINUTELY;INTERVAL=15;COUNT=6",10).iter()
+        .map(|datetime| datetime.format("%H:%M").to_string()).collect();
+    assert_eq!(times, ["09:00", "09:15", "09:30", "09:45", "10:00", "10:15"]);
+    let daily = expand("19970902T090000","RRULE:FREQ=DAILY;BYHOUR=9,10,11,12,13,14,15,16;BYMINUTE=0,20,40",50);
+    let minutely = expand("19970902T090000","RRULE:FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10,11,12,13,14,15,16",50);
+    assert_eq!(daily, minutely);
+    assert_eq!(daily[24].format("%d %H:%M").to_string(), "03 09:00");
+    let gap: Vec<String> = expand("19970405T023000","RRULE:FREQ=DAILY;COUNT=3",3).iter().map(|datetime| datetime.to_rfc3339()).collect();
+    assert_eq!(gap, ["1997-04-05T02:30:00-05:00", "1997-04-06T03:30:00-04:00", "1997-04-07T02:30:00-04:00"]);
+    let rfc3339 = |occurrences: &[DateTime<FixedOffset>]| -> Vec<String> {
+        return occurrences.iter().map(|datetime| datetime.to_rfc3339()).collect();
+    };
+    assert_eq!(rfc3339(&expand("19970405T023000","RRULE:FREQ=DAILY;COUNT=3\nEXDATE;TZID=America/New_York:19970406T023000",3)),
+               ["1997-04-05T02:30:00-05:00", "1997-04-07T02:30:00-04:00"]);
+    assert_eq!(rfc3339(&expand("19970406T023000","RDATE;TZID=America/New_York:19970406T020000",3)),
+               ["1997-04-06T03:00:00-04:00", "1997-04-06T03:30:00-04:00"]);
+    assert_eq!(rfc3339(&expand("19971026T013000","RRULE:FREQ=HOURLY;COUNT=3\nRDATE;TZID=America/New_York:19971026T011500\nEXDATE;TZID=America/New_York:19971026T023000",5)),
+               ["1997-10-26T01:15:00-04:00", "1997-10-26T01:30:00-04:00", "1997-10-26T03:30:00-05:00"]);
+    let never = [
+        ("19970902T090000", "RRULE:FREQ=MINUTELY;INTERVAL=2;BYMINUTE=1"),
+        ("19970902T090000", "RRULE:FREQ=SECONDLY;INTERVAL=3600;BYMINUTE=30"),
+        ("19970902T090000", "RRULE:FREQ=HOURLY;INTERVAL=24;BYHOUR=10"),
+        ("19970902T090000", "RRULE:FREQ=DAILY;INTERVAL=7;BYDAY=MO"),
+        ("19970902T090000", "RRULE:FREQ=MONTHLY;INTERVAL=12;BYMONTH=3"),
+        ("19970902T090000", "RRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30"),
+    ];
+    for &(start, rules) in never.iter() {
+        // Recurrence set starts with DTSTART itself.
+        assert!(expand(start,rules,2).len() == 1, "Rule {} must never match", rules);
+    }
+    assert_eq!(expand("19970902T090100","RRULE:FREQ=MINUTELY;INTERVAL=2;BYMINUTE=1",2).len(), 2);
+    assert_eq!(dates(&expand("19970902T090000","RRULE:FREQ=DAILY;INTERVAL=7;BYDAY=TU",2)), ["1997-09-02", "1997-09-09"]);
+    let set = parse_recurrence_set("DTSTART:19970902\nRRULE:FREQ=WEEKLY;COUNT=3\nRDATE:19970910,19970902\nEXDATE:19970916").unwrap();
+    let utc = |_: Option<&str>, _: &NaiveDateTime| Some(FixedOffset::east(0));
+    let result: Vec<String> = dates(&set.occurrences(&utc).collect::<Vec<_>>());
+    assert_eq!(result, ["1997-09-02", "1997-09-09", "1997-09-10"]);
+}
@@ -0,0 +1,6 @@
+mod rule;
+mod expansion;
+mod set;
+pub use self::rule::{Frequency,RecurrenceRule,weekday_name,weekday_from_name};
+pub use self::expansion::{RuleOccurrences,OffsetResolver,resolve_local};
+pub use self::set::{RecurrenceSet,SetOccurrences};
\ No newline at end of file
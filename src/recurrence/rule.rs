@@ -0,0 +1,127 @@
+use chrono::Weekday;
+use parser::ICalDateTime;
+/// Frequency of recurrence rule (`FREQ`). Variants are ordered from the shortest to the longest period.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Frequency {
+    /// `SECONDLY`.
+    Secondly,
+    /// `MINUTELY`.
+    Minutely,
+    /// `HOURLY`.
+    Hourly,
+    /// `DAILY`.
+    Daily,
+    /// `WEEKLY`.
+    Weekly,
+    /// `MONTHLY`.
+    Monthly,
+    /// `YEARLY`.
+    Yearly,
+}
+impl Frequency {
+    /// Returns name of frequency such as `WEEKLY`.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Frequency::Secondly => "SECONDLY",
+            Frequency::Minutely => "MINUTELY",
+            Frequency::Hourly => "HOURLY",
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        }
+    }
+    /// Returns frequency by its name.
+    pub fn from_name(name: &str) -> Option<Frequency> {
+        match name {
+            "SECONDLY" => Some(Frequency::Secondly),
+            "MINUTELY" => Some(Frequency::Minutely),
+            "HOURLY" => Some(Frequency::Hourly),
+            "DAILY" => Some(Frequency::Daily),
+            "WEEKLY" => Some(Frequency::Weekly),
+            "MONTHLY" => Some(Frequency::Monthly),
+            "YEARLY" => Some(Frequency::Yearly),
+            _ => None,
+        }
+    }
+}
+/// Returns two-letter iCalendar name of weekday such as `MO`.
+pub fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+/// Returns weekday by two-letter iCalendar name.
+pub fn weekday_from_name(name: &str) -> Option<Weekday> {
+    match name {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+/// iCalendar (RFC 5545) recurrence rule (`RRULE`).
+///
+/// Empty `by_*` lists mean that the part is not specified.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RecurrenceRule {
+    /// `FREQ`.
+    pub frequency: Frequency,
+    /// `INTERVAL`, 1 by default.
+    pub interval: u32,
+    /// `COUNT`.
+    pub count: Option<u32>,
+    /// `UNTIL`, inclusive.
+    pub until: Option<ICalDateTime>,
+    /// `BYSECOND`: 0 through 60.
+    pub by_second: Vec<u32>,
+    /// `BYMINUTE`: 0 through 59.
+    pub by_minute: Vec<u32>,
+    /// `BYHOUR`: 0 through 23.
+    pub by_hour: Vec<u32>,
+    /// `BYDAY`: weekdays with ordinal such as `(-1, Weekday::Fri)` for `-1FR`. Ordinal 0 means every weekday.
+    pub by_day: Vec<(i32, Weekday)>,
+    /// `BYMONTHDAY`: 1 through 31 or -31 through -1.
+    pub by_month_day: Vec<i32>,
+    /// `BYYEARDAY`: 1 through 366 or -366 through -1.
+    pub by_year_day: Vec<i32>,
+    /// `BYWEEKNO`: 1 through 53 or -53 through -1.
+    pub by_week_no: Vec<i32>,
+    /// `BYMONTH`: 1 through 12.
+    pub by_month: Vec<u32>,
+    /// `BYSETPOS`: 1 through 366 or -366 through -1.
+    pub by_set_pos: Vec<i32>,
+    /// `WKST`, Monday by default.
+    pub week_start: Weekday,
+}
+impl RecurrenceRule {
+    /// Returns rule of the given frequency with interval 1 and without other parts.
+    pub fn new(frequency: Frequency) -> RecurrenceRule {
+        return RecurrenceRule {
+            frequency: frequency,
+            interval: 1,
+            count: None,
+            until: None,
+            by_second: Vec::new(),
+            by_minute: Vec::new(),
+            by_hour: Vec::new(),
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_year_day: Vec::new(),
+            by_week_no: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: Weekday::Mon,
+        };
+    }
+}
@@ -0,0 +1,86 @@
+use std::iter::Peekable;
+use chrono::DateTime;
+use chrono::FixedOffset;
+use parser::ICalDateTime;
+use super::rule::RecurrenceRule;
+use super::expansion::{RuleOccurrences,OffsetResolver,resolve_local};
+/// iCalendar (RFC 5545) recurrence set: `DTSTART`, `RRULE`, `RDATE` and `EXDATE` properties.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RecurrenceSet {
+    /// `DTSTART`: the first occurrence.
+    pub dtstart: ICalDateTime,
+    /// `RRULE` properties.
+    pub rules: Vec<RecurrenceRule>,
+    /// `RDATE` values: additional occurrences.
+    pub rdates: Vec<ICalDateTime>,
+    /// `EXDATE` values: excluded occurrences.
+    pub exdates: Vec<ICalDateTime>,
+}
+/// Resolves value as rule occurrences are resolved, so that equal instants compare equal.
+fn resolve(value: &ICalDateTime,resolver: OffsetResolver) -> Option<DateTime<FixedOffset>> {
+    if let ICalDateTime::Utc(utc) = *value {
+        return Some(DateTime::from_utc(utc, FixedOffset::east_opt(0).unwrap()));
+    }
+    return resolve_local(resolver,value.tzid(),&value.naive_local());
+}
+impl RecurrenceSet {
+    /// Returns set with `DTSTART` only.
+    pub fn new(dtstart: ICalDateTime) -> RecurrenceSet {
+        return RecurrenceSet {
+            dtstart: dtstart,
+            rules: Vec::new(),
+            rdates: Vec::new(),
+            exdates: Vec::new(),
+        };
+    }
+    /// Returns sorted iterator over occurrences: `DTSTART`, occurrences of rules and `RDATE` values
+    /// except `EXDATE` values. Equal occurrences are returned once.
+    ///
+    /// See `RecurrenceRule::occurrences` about `resolver`.
+    pub fn occurrences<'a>(&'a self,resolver: OffsetResolver<'a>) -> SetOccurrences<'a> {
+        let mut dates: Vec<DateTime<FixedOffset>> = self.rdates.iter().chain(Some(&self.dtstart)).filter_map(|value| resolve(value,resolver)).collect();
+        dates.sort();
+        let exdates = self.exdates.iter().filter_map(|value| resolve(value,resolver)).collect();
+        return SetOccurrences {
+            rules: self.rules.iter().map(|rule| rule.occurrences(&self.dtstart,resolver).peekable()).collect(),
+            dates: dates,
+            date_index: 0,
+            exdates: exdates,
+            last: None,
+        };
+    }
+}
+/// Iterator over occurrences of recurrence set. Created by `RecurrenceSet::occurrences`.
+pub struct SetOccurrences<'a> {
+    rules: Vec<Peekable<RuleOccurrences<'a>>>,
+    dates: Vec<DateTime<FixedOffset>>,
+    date_index: usize,
+    exdates: Vec<DateTime<FixedOffset>>,
+    last: Option<DateTime<FixedOffset>>,
+}
+impl<'a> Iterator for SetOccurrences<'a> {
+    type Item = DateTime<FixedOffset>;
+    fn next(&mut self) -> Option<DateTime<FixedOffset>> {
+        loop {
+            let mut next: Option<(usize, DateTime<FixedOffset>)> = self.dates.get(self.date_index).map(|date| (self.rules.len(), *date));
+            for (index,rule) in self.rules.iter_mut().enumerate() {
+                if let Some(&datetime) = rule.peek() {
+                    if next.map(|(_,next)| datetime < next).unwrap_or(true) {
+                        next = Some((index, datetime));
+                    }
+                }
+            }
+            let (index, datetime) = next?;
+            if index == self.rules.len() {
+                self.date_index = self.date_index + 1;
+            } else {
+                self.rules[index].next();
+            }
+            if self.last == Some(datetime) || self.exdates.contains(&datetime) {
+                continue;
+            }
+            self.last = Some(datetime);
+            return Some(datetime);
+        }
+    }
+}
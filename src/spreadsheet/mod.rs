@@ -0,0 +1,2 @@
+mod serial_date;
+pub use self::serial_date::{SerialEpoch,to_serial,from_serial,to_serial_datetime,from_serial_datetime};
\ No newline at end of file
@@ -0,0 +1,128 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Duration;
+use julian_day::DayNumber;
+/// Serial number of the phantom date 1900-02-29 in the 1900 date system.
+const PHANTOM_LEAP_DAY: i64 = 60;
+/// Date system of spreadsheet serial numbers.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SerialEpoch {
+    /// Excel 1900 date system. Serial 1 is 1900-01-01, serial 60 is the phantom 1900-02-29.
+    /// Serial 0 (shown as 1900-01-00) is 1899-12-31.
+    Excel1900,
+    /// Excel 1904 date system (Excel for Mac before 2011). Serial 0 is 1904-01-01.
+    Excel1904,
+    /// LibreOffice and OpenOffice date system. Serial 0 is 1899-12-30, negative serials are allowed.
+    LibreOffice,
+    /// Lotus 1-2-3 date system. Same to `Excel1900` including the phantom 1900-02-29.
+    Lotus,
+}
+impl SerialEpoch {
+    fn has_phantom_leap_day(&self) -> bool {
+        return *self == SerialEpoch::Excel1900 || *self == SerialEpoch::Lotus;
+    }
+}
+/// Returns spreadsheet serial number of local date and time.
+///
+/// Returns `None` if date is before the first date of date system.
+pub fn to_serial(datetime: &NaiveDateTime,epoch: SerialEpoch) -> Option<DayNumber> {
+    match epoch {
+        SerialEpoch::Excel1900 | SerialEpoch::Lotus => {
+            let mut serial = DayNumber::from_naive_since(datetime, &NaiveDate::from_ymd_opt(1899, 12, 31).unwrap().and_hms_opt(0, 0, 0).unwrap());
+            if serial.day < 0 {
+                return None;
+            }
+            if serial.day >= PHANTOM_LEAP_DAY {
+                serial.day = serial.day + 1;
+            }
+            return Some(serial);
+        },
+        SerialEpoch::Excel1904 => {
+            let serial = DayNumber::from_naive_since(datetime, &NaiveDate::from_ymd_opt(1904, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+            if serial.day < 0 {
+                return None;
+            }
+            return Some(serial);
+        },
+        SerialEpoch::LibreOffice => {
+            return Some(DayNumber::from_naive_since(datetime, &NaiveDate::from_ymd_opt(1899, 12, 30).unwrap().and_hms_opt(0, 0, 0).unwrap()));
+        },
+    }
+}
+/// Returns local date and time of spreadsheet serial number.
+///
+/// Returns `None` for the phantom 1900-02-29 (serial 60 of the 1900 date system)
+/// and for serials out of range of date system.
+pub fn from_serial(serial: &DayNumber,epoch: SerialEpoch) -> Option<NaiveDateTime> {
+    if epoch.has_phantom_leap_day() {
+        if serial.day < 0 || serial.day == PHANTOM_LEAP_DAY {
+            return None;
+        }
+        if serial.day < PHANTOM_LEAP_DAY {
+            return serial.to_naive_since(&NaiveDate::from_ymd_opt(1899, 12, 31).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        }
+        return serial.to_naive_since(&NaiveDate::from_ymd_opt(1899, 12, 30).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    }
+    if epoch == SerialEpoch::Excel1904 {
+        if serial.day < 0 {
+            return None;
+        }
+        return serial.to_naive_since(&NaiveDate::from_ymd_opt(1904, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    }
+    return serial.to_naive_since(&NaiveDate::from_ymd_opt(1899, 12, 30).unwrap().and_hms_opt(0, 0, 0).unwrap());
+}
+/// Returns spreadsheet serial number of date and time.
+///
+/// Spreadsheets keep local time, so offset is not stored.
+pub fn to_serial_datetime(datetime: &DateTime<FixedOffset>,epoch: SerialEpoch) -> Option<DayNumber> {
+    return to_serial(&datetime.naive_local(), epoch);
+}
+/// Returns `DateTime` of spreadsheet serial number with local time in the `offset`.
+pub fn from_serial_datetime(serial: &DayNumber,epoch: SerialEpoch,offset: FixedOffset) -> Option<DateTime<FixedOffset>> {
+    let local = from_serial(serial, epoch)?;
+    let utc = local.checked_sub_signed(Duration::seconds(offset.local_minus_utc() as i64))?;
+    return Some(DateTime::from_utc(utc, offset));
+}
+#[cfg(test)]
+#[test]
+fn test_serial_date() {
+    use formatter::{format_serial,format_serial_datetime};
+    let testdata = [
+        ((1899, 12, 30), SerialEpoch::Excel1900, None),
+        ((1899, 12, 31), SerialEpoch::Excel1900, Some(0)),
+        ((1900, 1, 1), SerialEpoch::Excel1900, Some(1)),
+        ((1900, 2, 28), SerialEpoch::Excel1900, Some(59)),
+        ((1900, 3, 1), SerialEpoch::Excel1900, Some(61)),
+        ((2015, 1, 20), SerialEpoch::Excel1900, Some(42024)),
+        ((2015, 1, 20), SerialEpoch::Lotus, Some(42024)),
+        ((2015, 1, 20), SerialEpoch::Excel1904, Some(40562)),
+        ((1903, 12, 31), SerialEpoch::Excel1904, None),
+        ((2015, 1, 20), SerialEpoch::LibreOffice, Some(42024)),
+        ((1900, 2, 28), SerialEpoch::LibreOffice, Some(60)),
+        ((1899, 12, 29), SerialEpoch::LibreOffice, Some(-1)),
+    ];
+    for &((year, month, day), epoch, serial) in testdata.iter() {
+        let datetime = NaiveDate::from_ymd(year, month, day).and_hms(0, 0, 0);
+        let result = to_serial(&datetime, epoch);
+        assert_eq!(result.map(|serial| serial.day), serial, "{} {:?}", datetime, epoch);
+        if let Some(result) = result {
+            assert_eq!(from_serial(&result, epoch), Some(datetime));
+        }
+    }
+    assert_eq!(from_serial(&DayNumber { day: 60, nanosecond: 0 }, SerialEpoch::Excel1900), None);
+    assert_eq!(from_serial(&DayNumber { day: -1, nanosecond: 0 }, SerialEpoch::Excel1904), None);
+
+    let serial = DayNumber::from_f64(42024.75).unwrap();
+    let datetime = from_serial_datetime(&serial, SerialEpoch::Excel1900, FixedOffset::west(8 * 3600)).unwrap();
+    assert_eq!(format!("{}", datetime), "2015-01-20 18:00:00 -08:00");
+    assert_eq!(to_serial_datetime(&datetime, SerialEpoch::Excel1900), Some(serial));
+    assert_eq!(format_serial(&serial, 3), Some("42024.750".to_string()));
+    assert_eq!(format_serial(&DayNumber { day: i64::MAX, nanosecond: 86_399_999_999_999 }, 3), None);
+    assert_eq!(format_serial(&DayNumber { day: i64::MIN, nanosecond: 43_200_000_000_000 }, 3), Some("-9223372036854775807.500".to_string()));
+    assert_eq!(format_serial_datetime(&serial, SerialEpoch::Excel1900), Some("2015-01-20T18:00:00".to_string()));
+    let serial = DayNumber::from_f64(60.5).unwrap();
+    assert_eq!(format_serial_datetime(&serial, SerialEpoch::Excel1900), Some("1900-02-29T12:00:00".to_string()));
+    assert_eq!(format_serial_datetime(&serial, SerialEpoch::LibreOffice), Some("1900-02-28T12:00:00".to_string()));
+}
@@ -0,0 +1,96 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDateTime;
+use chrono::Duration;
+use chrono::Timelike;
+use leap_seconds::LeapSecondTable;
+use super::scale::TimeScale;
+/// Converts date and time to the time scale.
+///
+/// Returns date and time in the time scale, or `None` if date is not covered by the leap seconds table.
+/// Conversion to UTC returns UTC date and time with leap second kept.
+pub fn to_time_scale(datetime: &DateTime<FixedOffset>,scale: TimeScale,leap_seconds: &LeapSecondTable) -> Option<NaiveDateTime> {
+    let utc = datetime.naive_utc();
+    let tai_offset = match scale.tai_offset() {
+        Some(tai_offset) => tai_offset,
+        None => return Some(utc),
+    };
+    let nanosecond = utc.nanosecond();
+    let mut seconds = 0;
+    if nanosecond >= 1_000_000_000 {
+        // Leap second is the second after 23:59:59.
+        seconds = 1;
+    }
+    let base = utc.with_nanosecond(0)?;
+    let tai_minus_utc = match leap_seconds.tai_minus_utc(&base) {
+        Some(tai_minus_utc) => tai_minus_utc as i64,
+        None => return None,
+    };
+    let shift = Duration::seconds(tai_minus_utc + seconds - tai_offset) + Duration::nanoseconds((nanosecond % 1_000_000_000) as i64);
+    return base.checked_add_signed(shift);
+}
+/// Converts date and time in the time scale to UTC `DateTime`.
+///
+/// Instants inside of leap second are returned with nanosecond value of 1 000 000 000 or greater.
+/// Returns `None` if date is not covered by the leap seconds table.
+pub fn from_time_scale(datetime: &NaiveDateTime,scale: TimeScale,leap_seconds: &LeapSecondTable) -> Option<DateTime<FixedOffset>> {
+    let utc_offset = FixedOffset::east_opt(0).unwrap();
+    let tai_offset = match scale.tai_offset() {
+        Some(tai_offset) => tai_offset,
+        None => return Some(DateTime::from_utc(*datetime, utc_offset)),
+    };
+    let tai = datetime.checked_add_signed(Duration::seconds(tai_offset))?;
+    let mut tai_minus_utc = None;
+    for &(since, offset) in leap_seconds.entries().iter() {
+        let since = since.and_hms_opt(0, 0, 0).unwrap();
+        if tai >= since + Duration::seconds(offset as i64) {
+            tai_minus_utc = Some(offset);
+            continue;
+        }
+        if let Some(previous) = tai_minus_utc {
+            let leap_second_start = since + Duration::seconds(previous as i64);
+            if tai >= leap_second_start {
+                let nanosecond = match tai.signed_duration_since(leap_second_start).num_nanoseconds() {
+                    Some(nanosecond) => nanosecond as u32,
+                    None => return None,
+                };
+                let utc = (since - Duration::seconds(1)).with_nanosecond(1_000_000_000 + nanosecond);
+                return utc.map(|utc| DateTime::from_utc(utc, utc_offset));
+            }
+        }
+        break;
+    }
+    return tai_minus_utc.map(|tai_minus_utc| {
+        DateTime::from_utc(tai - Duration::seconds(tai_minus_utc as i64), utc_offset)
+    });
+}
+#[cfg(test)]
+#[test]
+fn test_time_scale_conversion() {
+    use parser::parse_w3c_datetime_leap;
+    use formatter::format_w3c;
+    let table = LeapSecondTable::builtin();
+    let testdates = [
+        ("2017-01-01T00:00:00Z", TimeScale::Tai, "2017-01-01 00:00:37"),
+        ("2017-01-01T00:00:00Z", TimeScale::Gps, "2017-01-01 00:00:18"),
+        ("2017-01-01T00:00:00Z", TimeScale::Galileo, "2017-01-01 00:00:18"),
+        ("2017-01-01T00:00:00Z", TimeScale::BeiDou, "2017-01-01 00:00:04"),
+        ("2016-12-31T23:59:59Z", TimeScale::Tai, "2017-01-01 00:00:35"),
+        ("2016-12-31T23:59:60Z", TimeScale::Tai, "2017-01-01 00:00:36"),
+        ("2016-12-31T23:59:60.5Z", TimeScale::Tai, "2017-01-01 00:00:36.500"),
+        ("2016-12-31T15:59:60-08:00", TimeScale::Gps, "2017-01-01 00:00:17"),
+        ("2015-01-20T17:35:20-08:00", TimeScale::Tai, "2015-01-21 01:35:55"),
+        ("2015-01-20T17:35:20-08:00", TimeScale::Utc, "2015-01-21 01:35:20"),
+    ];
+    for &(date, scale, expected) in testdates.iter() {
+        let datetime = parse_w3c_datetime_leap(date, &table).unwrap();
+        let converted = to_time_scale(&datetime, scale, &table).unwrap();
+        assert_eq!(format!("{}", converted), expected, "{} to {:?}", date, scale);
+        let back = from_time_scale(&converted, scale, &table).unwrap();
+        assert_eq!(back, datetime, "{} from {:?}", date, scale);
+    }
+    let datetime = from_time_scale(&NaiveDateTime::parse_from_str("2017-01-01 00:00:36.25", "%Y-%m-%d %H:%M:%S%.f").unwrap(), TimeScale::Tai, &table).unwrap();
+    assert_eq!(format_w3c(&datetime), "2016-12-31T23:59:60.250Z");
+    let datetime = parse_w3c_datetime_leap("1970-01-01T00:00:00Z", &table).unwrap();
+    assert_eq!(to_time_scale(&datetime, TimeScale::Tai, &table), None);
+}
@@ -0,0 +1,6 @@
+mod scale;
+mod conversion;
+mod week_time;
+pub use self::scale::TimeScale;
+pub use self::conversion::{to_time_scale,from_time_scale};
+pub use self::week_time::{WeekTime,to_week_time,from_week_time};
\ No newline at end of file
@@ -0,0 +1,59 @@
+use chrono::NaiveDate;
+/// Time scale of date and time.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TimeScale {
+    /// Coordinated Universal Time. Contains leap seconds.
+    Utc,
+    /// International Atomic Time.
+    Tai,
+    /// GPS time. `TAI - GPS = 19 s`.
+    Gps,
+    /// Galileo System Time. Same to GPS time, but weeks are counted from 1999-08-22.
+    Galileo,
+    /// BeiDou Time. `TAI - BDT = 33 s`.
+    BeiDou,
+}
+impl TimeScale {
+    /// Returns difference between TAI and time scale in seconds.
+    ///
+    /// Returns `None` for UTC, because it depends on leap seconds.
+    pub fn tai_offset(&self) -> Option<i64> {
+        match *self {
+            TimeScale::Utc => None,
+            TimeScale::Tai => Some(0),
+            TimeScale::Gps => Some(19),
+            TimeScale::Galileo => Some(19),
+            TimeScale::BeiDou => Some(33),
+        }
+    }
+    /// Returns start of week numbering of navigation system time scale.
+    pub fn week_epoch(&self) -> Option<NaiveDate> {
+        match *self {
+            TimeScale::Gps => NaiveDate::from_ymd_opt(1980, 1, 6),
+            TimeScale::Galileo => NaiveDate::from_ymd_opt(1999, 8, 22),
+            TimeScale::BeiDou => NaiveDate::from_ymd_opt(2006, 1, 1),
+            _ => None,
+        }
+    }
+    /// Returns abbreviation of time scale such as `TAI`.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            TimeScale::Utc => "UTC",
+            TimeScale::Tai => "TAI",
+            TimeScale::Gps => "GPS",
+            TimeScale::Galileo => "GST",
+            TimeScale::BeiDou => "BDT",
+        }
+    }
+    /// Returns time scale by its abbreviation.
+    pub fn from_name(name: &str) -> Option<TimeScale> {
+        match name {
+            "UTC" => Some(TimeScale::Utc),
+            "TAI" => Some(TimeScale::Tai),
+            "GPS" => Some(TimeScale::Gps),
+            "GST" => Some(TimeScale::Galileo),
+            "BDT" => Some(TimeScale::BeiDou),
+            _ => None,
+        }
+    }
+}
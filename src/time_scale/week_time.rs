@@ -0,0 +1,90 @@
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDateTime;
+use chrono::Duration;
+use chrono::Timelike;
+use leap_seconds::LeapSecondTable;
+use super::scale::TimeScale;
+use super::conversion::{to_time_scale,from_time_scale};
+const SECONDS_PER_WEEK: i64 = 7 * 24 * 60 * 60;
+/// Week number and time of week of navigation system time scale.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct WeekTime {
+    /// Week number since the start of time scale (without rollover).
+    pub week: i64,
+    /// Seconds of week.
+    pub seconds: u32,
+    /// Nanoseconds of second.
+    pub nanosecond: u32,
+}
+impl WeekTime {
+    /// Returns week time of date and time given in the `scale` time scale.
+    ///
+    /// Returns `None` if time scale has no week numbering.
+    pub fn from_naive(datetime: &NaiveDateTime,scale: TimeScale) -> Option<WeekTime> {
+        let epoch = match scale.week_epoch() {
+            Some(epoch) => epoch.and_hms_opt(0, 0, 0).unwrap(),
+            None => return None,
+        };
+        let nanosecond = datetime.nanosecond() % 1_000_000_000;
+        let seconds = match datetime.with_nanosecond(0) {
+            Some(datetime) => datetime.signed_duration_since(epoch).num_seconds(),
+            None => return None,
+        };
+        return Some(WeekTime {
+            week: seconds.div_euclid(SECONDS_PER_WEEK),
+            seconds: seconds.rem_euclid(SECONDS_PER_WEEK) as u32,
+            nanosecond: nanosecond,
+        });
+    }
+    /// Returns date and time in the `scale` time scale.
+    ///
+    /// Returns `None` if time scale has no week numbering or value is out of range.
+    pub fn to_naive(&self,scale: TimeScale) -> Option<NaiveDateTime> {
+        let epoch = match scale.week_epoch() {
+            Some(epoch) => epoch.and_hms_opt(0, 0, 0).unwrap(),
+            None => return None,
+        };
+        if self.seconds as i64 >= SECONDS_PER_WEEK || self.nanosecond >= 1_000_000_000 {
+            return None;
+        }
+        let weeks = self.week.checked_mul(SECONDS_PER_WEEK)?;
+        let duration = Duration::seconds(weeks + self.seconds as i64) + Duration::nanoseconds(self.nanosecond as i64);
+        return epoch.checked_add_signed(duration);
+    }
+}
+/// Converts date and time to week number and time of week of navigation system time scale.
+pub fn to_week_time(datetime: &DateTime<FixedOffset>,scale: TimeScale,leap_seconds: &LeapSecondTable) -> Option<WeekTime> {
+    return match to_time_scale(datetime, scale, leap_seconds) {
+        Some(naive) => WeekTime::from_naive(&naive, scale),
+        None => None,
+    };
+}
+/// Converts week number and time of week of navigation system time scale to UTC `DateTime`.
+pub fn from_week_time(week_time: &WeekTime,scale: TimeScale,leap_seconds: &LeapSecondTable) -> Option<DateTime<FixedOffset>> {
+    return match week_time.to_naive(scale) {
+        Some(naive) => from_time_scale(&naive, scale, leap_seconds),
+        None => None,
+    };
+}
+#[cfg(test)]
+#[test]
+fn test_week_time() {
+    use parser::parse_w3c_datetime;
+    let table = LeapSecondTable::builtin();
+    let datetime = parse_w3c_datetime("2017-01-01T00:00:00Z").unwrap();
+    let testdata = [
+        (TimeScale::Gps, 1930, 18),
+        (TimeScale::Galileo, 906, 18),
+        (TimeScale::BeiDou, 574, 4),
+    ];
+    for &(scale, week, seconds) in testdata.iter() {
+        let week_time = to_week_time(&datetime, scale, &table).unwrap();
+        assert_eq!(week_time, WeekTime { week: week, seconds: seconds, nanosecond: 0 }, "{:?}", scale);
+        assert_eq!(from_week_time(&week_time, scale, &table), Some(datetime));
+    }
+    let datetime = parse_w3c_datetime("2016-12-31T23:59:00.5+03:00").unwrap();
+    let week_time = to_week_time(&datetime, TimeScale::Gps, &table).unwrap();
+    assert_eq!(week_time, WeekTime { week: 1929, seconds: 6 * 86400 + 20 * 3600 + 59 * 60 + 17, nanosecond: 500_000_000 });
+    assert_eq!(to_week_time(&datetime, TimeScale::Tai, &table), None);
+}